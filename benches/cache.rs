@@ -0,0 +1,47 @@
+use std::num::NonZeroUsize;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use conventional_commit_parser::cache::CachedParser;
+use conventional_commit_parser::parse;
+
+/// A CI pipeline re-parsing the same commit range in each job
+fn messages() -> Vec<String> {
+    let commits: Vec<String> = (0..50)
+        .map(|idx| {
+            format!(
+                "feat(parser): add feature number {}\n\nA body describing the change\nover a few lines.\n\nRefs: #{}\nReviewed-by: Z",
+                idx, idx
+            )
+        })
+        .collect();
+
+    (0..20).flat_map(|_| commits.clone()).collect()
+}
+
+fn repeated_messages(c: &mut Criterion) {
+    let messages = messages();
+    let mut group = c.benchmark_group("repeated messages");
+
+    group.bench_function("parse", |b| {
+        b.iter(|| {
+            for message in &messages {
+                black_box(parse(message).unwrap());
+            }
+        })
+    });
+
+    group.bench_function("cached parse", |b| {
+        b.iter(|| {
+            let mut parser = CachedParser::new(NonZeroUsize::new(64).unwrap());
+            for message in &messages {
+                black_box(parser.parse(message).unwrap());
+            }
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, repeated_messages);
+criterion_main!(benches);
@@ -0,0 +1,53 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+
+use conventional_commit_parser::{parse, parse_summary};
+
+const MESSAGES: usize = 1_000_000;
+
+/// A large history of one-line messages, as produced by `git log --format=%s`
+fn one_line_messages() -> Vec<String> {
+    let headers = [
+        "feat(parser): add feature number",
+        "fix: handle edge case number",
+        "chore(deps)!: bump dependency number",
+        "docs(readme): document option number",
+    ];
+
+    (0..MESSAGES)
+        .map(|idx| format!("{} {}", headers[idx % headers.len()], idx))
+        .collect()
+}
+
+fn one_line_headers(c: &mut Criterion) {
+    let messages = one_line_messages();
+    // A trailing line break sends the same headers through the grammar
+    let terminated: Vec<String> = messages
+        .iter()
+        .map(|message| format!("{}\n", message))
+        .collect();
+
+    let mut group = c.benchmark_group("one-line headers");
+    group.sample_size(10);
+    group.throughput(Throughput::Elements(MESSAGES as u64));
+
+    group.bench_function("fast path", |b| {
+        b.iter(|| {
+            for message in &messages {
+                black_box(parse(message).unwrap());
+            }
+        })
+    });
+
+    group.bench_function("grammar", |b| {
+        b.iter(|| {
+            for message in &terminated {
+                black_box(parse_summary(message).unwrap());
+            }
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, one_line_headers);
+criterion_main!(benches);
@@ -0,0 +1,91 @@
+//! Colorized terminal rendering of commits, requires the `ansi` feature
+
+use crate::commit::ConventionalCommit;
+
+const RESET: &str = "\x1b[0m";
+const DIM: &str = "\x1b[2m";
+const BOLD_YELLOW: &str = "\x1b[1;33m";
+const CYAN: &str = "\x1b[36m";
+const BOLD_RED: &str = "\x1b[1;31m";
+const BOLD_BLUE: &str = "\x1b[1;34m";
+
+impl ConventionalCommit {
+    /// Render the commit message like its [`std::fmt::Display`] implementation, with ANSI
+    /// colors: the type in bold yellow, the scope in cyan, breaking change marks and footers
+    /// in bold red, other footer tokens in bold blue. Colors are omitted when the `NO_COLOR`
+    /// environment variable is set to a non-empty value, see <https://no-color.org>.
+    ///
+    /// # Example :
+    /// ```
+    /// use conventional_commit_parser::parse;
+    ///
+    /// let commit = parse("fix(parser)!: handle tabs").unwrap();
+    /// let rendered = commit.render_ansi();
+    ///
+    /// if std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty()) {
+    ///     assert_eq!(rendered, commit.to_string());
+    /// } else {
+    ///     assert_eq!(rendered, "\x1b[1;33mfix\x1b[0m(\x1b[36mparser\x1b[0m)\x1b[1;31m!\x1b[0m: handle tabs");
+    /// }
+    /// ```
+    pub fn render_ansi(&self) -> String {
+        let no_color = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+        if no_color {
+            return self.to_string();
+        }
+
+        let mut message = paint(BOLD_YELLOW, self.commit_type.as_ref());
+
+        if let Some(scope) = &self.scope {
+            let scope = scope.replace('(', "\\(").replace(')', "\\)");
+            message.push_str(&format!("({})", paint(CYAN, &scope)));
+        }
+
+        let has_breaking_change_footer = self
+            .footers
+            .iter()
+            .any(|footer| self.is_breaking_change_footer(footer));
+        if self.breaking_change_mark || (self.is_breaking_change && !has_breaking_change_footer) {
+            message.push_str(&paint(BOLD_RED, "!"));
+        }
+
+        message.push_str(&format!(": {}", self.summary));
+
+        if let Some(body) = &self.body {
+            message.push_str(&format!("\n\n{}", body));
+        }
+
+        if !self.footers.is_empty() {
+            message.push('\n');
+        }
+
+        for footer in &self.footers {
+            let color = if self.is_breaking_change_footer(footer) {
+                BOLD_RED
+            } else {
+                BOLD_BLUE
+            };
+
+            message.push_str(&format!(
+                "\n{}{}{}",
+                paint(color, &footer.token),
+                footer.token_separator,
+                footer.content
+            ));
+        }
+
+        if let Some(sha) = &self.cherry_picked_from {
+            if self.footers.is_empty() {
+                message.push('\n');
+            }
+            message.push('\n');
+            message.push_str(&paint(DIM, &format!("(cherry picked from commit {})", sha)));
+        }
+
+        message
+    }
+}
+
+fn paint(color: &str, text: &str) -> String {
+    format!("{}{}{}", color, text, RESET)
+}
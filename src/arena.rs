@@ -0,0 +1,340 @@
+//! Batch parsing into a [`Bump`] arena, requires the `bumpalo` feature.
+//!
+//! Each message is copied once into the arena and parsed in place: every commit string is a
+//! slice of that copy, so a whole history is parsed without a heap allocation per field and
+//! freed at once with the arena. Convert an [`ArenaCommit`] into a [`ConventionalCommit`]
+//! to keep it past the arena, or into a [`CowCommit`] copying only the sections selected
+//! with [`OwnedSections`] and borrowing the others from the arena.
+
+use std::borrow::Cow;
+
+use bumpalo::collections::Vec as BumpVec;
+use bumpalo::Bump;
+use pest::iterators::Pair;
+use pest::Parser;
+
+use crate::commit::{take_cherry_pick_line, CommitType, ConventionalCommit, Footer, Separator};
+use crate::error::{ParseError, ParseErrorKind};
+use crate::{fast_path, is_effectively_empty, ConventionalCommitParser, Rule};
+
+/// A commit whose strings live in an arena, see [`parse_all_in`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArenaCommit<'a> {
+    /// The commit type as written, see [`ArenaCommit::commit_type`]
+    pub commit_type: &'a str,
+    pub scope: Option<&'a str>,
+    pub summary: &'a str,
+    pub body: Option<&'a str>,
+    pub footers: &'a [ArenaFooter<'a>],
+    pub is_breaking_change: bool,
+    pub breaking_change_mark: bool,
+    pub cherry_picked_from: Option<&'a str>,
+}
+
+/// A footer whose strings live in an arena
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArenaFooter<'a> {
+    pub token: &'a str,
+    pub content: &'a str,
+    pub token_separator: Separator,
+}
+
+/// The sections copied out of the arena by [`ArenaCommit::to_cow`], the other sections
+/// borrow the arena until [`CowCommit::into_owned`]. The header is always copied.
+/// Every section is copied by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OwnedSections {
+    /// Copy the body, large bodies are often not needed, e.g. for a changelog
+    pub body: bool,
+    /// Copy the footers
+    pub footers: bool,
+}
+
+impl Default for OwnedSections {
+    fn default() -> Self {
+        OwnedSections {
+            body: true,
+            footers: true,
+        }
+    }
+}
+
+/// A commit whose body and footers are either copied or borrowed from an arena,
+/// see [`ArenaCommit::to_cow`]. A `CowCommit<'static>` owns its sections, or borrows them
+/// from an arena living as long as the program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CowCommit<'a> {
+    pub commit_type: CommitType,
+    pub scope: Option<String>,
+    pub summary: String,
+    pub body: Option<Cow<'a, str>>,
+    pub footers: Vec<CowFooter<'a>>,
+    pub is_breaking_change: bool,
+    pub breaking_change_mark: bool,
+    pub cherry_picked_from: Option<String>,
+}
+
+/// A footer of a [`CowCommit`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CowFooter<'a> {
+    pub token: Cow<'a, str>,
+    pub content: Cow<'a, str>,
+    pub token_separator: Separator,
+}
+
+impl ArenaCommit<'_> {
+    /// The parsed commit type
+    pub fn commit_type(&self) -> CommitType {
+        CommitType::from(self.commit_type)
+    }
+}
+
+impl<'a> ArenaCommit<'a> {
+    /// Convert into a commit copying the header and the given sections, the other sections
+    /// borrow the arena. Nothing is dropped: borrowed sections are copied later, if needed,
+    /// by [`CowCommit::into_owned`].
+    ///
+    /// # Example :
+    /// ```
+    /// use std::borrow::Cow;
+    ///
+    /// use bumpalo::Bump;
+    /// use conventional_commit_parser::arena::{parse_all_in, CowCommit, OwnedSections};
+    ///
+    /// let arena = Bump::new();
+    /// let commits = parse_all_in(&arena, ["feat: add arenas\n\nA long body\n\nBREAKING CHANGE: drop parse"]);
+    ///
+    /// let sections = OwnedSections {
+    ///     body: false,
+    ///     ..Default::default()
+    /// };
+    /// let commit = commits[0].as_ref().unwrap().to_cow(&sections);
+    ///
+    /// assert!(matches!(commit.body, Some(Cow::Borrowed("A long body"))));
+    /// assert!(matches!(commit.footers[0].content, Cow::Owned(_)));
+    ///
+    /// // Copy the body before the arena is dropped
+    /// let commit: CowCommit<'static> = commit.into_owned();
+    /// drop(commits);
+    /// drop(arena);
+    /// assert_eq!(commit.body.as_deref(), Some("A long body"));
+    /// ```
+    pub fn to_cow(&self, sections: &OwnedSections) -> CowCommit<'a> {
+        let text = |text: &'a str, copied: bool| {
+            if copied {
+                Cow::Owned(text.to_string())
+            } else {
+                Cow::Borrowed(text)
+            }
+        };
+
+        CowCommit {
+            commit_type: self.commit_type(),
+            scope: self.scope.map(str::to_string),
+            summary: self.summary.to_string(),
+            body: self.body.map(|body| text(body, sections.body)),
+            footers: self
+                .footers
+                .iter()
+                .map(|footer| CowFooter {
+                    token: text(footer.token, sections.footers),
+                    content: text(footer.content, sections.footers),
+                    token_separator: footer.token_separator.clone(),
+                })
+                .collect(),
+            is_breaking_change: self.is_breaking_change,
+            breaking_change_mark: self.breaking_change_mark,
+            cherry_picked_from: self.cherry_picked_from.map(str::to_string),
+        }
+    }
+}
+
+impl CowCommit<'_> {
+    /// Copy the sections still borrowed from the arena
+    pub fn into_owned(self) -> CowCommit<'static> {
+        CowCommit {
+            commit_type: self.commit_type,
+            scope: self.scope,
+            summary: self.summary,
+            body: self.body.map(|body| Cow::Owned(body.into_owned())),
+            footers: self
+                .footers
+                .into_iter()
+                .map(|footer| CowFooter {
+                    token: Cow::Owned(footer.token.into_owned()),
+                    content: Cow::Owned(footer.content.into_owned()),
+                    token_separator: footer.token_separator,
+                })
+                .collect(),
+            is_breaking_change: self.is_breaking_change,
+            breaking_change_mark: self.breaking_change_mark,
+            cherry_picked_from: self.cherry_picked_from,
+        }
+    }
+}
+
+impl From<CowCommit<'_>> for ConventionalCommit {
+    fn from(commit: CowCommit<'_>) -> Self {
+        ConventionalCommit {
+            commit_type: commit.commit_type,
+            scope: commit.scope,
+            summary: commit.summary,
+            body: commit.body.map(Cow::into_owned),
+            footers: commit
+                .footers
+                .into_iter()
+                .map(|footer| Footer {
+                    token: footer.token.into_owned(),
+                    content: footer.content.into_owned(),
+                    token_separator: footer.token_separator,
+                })
+                .collect(),
+            is_breaking_change: commit.is_breaking_change,
+            breaking_change_mark: commit.breaking_change_mark,
+            cherry_picked_from: commit.cherry_picked_from,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<ArenaCommit<'_>> for ConventionalCommit {
+    fn from(commit: ArenaCommit<'_>) -> Self {
+        ConventionalCommit::from(commit.to_cow(&OwnedSections::default()))
+    }
+}
+
+/// Parse a batch of commit messages like [`crate::parse`], allocating the commits in `arena`.
+/// Results are returned in input order.
+///
+/// # Example :
+/// ```
+/// use bumpalo::Bump;
+/// use conventional_commit_parser::arena::parse_all_in;
+/// use conventional_commit_parser::commit::CommitType;
+///
+/// let arena = Bump::new();
+/// let history = ["feat(parser): add arenas", "fix: typo\n\nRefs: #12", "not conventional"];
+///
+/// let commits = parse_all_in(&arena, history);
+///
+/// let feature = commits[0].as_ref().unwrap();
+/// assert_eq!(feature.commit_type(), CommitType::Feature);
+/// assert_eq!(feature.scope, Some("parser"));
+/// assert_eq!(commits[1].as_ref().unwrap().footers[0].content, "#12");
+/// assert!(commits[2].is_err());
+/// ```
+pub fn parse_all_in<'a, I, S>(
+    arena: &'a Bump,
+    messages: I,
+) -> BumpVec<'a, Result<ArenaCommit<'a>, ParseError>>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut commits = BumpVec::new_in(arena);
+    for message in messages {
+        let message: &'a str = arena.alloc_str(message.as_ref());
+        commits.push(parse_in(arena, message));
+    }
+
+    commits
+}
+
+fn parse_in<'a>(arena: &'a Bump, message: &'a str) -> Result<ArenaCommit<'a>, ParseError> {
+    if is_effectively_empty(message) {
+        return Err(ParseError::custom(
+            ParseErrorKind::EmptyCommitMessage,
+            message,
+            0,
+        ));
+    }
+
+    let mut commit = ArenaCommit {
+        commit_type: "",
+        scope: None,
+        summary: "",
+        body: None,
+        footers: &[],
+        is_breaking_change: false,
+        breaking_change_mark: false,
+        cherry_picked_from: None,
+    };
+
+    if let Some(header) = fast_path::split_one_line(message) {
+        commit.commit_type = header.commit_type;
+        commit.scope = header.scope;
+        commit.summary = header.summary;
+        commit.is_breaking_change = header.breaking_change_mark;
+        commit.breaking_change_mark = header.breaking_change_mark;
+        return Ok(commit);
+    }
+
+    let pairs = ConventionalCommitParser::parse(Rule::message, message)
+        .map_err(ParseError::from)?
+        .next()
+        .unwrap();
+
+    let mut footers = BumpVec::new_in(arena);
+    for pair in pairs.into_inner() {
+        match pair.as_rule() {
+            Rule::summary => set_summary(&mut commit, pair),
+            Rule::body => commit.body = Some(pair.as_str().trim()).filter(|b| !b.is_empty()),
+            Rule::footers => footers.extend(pair.into_inner().map(footer)),
+            _ => (),
+        }
+    }
+
+    commit.is_breaking_change |= footers
+        .iter()
+        .any(|footer| footer.token == "BREAKING CHANGE" || footer.token == "BREAKING-CHANGE");
+
+    // Same as `ConventionalCommit::extract_cherry_pick`, the remaining text is only
+    // allocated for cherry-picked commits
+    let last_footer = footers.last().map(|footer| footer.content);
+    if let Some((content, sha)) = last_footer.and_then(take_cherry_pick_line) {
+        if let Some(footer) = footers.last_mut() {
+            footer.content = arena.alloc_str(&content);
+        }
+        commit.cherry_picked_from = Some(arena.alloc_str(&sha));
+    } else if let Some((body, sha)) = commit.body.and_then(take_cherry_pick_line) {
+        commit.body = Some(&*arena.alloc_str(&body)).filter(|body| !body.is_empty());
+        commit.cherry_picked_from = Some(arena.alloc_str(&sha));
+    }
+
+    commit.footers = footers.into_bump_slice();
+    Ok(commit)
+}
+
+fn set_summary<'a>(commit: &mut ArenaCommit<'a>, summary: Pair<'a, Rule>) {
+    for pair in summary.into_inner() {
+        match pair.as_rule() {
+            Rule::commit_type => commit.commit_type = pair.as_str(),
+            Rule::scope => {
+                commit.scope = pair
+                    .into_inner()
+                    .next()
+                    .map(|scope| scope.as_str())
+                    .filter(|scope| !scope.is_empty())
+            }
+            Rule::summary_content => commit.summary = pair.as_str(),
+            Rule::breaking_change_mark if !pair.as_str().is_empty() => {
+                commit.is_breaking_change = true;
+                commit.breaking_change_mark = true;
+            }
+            _ => (),
+        }
+    }
+}
+
+fn footer(pair: Pair<'_, Rule>) -> ArenaFooter<'_> {
+    let mut pair = pair.into_inner();
+    let token = pair.next().unwrap().as_str();
+    let token_separator = Separator::from(pair.next().unwrap().as_str());
+    let content = pair.next().unwrap().as_str().trim();
+
+    ArenaFooter {
+        token,
+        content,
+        token_separator,
+    }
+}
@@ -0,0 +1,103 @@
+//! Memoized parsing of repeated commit messages, requires the `cache` feature
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::num::NonZeroUsize;
+
+use lru::LruCache;
+
+use crate::commit::ConventionalCommit;
+use crate::error::ParseError;
+use crate::options::ParserOptions;
+
+/// A parser keeping the most recently parsed messages in an LRU cache keyed by message hash.
+/// Parse errors are cached as well, so a malformed message is only parsed once.
+///
+/// # Example :
+/// ```
+/// use std::num::NonZeroUsize;
+/// use conventional_commit_parser::cache::CachedParser;
+///
+/// let mut parser = CachedParser::new(NonZeroUsize::new(128).unwrap());
+///
+/// let first = parser.parse("feat: add a cache").unwrap();
+/// let second = parser.parse("feat: add a cache").unwrap();
+///
+/// assert_eq!(first, second);
+/// assert_eq!(parser.hits(), 1);
+/// assert_eq!(parser.misses(), 1);
+/// ```
+#[derive(Debug)]
+pub struct CachedParser {
+    options: ParserOptions,
+    cache: LruCache<u64, (String, Result<ConventionalCommit, ParseError>)>,
+    hits: u64,
+    misses: u64,
+}
+
+impl CachedParser {
+    /// Create a parser caching up to `capacity` messages, parsed with the default options
+    pub fn new(capacity: NonZeroUsize) -> Self {
+        Self::with_options(capacity, ParserOptions::default())
+    }
+
+    /// Create a parser caching up to `capacity` messages, parsed with [`crate::parse_with_options`]
+    pub fn with_options(capacity: NonZeroUsize, options: ParserOptions) -> Self {
+        CachedParser {
+            options,
+            cache: LruCache::new(capacity),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Parse a commit message, returning the cached result if the message was seen recently
+    pub fn parse(&mut self, commit_message: &str) -> Result<ConventionalCommit, ParseError> {
+        let key = message_hash(commit_message);
+
+        // Compare the message as well, a hash collision must not return another commit
+        if let Some((message, parsed)) = self.cache.get(&key) {
+            if message == commit_message {
+                self.hits += 1;
+                return parsed.clone();
+            }
+        }
+
+        self.misses += 1;
+        let parsed = crate::parse_with_options(commit_message, &self.options);
+        self.cache
+            .put(key, (commit_message.to_string(), parsed.clone()));
+        parsed
+    }
+
+    /// Number of messages served from the cache
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Number of messages that had to be parsed
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+
+    /// Number of cached messages
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    /// Returns `true` if no message is cached
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    /// Drop every cached message, keeping the hit and miss counters
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+}
+
+fn message_hash(commit_message: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    commit_message.hash(&mut hasher);
+    hasher.finish()
+}
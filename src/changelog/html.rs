@@ -0,0 +1,187 @@
+//! HTML rendering of [`ChangelogSections`]
+
+use std::fmt::Write;
+
+use crate::changelog::template::Remote;
+use crate::changelog::{
+    ChangelogEntry, ChangelogRenderer, ChangelogSections, ScopeGrouping, TypeNames,
+};
+use crate::commit::{text_fragments, CommitType, SummaryFragment};
+
+/// A [`ChangelogRenderer`] producing an HTML fragment, one `<section>` per release
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::changelog::{ChangelogRenderer, ChangelogSections};
+/// use conventional_commit_parser::changelog::html::HtmlRenderer;
+/// use conventional_commit_parser::parse;
+///
+/// let sections = ChangelogSections::from_entries(vec![parse("fix: escape <tags>").unwrap()]);
+///
+/// let html = HtmlRenderer::default().render(&sections);
+///
+/// assert!(html.contains("<li>escape &lt;tags&gt;</li>"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HtmlRenderer {
+    /// The remote used to render commit, compare, issue and user links
+    pub remote: Option<Remote>,
+    /// Nest entries by scope under each commit type heading
+    pub scope_grouping: Option<ScopeGrouping>,
+    /// Commit type headings order, see [`crate::changelog::type_priority`]
+    pub type_order: Vec<CommitType>,
+    /// Commit type headings display names
+    pub type_names: TypeNames,
+}
+
+impl ChangelogRenderer for HtmlRenderer {
+    fn render(&self, sections: &ChangelogSections) -> String {
+        let mut out = String::from("<section class=\"release\">\n<h2>");
+        let version = escape(sections.version.as_deref().unwrap_or("Unreleased"));
+
+        match self
+            .remote
+            .as_ref()
+            .and_then(|remote| sections.compare_url(remote))
+        {
+            Some(url) => write!(out, "<a href=\"{}\">{}</a>", escape(&url), version).unwrap(),
+            None => out.push_str(&version),
+        }
+
+        if let Some(date) = &sections.date {
+            write!(out, " - {}", escape(date)).unwrap();
+        }
+
+        out.push_str("</h2>\n");
+
+        for section in sections.ordered_sections(&self.type_order) {
+            writeln!(
+                out,
+                "<h3>{}</h3>",
+                escape(section.commit_type.display_name(&self.type_names))
+            )
+            .unwrap();
+            match &self.scope_grouping {
+                None => self.write_list(&mut out, section.entries.iter(), true),
+                Some(grouping) => {
+                    for group in grouping.group(&section.entries) {
+                        if let Some(display_name) = group.display_name {
+                            writeln!(out, "<h4>{}</h4>", escape(display_name)).unwrap();
+                        }
+                        self.write_list(&mut out, group.entries.into_iter(), false);
+                    }
+                }
+            }
+        }
+
+        out.push_str("</section>\n");
+        out
+    }
+}
+
+impl HtmlRenderer {
+    fn write_list<'a>(
+        &self,
+        out: &mut String,
+        entries: impl Iterator<Item = &'a ChangelogEntry>,
+        with_scope: bool,
+    ) {
+        out.push_str("<ul>\n");
+        for entry in entries {
+            writeln!(out, "<li>{}</li>", self.line(entry, with_scope)).unwrap();
+        }
+        out.push_str("</ul>\n");
+    }
+
+    fn line(&self, entry: &ChangelogEntry, with_scope: bool) -> String {
+        let mut line = String::new();
+        if entry.commit.is_breaking_change {
+            line.push_str("<span class=\"breaking\">BREAKING</span> ");
+        }
+
+        if let Some(scope) = entry.commit.scope.as_ref().filter(|_| with_scope) {
+            write!(line, "<strong>({})</strong> ", escape(scope)).unwrap();
+        }
+
+        let summary = entry.commit.changelog_entry();
+        for fragment in text_fragments(&summary) {
+            match fragment {
+                SummaryFragment::Text(text) => line.push_str(&escape(text)),
+                SummaryFragment::Code(code) => {
+                    write!(line, "<code>{}</code>", escape(code)).unwrap()
+                }
+                SummaryFragment::Issue(issue) => {
+                    match self.remote.as_ref().and_then(|r| r.issue_url(issue)) {
+                        Some(url) => {
+                            write!(line, "<a href=\"{}\">#{}</a>", escape(&url), issue).unwrap()
+                        }
+                        None => write!(line, "#{}", issue).unwrap(),
+                    }
+                }
+            }
+        }
+
+        if let (Some(sha), Some(short_sha)) = (&entry.sha, entry.short_sha()) {
+            match self.remote.as_ref().and_then(|r| r.commit_url(sha)) {
+                Some(url) => write!(
+                    line,
+                    " - <a href=\"{}\"><code>{}</code></a>",
+                    escape(&url),
+                    escape(short_sha)
+                )
+                .unwrap(),
+                None => write!(line, " - <code>{}</code>", escape(short_sha)).unwrap(),
+            }
+        }
+
+        if let Some(author) = &entry.author {
+            match self.remote.as_ref().and_then(|r| r.user_url(author)) {
+                Some(url) => write!(
+                    line,
+                    " - <a href=\"{}\">@{}</a>",
+                    escape(&url),
+                    escape(author)
+                )
+                .unwrap(),
+                None => write!(line, " - {}", escape(author)).unwrap(),
+            }
+        }
+
+        for link in &entry.links {
+            write!(
+                line,
+                " - <a href=\"{}\">{}</a>",
+                escape(&link.url),
+                escape(&link.title)
+            )
+            .unwrap();
+        }
+
+        if let Some(migration) = entry.commit.breaking_change_migration() {
+            write!(
+                line,
+                "<p class=\"migration\">Migration: {}</p>",
+                escape(migration)
+            )
+            .unwrap();
+        }
+
+        line
+    }
+}
+
+/// Escape HTML special characters
+pub(crate) fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,142 @@
+//! JSON rendering of [`ChangelogSections`], requires the `json` feature
+
+use serde_json::{json, Value};
+
+use crate::changelog::template::Remote;
+use crate::changelog::{
+    ChangelogEntry, ChangelogRenderer, ChangelogSections, ScopeGrouping, TypeNames,
+};
+use crate::commit::CommitType;
+
+/// A [`ChangelogRenderer`] producing a JSON document
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::changelog::{ChangelogRenderer, ChangelogSections};
+/// use conventional_commit_parser::changelog::json::JsonRenderer;
+/// use conventional_commit_parser::parse;
+///
+/// let mut sections = ChangelogSections::from_entries(vec![parse("feat(api): add endpoint").unwrap()]);
+/// sections.version = Some("1.0.0".to_string());
+///
+/// let value = JsonRenderer::default().to_value(&sections);
+///
+/// assert_eq!(value["version"], "1.0.0");
+/// assert_eq!(value["sections"][0]["type"], "feat");
+/// assert_eq!(value["sections"][0]["entries"][0]["scope"], "api");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JsonRenderer {
+    /// The remote used to render commit, compare and user links
+    pub remote: Option<Remote>,
+    /// Pretty print the rendered JSON
+    pub pretty: bool,
+    /// Add a `scopes` array to each section, nesting its entries by scope
+    pub scope_grouping: Option<ScopeGrouping>,
+    /// Sections order, see [`crate::changelog::type_priority`]
+    pub type_order: Vec<CommitType>,
+    /// Section titles display names
+    pub type_names: TypeNames,
+}
+
+impl ChangelogRenderer for JsonRenderer {
+    fn render(&self, sections: &ChangelogSections) -> String {
+        let value = self.to_value(sections);
+        if self.pretty {
+            serde_json::to_string_pretty(&value).expect("valid json value")
+        } else {
+            value.to_string()
+        }
+    }
+}
+
+impl JsonRenderer {
+    /// Build the JSON document without serializing it
+    pub fn to_value(&self, sections: &ChangelogSections) -> Value {
+        let compare_url = self
+            .remote
+            .as_ref()
+            .and_then(|remote| sections.compare_url(remote));
+
+        let rendered_sections: Vec<Value> = sections
+            .ordered_sections(&self.type_order)
+            .into_iter()
+            .map(|section| {
+                let mut value = json!({
+                    "type": section.commit_type.as_ref(),
+                    "title": section.commit_type.display_name(&self.type_names),
+                    "entries": section.entries.iter().map(|entry| self.entry(entry)).collect::<Vec<_>>(),
+                });
+
+                if let Some(grouping) = &self.scope_grouping {
+                    let scopes: Vec<Value> = grouping
+                        .group(&section.entries)
+                        .into_iter()
+                        .map(|group| {
+                            json!({
+                                "scope": group.scope,
+                                "title": group.display_name,
+                                "entries": group.entries.into_iter().map(|entry| self.entry(entry)).collect::<Vec<_>>(),
+                            })
+                        })
+                        .collect();
+
+                    value["scopes"] = Value::from(scopes);
+                }
+
+                value
+            })
+            .collect();
+
+        json!({
+            "version": sections.version,
+            "previous_version": sections.previous_version,
+            "date": sections.date,
+            "compare_url": compare_url,
+            "sections": rendered_sections,
+        })
+    }
+
+    fn entry(&self, entry: &ChangelogEntry) -> Value {
+        let commit = &entry.commit;
+        let commit_url = match (&self.remote, &entry.sha) {
+            (Some(remote), Some(sha)) => remote.commit_url(sha),
+            _ => None,
+        };
+        let author_url = match (&self.remote, &entry.author) {
+            (Some(remote), Some(author)) => remote.user_url(author),
+            _ => None,
+        };
+
+        let footers: Vec<Value> = commit
+            .footers
+            .iter()
+            .map(|footer| {
+                json!({
+                    "token": footer.token,
+                    "separator": footer.token_separator.as_str(),
+                    "content": footer.content,
+                })
+            })
+            .collect();
+
+        json!({
+            "scope": commit.scope,
+            "summary": commit.changelog_entry(),
+            "body": commit.body,
+            "breaking_change": commit.is_breaking_change,
+            "breaking_change_description": commit.breaking_change_description(),
+            "migration": commit.breaking_change_migration(),
+            "footers": footers,
+            "sha": entry.sha,
+            "commit_url": commit_url,
+            "author": entry.author,
+            "author_url": author_url,
+            "date": entry.date,
+            "links": entry.links.iter().map(|link| json!({
+                "title": link.title,
+                "url": link.url,
+            })).collect::<Vec<_>>(),
+        })
+    }
+}
@@ -0,0 +1,463 @@
+//! Markdown rendering of [`ChangelogSections`]
+
+use std::fmt::Write;
+
+use crate::changelog::template::Remote;
+use crate::changelog::{
+    ChangelogEntry, ChangelogRenderer, ChangelogSections, ScopeGrouping, TypeNames,
+};
+use crate::commit::{text_fragments, CommitType, SummaryFragment};
+
+/// Markdown layout produced by [`render_markdown`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MarkdownStyle {
+    /// Cocogitto changelog layout: one `####` heading per commit type
+    #[default]
+    Cocogitto,
+    /// [Keep a changelog](https://keepachangelog.com) layout: `Added`, `Changed`, `Deprecated`,
+    /// `Removed`, `Fixed` and `Security` headings and compare link references
+    KeepAChangelog,
+}
+
+/// Markdown rendering options
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RenderOptions {
+    pub style: MarkdownStyle,
+    /// The remote used to render commit, compare, issue and user links
+    pub remote: Option<Remote>,
+    /// Nest entries by scope under each heading
+    pub scope_grouping: Option<ScopeGrouping>,
+    /// Commit type headings order, see [`crate::changelog::type_priority`]
+    pub type_order: Vec<CommitType>,
+    /// Commit type headings display names
+    pub type_names: TypeNames,
+}
+
+/// A [`ChangelogRenderer`] producing markdown, see [`render_markdown`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MarkdownRenderer {
+    pub options: RenderOptions,
+}
+
+impl ChangelogRenderer for MarkdownRenderer {
+    fn render(&self, sections: &ChangelogSections) -> String {
+        render_markdown(sections, &self.options)
+    }
+}
+
+/// Render a release as markdown
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::changelog::ChangelogSections;
+/// use conventional_commit_parser::changelog::markdown::{render_markdown, RenderOptions};
+/// use conventional_commit_parser::parse;
+///
+/// let mut sections = ChangelogSections::from_entries(vec![
+///     parse("feat(parser): add render_markdown").unwrap(),
+///     parse("fix: correct minor typos in code").unwrap(),
+/// ]);
+///
+/// sections.version = Some("1.0.0".to_string());
+/// sections.date = Some("2022-01-17".to_string());
+///
+/// let markdown = render_markdown(&sections, &RenderOptions::default());
+///
+/// assert_eq!(markdown, [
+///     "## 1.0.0 - 2022-01-17",
+///     "#### Features",
+///     "- **(parser)** add render_markdown",
+///     "#### Bug Fixes",
+///     "- correct minor typos in code",
+///     "- - -\n",
+/// ].join("\n"));
+/// ```
+pub fn render_markdown(sections: &ChangelogSections, options: &RenderOptions) -> String {
+    render_markdown_with(sections, options, &options.style)
+}
+
+/// Render a release as markdown like [`render_markdown`], formatting entry lines with
+/// a custom [`LineFormatter`]. Headings and breaking change migration notes are still
+/// rendered according to [`RenderOptions`].
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::changelog::{ChangelogEntry, ChangelogSections};
+/// use conventional_commit_parser::changelog::markdown::{
+///     render_markdown_with, LineContext, RenderOptions,
+/// };
+/// use conventional_commit_parser::parse;
+///
+/// let sections = ChangelogSections::from_entries(vec![ChangelogEntry {
+///     commit: parse("fix: correct minor typos in code").unwrap(),
+///     sha: Some("1df053fb3133ca36ca4127cf48231bf7c77b3d33".to_string()),
+///     author: Some("oknozor".to_string()),
+///     date: None,
+///     links: vec![],
+/// }]);
+///
+/// let formatter = |line: &LineContext<'_>| {
+///     format!("* {} by {}", line.summary, line.entry.author.as_deref().unwrap_or("unknown"))
+/// };
+///
+/// let markdown = render_markdown_with(&sections, &RenderOptions::default(), &formatter);
+///
+/// assert!(markdown.contains("* correct minor typos in code by oknozor\n"));
+/// ```
+pub fn render_markdown_with<F>(
+    sections: &ChangelogSections,
+    options: &RenderOptions,
+    formatter: &F,
+) -> String
+where
+    F: LineFormatter + ?Sized,
+{
+    match options.style {
+        MarkdownStyle::Cocogitto => render_cocogitto(sections, options, formatter),
+        MarkdownStyle::KeepAChangelog => render_keep_a_changelog(sections, options, formatter),
+    }
+}
+
+/// Formats a single changelog entry line, see [`render_markdown_with`].
+/// [`MarkdownStyle`] implements the built-in line formats and closures taking
+/// a [`LineContext`] can be used as formatters.
+pub trait LineFormatter {
+    /// Format an entry line, without a trailing new line
+    fn format_line(&self, line: &LineContext<'_>) -> String;
+}
+
+/// Everything needed to format an entry line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineContext<'a> {
+    pub entry: &'a ChangelogEntry,
+    /// The summary, with issue references linked when a remote is configured
+    pub summary: String,
+    /// `false` when the entry is nested under its scope heading and the scope should
+    /// not be repeated on the line
+    pub with_scope: bool,
+    /// The abbreviated commit sha
+    pub short_sha: Option<&'a str>,
+    pub commit_url: Option<String>,
+    pub author_url: Option<String>,
+}
+
+impl<F> LineFormatter for F
+where
+    F: Fn(&LineContext<'_>) -> String,
+{
+    fn format_line(&self, line: &LineContext<'_>) -> String {
+        self(line)
+    }
+}
+
+impl LineFormatter for MarkdownStyle {
+    fn format_line(&self, line: &LineContext<'_>) -> String {
+        match self {
+            MarkdownStyle::Cocogitto => cocogitto_line(line),
+            MarkdownStyle::KeepAChangelog => keep_a_changelog_line(line),
+        }
+    }
+}
+
+fn render_cocogitto<F: LineFormatter + ?Sized>(
+    sections: &ChangelogSections,
+    options: &RenderOptions,
+    formatter: &F,
+) -> String {
+    let mut out = String::new();
+    let version = sections.version.as_deref().unwrap_or("Unreleased");
+
+    match compare_url(sections, options) {
+        Some(url) => write!(out, "## [{}]({})", version, url).unwrap(),
+        None => write!(out, "## {}", version).unwrap(),
+    }
+
+    if let Some(date) = &sections.date {
+        write!(out, " - {}", date).unwrap();
+    }
+
+    out.push('\n');
+
+    let security_fixes: Vec<&ChangelogEntry> = sections.security_fixes().collect();
+    if !security_fixes.is_empty() {
+        out.push_str("#### Security\n");
+        write_entries(&mut out, security_fixes, "#####", options, formatter);
+    }
+
+    for section in sections.ordered_sections(&options.type_order) {
+        let entries: Vec<&ChangelogEntry> = section
+            .entries
+            .iter()
+            .filter(|entry| !entry.commit.is_security_fix())
+            .collect();
+
+        if entries.is_empty() {
+            continue;
+        }
+
+        writeln!(
+            out,
+            "#### {}",
+            section.commit_type.display_name(&options.type_names)
+        )
+        .unwrap();
+        write_entries(&mut out, entries, "#####", options, formatter);
+    }
+
+    out.push_str("- - -\n");
+    out
+}
+
+/// Write entry lines, nested under scope headings when scope grouping is enabled
+fn write_entries<F: LineFormatter + ?Sized>(
+    out: &mut String,
+    entries: Vec<&ChangelogEntry>,
+    scope_heading: &str,
+    options: &RenderOptions,
+    formatter: &F,
+) {
+    let line = |entry: &ChangelogEntry, options: &RenderOptions, with_scope: bool| {
+        let context = LineContext {
+            entry,
+            summary: link_issues(&entry.commit.changelog_entry(), options),
+            with_scope,
+            short_sha: entry.short_sha(),
+            commit_url: entry
+                .sha
+                .as_deref()
+                .and_then(|sha| commit_url(sha, options)),
+            author_url: entry.author.as_deref().and_then(|author| {
+                options
+                    .remote
+                    .as_ref()
+                    .and_then(|remote| remote.user_url(author))
+            }),
+        };
+
+        let mut line = formatter.format_line(&context);
+        push_migration(&mut line, entry);
+        line
+    };
+
+    match &options.scope_grouping {
+        None => {
+            for entry in entries {
+                writeln!(out, "{}", line(entry, options, true)).unwrap();
+            }
+        }
+        Some(grouping) => {
+            for group in grouping.group(entries) {
+                if let Some(display_name) = group.display_name {
+                    writeln!(out, "{} {}", scope_heading, display_name).unwrap();
+                }
+
+                for entry in group.entries {
+                    writeln!(out, "{}", line(entry, options, false)).unwrap();
+                }
+            }
+        }
+    }
+}
+
+fn cocogitto_line(context: &LineContext<'_>) -> String {
+    let entry = context.entry;
+    let mut line = String::from("- ");
+    if entry.commit.is_breaking_change {
+        line.push_str("**BREAKING** ");
+    }
+
+    if let Some(scope) = entry.commit.scope.as_ref().filter(|_| context.with_scope) {
+        write!(line, "**({})** ", scope).unwrap();
+    }
+
+    line.push_str(&context.summary);
+
+    if let Some(short_sha) = context.short_sha {
+        match &context.commit_url {
+            Some(url) => write!(line, " - ([{}]({}))", short_sha, url).unwrap(),
+            None => write!(line, " - ({})", short_sha).unwrap(),
+        }
+    }
+
+    if let Some(author) = &entry.author {
+        match &context.author_url {
+            Some(url) => write!(line, " - [@{}]({})", author, url).unwrap(),
+            None => write!(line, " - {}", author).unwrap(),
+        }
+    }
+
+    for link in &entry.links {
+        write!(line, " - [{}]({})", link.title, link.url).unwrap();
+    }
+
+    line
+}
+
+/// Nest the breaking change migration note under an entry line
+fn push_migration(line: &mut String, entry: &ChangelogEntry) {
+    if let Some(migration) = entry.commit.breaking_change_migration() {
+        write!(
+            line,
+            "\n  - **Migration:** {}",
+            migration
+                .lines()
+                .map(str::trim)
+                .collect::<Vec<&str>>()
+                .join("\n    ")
+        )
+        .unwrap();
+    }
+}
+
+/// Keep a changelog categories, in rendering order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Category {
+    Added,
+    Changed,
+    Deprecated,
+    Removed,
+    Fixed,
+    Security,
+}
+
+impl Category {
+    const ALL: [Category; 6] = [
+        Category::Added,
+        Category::Changed,
+        Category::Deprecated,
+        Category::Removed,
+        Category::Fixed,
+        Category::Security,
+    ];
+
+    fn title(&self) -> &'static str {
+        match self {
+            Category::Added => "Added",
+            Category::Changed => "Changed",
+            Category::Deprecated => "Deprecated",
+            Category::Removed => "Removed",
+            Category::Fixed => "Fixed",
+            Category::Security => "Security",
+        }
+    }
+
+    fn of(entry: &ChangelogEntry) -> Category {
+        if entry.commit.is_security_fix() {
+            return Category::Security;
+        }
+
+        match entry.commit.commit_type {
+            CommitType::Feature => Category::Added,
+            CommitType::BugFix => Category::Fixed,
+            CommitType::Revert => Category::Removed,
+            _ => Category::Changed,
+        }
+    }
+}
+
+fn render_keep_a_changelog<F: LineFormatter + ?Sized>(
+    sections: &ChangelogSections,
+    options: &RenderOptions,
+    formatter: &F,
+) -> String {
+    let mut out = String::new();
+    let version = sections.version.as_deref().unwrap_or("Unreleased");
+
+    write!(out, "## [{}]", version).unwrap();
+    if let Some(date) = &sections.date {
+        write!(out, " - {}", date).unwrap();
+    }
+
+    out.push('\n');
+
+    for category in Category::ALL {
+        if category == Category::Deprecated {
+            let deprecations: Vec<&str> = sections
+                .entries()
+                .flat_map(|entry| entry.commit.deprecations())
+                .collect();
+
+            if !deprecations.is_empty() {
+                writeln!(out, "### {}", category.title()).unwrap();
+                for deprecation in deprecations {
+                    writeln!(out, "- {}", link_issues(deprecation, options)).unwrap();
+                }
+            }
+        } else {
+            let entries: Vec<&ChangelogEntry> = sections
+                .entries()
+                .filter(|entry| Category::of(entry) == category)
+                .collect();
+
+            if !entries.is_empty() {
+                writeln!(out, "### {}", category.title()).unwrap();
+                write_entries(&mut out, entries, "####", options, formatter);
+            }
+        }
+    }
+
+    if let Some(url) = compare_url(sections, options) {
+        write!(out, "\n[{}]: {}\n", version, url).unwrap();
+    }
+
+    out
+}
+
+fn keep_a_changelog_line(context: &LineContext<'_>) -> String {
+    let entry = context.entry;
+    let mut line = String::from("- ");
+    if entry.commit.is_breaking_change {
+        line.push_str("**BREAKING** ");
+    }
+
+    if let Some(scope) = entry.commit.scope.as_ref().filter(|_| context.with_scope) {
+        write!(line, "**{}:** ", scope).unwrap();
+    }
+
+    line.push_str(&context.summary);
+
+    if let Some(short_sha) = context.short_sha {
+        match &context.commit_url {
+            Some(url) => write!(line, " ([{}]({}))", short_sha, url).unwrap(),
+            None => write!(line, " ({})", short_sha).unwrap(),
+        }
+    }
+
+    for link in &entry.links {
+        write!(line, " ([{}]({}))", link.title, link.url).unwrap();
+    }
+
+    line
+}
+
+fn compare_url(sections: &ChangelogSections, options: &RenderOptions) -> Option<String> {
+    sections.compare_url(options.remote.as_ref()?)
+}
+
+fn commit_url(sha: &str, options: &RenderOptions) -> Option<String> {
+    options.remote.as_ref()?.commit_url(sha)
+}
+
+/// Replace `#123` issue references with markdown links when an issue template is configured,
+/// code spans are kept as is
+fn link_issues(text: &str, options: &RenderOptions) -> String {
+    let mut out = String::with_capacity(text.len());
+    for fragment in text_fragments(text) {
+        match fragment {
+            SummaryFragment::Text(text) => out.push_str(text),
+            SummaryFragment::Code(code) => {
+                let longest_run = code.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+                let delimiter = "`".repeat(longest_run + 1);
+                write!(out, "{}{}{}", delimiter, code, delimiter).unwrap()
+            }
+            SummaryFragment::Issue(issue) => {
+                match options.remote.as_ref().and_then(|r| r.issue_url(issue)) {
+                    Some(url) => write!(out, "[#{}]({})", issue, url).unwrap(),
+                    None => write!(out, "#{}", issue).unwrap(),
+                }
+            }
+        }
+    }
+
+    out
+}
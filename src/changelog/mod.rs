@@ -0,0 +1,670 @@
+//! Changelog generation from parsed commits.
+//!
+//! Commits are grouped into [`ChangelogSections`] which can then be rendered with
+//! any [`ChangelogRenderer`]: [`markdown::MarkdownRenderer`], [`html::HtmlRenderer`] or
+//! `json::JsonRenderer` with the `json` feature enabled.
+
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+
+use crate::changelog::template::{ReleaseCommitTemplate, Remote};
+use crate::commit::{CommitType, ConventionalCommit};
+
+pub mod html;
+#[cfg(feature = "json")]
+pub mod json;
+pub mod markdown;
+pub mod template;
+
+/// Name of the tag used as the upper compare bound of unreleased changes
+const UNRELEASED_TAG: &str = "HEAD";
+
+/// A changelog output format
+pub trait ChangelogRenderer {
+    /// Render a release
+    fn render(&self, sections: &ChangelogSections) -> String;
+}
+
+/// A single changelog line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangelogEntry {
+    pub commit: ConventionalCommit,
+    /// The full commit sha, used to render commit links
+    pub sha: Option<String>,
+    /// The commit author username on the remote, used to render user links
+    pub author: Option<String>,
+    /// The commit date, rendered as is
+    pub date: Option<String>,
+    /// Links related to the commit, such as its pull request, rendered after the entry
+    pub links: Vec<CommitLink>,
+}
+
+/// A link rendered along a changelog entry, see [`CommitMeta::links`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitLink {
+    pub title: String,
+    pub url: String,
+}
+
+/// Metadata of a commit known to the caller rather than parsed from its message, typically
+/// read with a git library. Pairing a commit with its metadata builds a [`ChangelogEntry`],
+/// so any git library can feed the changelog without this crate depending on it.
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::changelog::{ChangelogEntry, CommitLink, CommitMeta};
+/// use conventional_commit_parser::parse;
+///
+/// struct LogLine {
+///     sha: String,
+///     author: String,
+///     pull_request: u32,
+/// }
+///
+/// impl CommitMeta for LogLine {
+///     fn sha(&self) -> Option<&str> {
+///         Some(&self.sha)
+///     }
+///
+///     fn author(&self) -> Option<&str> {
+///         Some(&self.author)
+///     }
+///
+///     fn links(&self) -> Vec<CommitLink> {
+///         vec![CommitLink {
+///             title: format!("#{}", self.pull_request),
+///             url: format!("https://github.com/oknozor/cocogitto/pull/{}", self.pull_request),
+///         }]
+///     }
+/// }
+///
+/// let line = LogLine { sha: "1df053f".to_string(), author: "oknozor".to_string(), pull_request: 12 };
+/// let entry = ChangelogEntry::from((parse("fix: typo").unwrap(), line));
+///
+/// assert_eq!(entry.author.as_deref(), Some("oknozor"));
+/// assert_eq!(entry.links[0].title, "#12");
+/// ```
+pub trait CommitMeta {
+    /// The full commit sha
+    fn sha(&self) -> Option<&str>;
+
+    /// The commit author username on the remote
+    fn author(&self) -> Option<&str>;
+
+    /// The commit date, rendered as is
+    fn date(&self) -> Option<&str> {
+        None
+    }
+
+    /// Links related to the commit
+    fn links(&self) -> Vec<CommitLink> {
+        vec![]
+    }
+}
+
+/// Changelog entries sharing the same commit type
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangelogSection {
+    pub commit_type: CommitType,
+    pub entries: Vec<ChangelogEntry>,
+}
+
+/// A release worth of changelog entries grouped by commit type
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChangelogSections {
+    /// The released version, `None` for unreleased changes
+    pub version: Option<String>,
+    /// The previous release version, used to render compare links
+    pub previous_version: Option<String>,
+    /// The release date, rendered as is
+    pub date: Option<String>,
+    /// Sections in rendering order
+    pub sections: Vec<ChangelogSection>,
+}
+
+/// Changelog assembly settings, see [`Changelog::unreleased`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Changelog {
+    /// Sections order, see [`type_priority`]
+    pub type_order: Vec<CommitType>,
+    /// Commits carrying one of these markers are omitted
+    pub exclusion_markers: ExclusionMarkers,
+}
+
+/// Markers excluding a commit from the changelog,
+/// see [`ConventionalCommit::is_changelog_excluded`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExclusionMarkers {
+    /// Markers found in the summary or the body, matched case-insensitively.
+    /// Defaults to `[skip changelog]` and `[changelog skip]`.
+    pub markers: Vec<String>,
+    /// Footer tokens and values, both matched case-insensitively.
+    /// Defaults to `Changelog: none`.
+    pub footers: Vec<(String, String)>,
+}
+
+impl Default for ExclusionMarkers {
+    fn default() -> Self {
+        ExclusionMarkers {
+            markers: vec![
+                "[skip changelog]".to_string(),
+                "[changelog skip]".to_string(),
+            ],
+            footers: vec![("Changelog".to_string(), "none".to_string())],
+        }
+    }
+}
+
+impl ConventionalCommit {
+    /// Returns `true` if the commit carries one of the default [`ExclusionMarkers`]
+    ///
+    /// # Example :
+    /// ```
+    /// use conventional_commit_parser::parse;
+    ///
+    /// assert!(parse("chore: bump lockfile [skip changelog]").unwrap().is_changelog_excluded());
+    /// assert!(parse("fix: typo\n\nChangelog: none").unwrap().is_changelog_excluded());
+    /// assert!(!parse("fix: typo\n\nChangelog: fixed").unwrap().is_changelog_excluded());
+    /// ```
+    pub fn is_changelog_excluded(&self) -> bool {
+        self.is_changelog_excluded_by(&ExclusionMarkers::default())
+    }
+
+    /// Returns `true` if the commit carries one of the given markers
+    pub fn is_changelog_excluded_by(&self, markers: &ExclusionMarkers) -> bool {
+        let has_marker = |text: &str| {
+            let text = text.to_lowercase();
+            markers
+                .markers
+                .iter()
+                .any(|marker| text.contains(&marker.to_lowercase()))
+        };
+
+        let has_footer = self.footers.iter().any(|footer| {
+            markers.footers.iter().any(|(token, value)| {
+                footer.token.eq_ignore_ascii_case(token)
+                    && footer.content.trim().eq_ignore_ascii_case(value)
+            })
+        });
+
+        has_footer || has_marker(&self.summary) || self.body.as_deref().is_some_and(has_marker)
+    }
+
+    /// The text of the commit in release notes: the value of a `Changelog` footer other than
+    /// `Changelog: none`, falling back to [`ConventionalCommit::summary_without_ci_skip`]
+    ///
+    /// # Example :
+    /// ```
+    /// use conventional_commit_parser::parse;
+    ///
+    /// let commit = parse("fix(parser): handle tabs\n\nChangelog: Tabs are now accepted as separators").unwrap();
+    ///
+    /// assert_eq!(commit.changelog_entry(), "Tabs are now accepted as separators");
+    /// assert_eq!(parse("fix: typo [skip ci]").unwrap().changelog_entry(), "typo");
+    /// ```
+    pub fn changelog_entry(&self) -> Cow<'_, str> {
+        self.footers
+            .iter()
+            .filter(|footer| footer.token.eq_ignore_ascii_case("Changelog"))
+            .map(|footer| footer.content.trim())
+            .find(|content| !content.is_empty() && !content.eq_ignore_ascii_case("none"))
+            .map_or_else(|| self.summary_without_ci_skip(), Cow::Borrowed)
+    }
+}
+
+/// A breaking change of a release with its description, see [`ChangelogSections::breaking_change_notes`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BreakingChangeNote<'a> {
+    pub entry: &'a ChangelogEntry,
+    /// The `BREAKING CHANGE` footer content, or the summary of commits only marked with `!`
+    pub description: &'a str,
+    /// The migration note, see [`ConventionalCommit::breaking_change_migration`]
+    pub migration: Option<&'a str>,
+}
+
+/// Nest entries by scope under their section heading, monorepos typically use scopes
+/// to name packages.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScopeGrouping {
+    /// Display names of the scope headings, scopes missing from this map are displayed as is
+    pub scope_names: HashMap<String, String>,
+}
+
+/// Display names of commit types, used as section headings. Types missing from `names`
+/// use the default english titles ("Features", "Bug Fixes", ...).
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::changelog::TypeNames;
+/// use conventional_commit_parser::commit::CommitType;
+///
+/// let mut names = TypeNames::default();
+/// names.names.insert(CommitType::Feature, "Fonctionnalités".to_string());
+///
+/// assert_eq!(CommitType::Feature.display_name(&names), "Fonctionnalités");
+/// assert_eq!(CommitType::BugFix.display_name(&names), "Bug Fixes");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TypeNames {
+    pub names: HashMap<CommitType, String>,
+}
+
+impl CommitType {
+    /// Returns the display name of this commit type, see [`TypeNames`]
+    pub fn display_name<'a>(&'a self, type_names: &'a TypeNames) -> &'a str {
+        type_names
+            .names
+            .get(self)
+            .map(String::as_str)
+            .unwrap_or_else(|| section_title(self))
+    }
+}
+
+/// Entries of a section sharing the same scope, see [`ScopeGrouping`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScopeGroup<'a> {
+    /// The scope shared by the group entries, `None` for unscoped entries
+    pub scope: Option<&'a str>,
+    /// The scope heading
+    pub display_name: Option<&'a str>,
+    pub entries: Vec<&'a ChangelogEntry>,
+}
+
+impl ScopeGrouping {
+    /// Returns the display name of a scope
+    pub fn display_name<'a>(&'a self, scope: &'a str) -> &'a str {
+        self.scope_names
+            .get(scope)
+            .map(String::as_str)
+            .unwrap_or(scope)
+    }
+
+    /// Group entries by scope, unscoped entries come first then scopes by order of appearance
+    ///
+    /// # Example :
+    /// ```
+    /// use conventional_commit_parser::changelog::{ChangelogEntry, ScopeGrouping};
+    /// use conventional_commit_parser::parse;
+    ///
+    /// let entries: Vec<ChangelogEntry> = vec![
+    ///     parse("feat(cli): add verbose flag").unwrap().into(),
+    ///     parse("feat: add config file").unwrap().into(),
+    ///     parse("feat(cli): add quiet flag").unwrap().into(),
+    /// ];
+    ///
+    /// let mut grouping = ScopeGrouping::default();
+    /// grouping.scope_names.insert("cli".to_string(), "Command line".to_string());
+    ///
+    /// let groups = grouping.group(&entries);
+    ///
+    /// assert_eq!(groups[0].scope, None);
+    /// assert_eq!(groups[1].display_name, Some("Command line"));
+    /// assert_eq!(groups[1].entries.len(), 2);
+    /// ```
+    pub fn group<'a, I>(&'a self, entries: I) -> Vec<ScopeGroup<'a>>
+    where
+        I: IntoIterator<Item = &'a ChangelogEntry>,
+    {
+        let mut groups: Vec<ScopeGroup<'a>> = vec![ScopeGroup {
+            scope: None,
+            display_name: None,
+            entries: vec![],
+        }];
+
+        for entry in entries {
+            let scope = entry.commit.scope.as_deref();
+            match groups.iter_mut().find(|group| group.scope == scope) {
+                Some(group) => group.entries.push(entry),
+                None => groups.push(ScopeGroup {
+                    scope,
+                    display_name: scope.map(|scope| self.display_name(scope)),
+                    entries: vec![entry],
+                }),
+            }
+        }
+
+        groups.retain(|group| !group.entries.is_empty());
+        groups
+    }
+}
+
+impl From<ConventionalCommit> for ChangelogEntry {
+    fn from(commit: ConventionalCommit) -> Self {
+        ChangelogEntry {
+            commit,
+            sha: None,
+            author: None,
+            date: None,
+            links: vec![],
+        }
+    }
+}
+
+impl<M: CommitMeta> From<(ConventionalCommit, M)> for ChangelogEntry {
+    fn from((commit, meta): (ConventionalCommit, M)) -> Self {
+        ChangelogEntry {
+            commit,
+            sha: meta.sha().map(str::to_string),
+            author: meta.author().map(str::to_string),
+            date: meta.date().map(str::to_string),
+            links: meta.links(),
+        }
+    }
+}
+
+impl ChangelogEntry {
+    /// The abbreviated commit sha
+    pub fn short_sha(&self) -> Option<&str> {
+        self.sha.as_deref().map(short_sha)
+    }
+}
+
+impl Changelog {
+    /// Assemble the unreleased changes of a commit iterator into sections ordered by
+    /// [`Changelog::type_order`], ready to be rendered with any [`ChangelogRenderer`].
+    /// Commits carrying one of the [`Changelog::exclusion_markers`] are omitted.
+    ///
+    /// # Example :
+    /// ```
+    /// use conventional_commit_parser::changelog::markdown::MarkdownRenderer;
+    /// use conventional_commit_parser::changelog::{Changelog, ChangelogRenderer};
+    /// use conventional_commit_parser::commit::CommitType;
+    /// use conventional_commit_parser::parse;
+    ///
+    /// let changelog = Changelog {
+    ///     type_order: vec![CommitType::BugFix, CommitType::Feature],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let unreleased = changelog.unreleased(vec![
+    ///     parse("feat(api)!: paginate lists\n\nBREAKING CHANGE: lists hold 50 items").unwrap(),
+    ///     parse("fix: typo").unwrap(),
+    ///     parse("fix: bump lockfile [skip changelog]").unwrap(),
+    /// ]);
+    ///
+    /// assert_eq!(unreleased.version, None);
+    /// assert_eq!(unreleased.sections[0].commit_type, CommitType::BugFix);
+    /// assert_eq!(unreleased.breaking_change_notes()[0].description, "lists hold 50 items");
+    ///
+    /// let markdown = MarkdownRenderer::default().render(&unreleased);
+    /// assert!(markdown.starts_with("## Unreleased\n#### Bug Fixes\n- typo\n"));
+    /// ```
+    pub fn unreleased<I, E>(&self, commits: I) -> ChangelogSections
+    where
+        I: IntoIterator<Item = E>,
+        E: Into<ChangelogEntry>,
+    {
+        let mut sections =
+            ChangelogSections::from_entries_excluding(commits, &self.exclusion_markers);
+        sections.sort_sections(&self.type_order);
+        sections
+    }
+
+    /// Partition commits into releases. Commits are expected newest first as printed by
+    /// `git log`, paired with the tag pointing at them if any: a tagged commit starts a release
+    /// holding it and every older commit up to the next tag. Commits newer than the latest tag
+    /// form an unreleased first section, omitted when empty.
+    ///
+    /// Each release [`ChangelogSections::previous_version`] is the next older tag, so compare
+    /// links are rendered between consecutive tags.
+    ///
+    /// # Example :
+    /// ```
+    /// use conventional_commit_parser::changelog::Changelog;
+    /// use conventional_commit_parser::parse;
+    ///
+    /// let log = vec![
+    ///     (None, parse("fix: typo").unwrap()),
+    ///     (Some("v1.1.0"), parse("feat: add releases").unwrap()),
+    ///     (None, parse("fix: a fix").unwrap()),
+    ///     (Some("v1.0.0"), parse("feat: initial release").unwrap()),
+    /// ];
+    ///
+    /// let releases = Changelog::default().releases(log);
+    ///
+    /// assert_eq!(releases.len(), 3);
+    /// assert_eq!(releases[0].version, None);
+    /// assert_eq!(releases[0].previous_version.as_deref(), Some("v1.1.0"));
+    /// assert_eq!(releases[1].version.as_deref(), Some("v1.1.0"));
+    /// assert_eq!(releases[1].previous_version.as_deref(), Some("v1.0.0"));
+    /// assert_eq!(releases[1].entries().count(), 2);
+    /// assert_eq!(releases[2].previous_version, None);
+    /// ```
+    pub fn releases<I, T, E>(&self, commits: I) -> Vec<ChangelogSections>
+    where
+        I: IntoIterator<Item = (Option<T>, E)>,
+        T: Into<String>,
+        E: Into<ChangelogEntry>,
+    {
+        let mut releases: Vec<(Option<String>, Vec<ChangelogEntry>)> = vec![(None, vec![])];
+        for (tag, entry) in commits {
+            if let Some(tag) = tag {
+                releases.push((Some(tag.into()), vec![]));
+            }
+
+            if let Some((_, entries)) = releases.last_mut() {
+                entries.push(entry.into());
+            }
+        }
+
+        if releases[0].1.is_empty() {
+            releases.remove(0);
+        }
+
+        let previous_versions: Vec<Option<String>> = releases
+            .iter()
+            .skip(1)
+            .map(|(tag, _)| tag.clone())
+            .chain(std::iter::once(None))
+            .collect();
+
+        releases
+            .into_iter()
+            .zip(previous_versions)
+            .map(|((version, entries), previous_version)| {
+                let mut sections = self.unreleased(entries);
+                sections.version = version;
+                sections.previous_version = previous_version;
+                sections
+            })
+            .collect()
+    }
+}
+
+impl ChangelogSections {
+    /// Group entries by commit type, preserving the order of entries within a section.
+    /// Every entry is kept, see [`ChangelogSections::from_entries_excluding`] to omit
+    /// excluded commits.
+    ///
+    /// # Example :
+    /// ```
+    /// use conventional_commit_parser::changelog::ChangelogSections;
+    /// use conventional_commit_parser::commit::CommitType;
+    /// use conventional_commit_parser::parse;
+    ///
+    /// let commits = vec![
+    ///     parse("fix: a fix").unwrap(),
+    ///     parse("feat: a feature").unwrap(),
+    /// ];
+    ///
+    /// let sections = ChangelogSections::from_entries(commits);
+    ///
+    /// assert_eq!(sections.sections[0].commit_type, CommitType::Feature);
+    /// assert_eq!(sections.sections[1].commit_type, CommitType::BugFix);
+    /// ```
+    pub fn from_entries<I, E>(entries: I) -> Self
+    where
+        I: IntoIterator<Item = E>,
+        E: Into<ChangelogEntry>,
+    {
+        let mut grouped: BTreeMap<CommitType, Vec<ChangelogEntry>> = BTreeMap::new();
+        for entry in entries {
+            let entry = entry.into();
+            grouped
+                .entry(entry.commit.commit_type.clone())
+                .or_default()
+                .push(entry);
+        }
+
+        let sections = grouped
+            .into_iter()
+            .map(|(commit_type, entries)| ChangelogSection {
+                commit_type,
+                entries,
+            })
+            .collect();
+
+        ChangelogSections {
+            sections,
+            ..Default::default()
+        }
+    }
+
+    /// Group entries by commit type like [`ChangelogSections::from_entries`], omitting the
+    /// entries carrying one of the given markers
+    pub fn from_entries_excluding<I, E>(entries: I, markers: &ExclusionMarkers) -> Self
+    where
+        I: IntoIterator<Item = E>,
+        E: Into<ChangelogEntry>,
+    {
+        let entries = entries
+            .into_iter()
+            .map(Into::into)
+            .filter(|entry: &ChangelogEntry| !entry.commit.is_changelog_excluded_by(markers));
+
+        ChangelogSections::from_entries(entries)
+    }
+
+    /// Reorder sections according to `type_order`, see [`type_priority`]
+    ///
+    /// # Example :
+    /// ```
+    /// use conventional_commit_parser::changelog::ChangelogSections;
+    /// use conventional_commit_parser::commit::CommitType;
+    /// use conventional_commit_parser::parse;
+    ///
+    /// let mut sections = ChangelogSections::from_entries(vec![
+    ///     parse("docs: a documentation change").unwrap(),
+    ///     parse("feat: a feature").unwrap(),
+    ///     parse("fix: a fix").unwrap(),
+    /// ]);
+    ///
+    /// sections.sort_sections(&[CommitType::BugFix, CommitType::Feature]);
+    ///
+    /// assert_eq!(sections.sections[0].commit_type, CommitType::BugFix);
+    /// assert_eq!(sections.sections[1].commit_type, CommitType::Feature);
+    /// assert_eq!(sections.sections[2].commit_type, CommitType::Documentation);
+    /// ```
+    pub fn sort_sections(&mut self, type_order: &[CommitType]) {
+        self.sections
+            .sort_by_key(|section| type_priority(&section.commit_type, type_order));
+    }
+
+    /// Sections in rendering order, reordered according to `type_order` when it is not empty
+    pub(crate) fn ordered_sections(&self, type_order: &[CommitType]) -> Vec<&ChangelogSection> {
+        let mut sections: Vec<&ChangelogSection> = self.sections.iter().collect();
+        sections.sort_by_key(|section| type_priority(&section.commit_type, type_order));
+        sections
+    }
+
+    /// Render the compare link between the previous version and this one,
+    /// `HEAD` is used as the upper bound for unreleased changes
+    pub fn compare_url(&self, remote: &Remote) -> Option<String> {
+        let previous = self.previous_version.as_deref()?;
+        let current = self.version.as_deref().unwrap_or(UNRELEASED_TAG);
+        remote.compare_url(previous, current)
+    }
+
+    /// Iterate over every entry, in section order
+    pub fn entries(&self) -> impl Iterator<Item = &ChangelogEntry> {
+        self.sections
+            .iter()
+            .flat_map(|section| section.entries.iter())
+    }
+
+    /// Iterate over breaking change entries
+    pub fn breaking_changes(&self) -> impl Iterator<Item = &ChangelogEntry> {
+        self.entries()
+            .filter(|entry| entry.commit.is_breaking_change)
+    }
+
+    /// Breaking changes with their description and migration note, in section order
+    pub fn breaking_change_notes(&self) -> Vec<BreakingChangeNote<'_>> {
+        self.breaking_changes()
+            .filter_map(|entry| {
+                Some(BreakingChangeNote {
+                    entry,
+                    description: entry.commit.breaking_change_description()?,
+                    migration: entry.commit.breaking_change_migration(),
+                })
+            })
+            .collect()
+    }
+
+    /// Iterate over entries carrying a `Security` or `CVE` footer
+    pub fn security_fixes(&self) -> impl Iterator<Item = &ChangelogEntry> {
+        self.entries()
+            .filter(|entry| entry.commit.is_security_fix())
+    }
+}
+
+/// Priority of a commit type in `type_order`, lower values come first. Types missing from
+/// `type_order` come after every listed type, sorting by priority being stable they keep
+/// their previous relative order.
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::changelog::type_priority;
+/// use conventional_commit_parser::commit::CommitType;
+///
+/// let order = [CommitType::BugFix, CommitType::Feature];
+///
+/// assert!(type_priority(&CommitType::BugFix, &order) < type_priority(&CommitType::Feature, &order));
+/// assert!(type_priority(&CommitType::Feature, &order) < type_priority(&CommitType::Chore, &order));
+/// ```
+pub fn type_priority(commit_type: &CommitType, type_order: &[CommitType]) -> usize {
+    type_order
+        .iter()
+        .position(|ordered| ordered == commit_type)
+        .unwrap_or(type_order.len())
+}
+
+/// Return true if the commit header matches a release commit template, so history scanners
+/// can find the previous release. See [`ReleaseCommitTemplate::version`] to extract the version.
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::changelog::is_release_commit;
+/// use conventional_commit_parser::changelog::template::ReleaseCommitTemplate;
+/// use conventional_commit_parser::parse;
+///
+/// let template = ReleaseCommitTemplate::default();
+///
+/// assert!(is_release_commit(&parse("chore(version): 1.0.0").unwrap(), &template));
+/// assert!(!is_release_commit(&parse("chore: 1.0.0").unwrap(), &template));
+/// ```
+pub fn is_release_commit(commit: &ConventionalCommit, template: &ReleaseCommitTemplate) -> bool {
+    template.version(commit).is_some()
+}
+
+/// Default section title of a commit type
+fn section_title(commit_type: &CommitType) -> &str {
+    match commit_type {
+        CommitType::Feature => "Features",
+        CommitType::BugFix => "Bug Fixes",
+        CommitType::Chore => "Miscellaneous Chores",
+        CommitType::Revert => "Revert",
+        CommitType::Performances => "Performance Improvements",
+        CommitType::Documentation => "Documentation",
+        CommitType::Style => "Style",
+        CommitType::Refactor => "Refactoring",
+        CommitType::Test => "Tests",
+        CommitType::Build => "Build system",
+        CommitType::Ci => "Continuous Integration",
+        CommitType::Custom(custom) => custom,
+    }
+}
+
+pub(crate) fn short_sha(sha: &str) -> &str {
+    sha.get(..7).unwrap_or(sha)
+}
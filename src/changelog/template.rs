@@ -0,0 +1,478 @@
+//! URL templates used to build commit, compare, issue and user links.
+//!
+//! A template is a plain string containing `{{variable}}` placeholders, for instance
+//! `https://{{host}}/{{owner}}/{{repository}}/commit/{{sha}}`. Placeholders other than the
+//! built-in ones must be declared with [`UrlTemplate::parse_with_placeholders`], and `\{{`
+//! renders a literal `{{`.
+
+use pest::error::{Error as PestError, ErrorVariant};
+use pest::Parser;
+
+use crate::commit::ConventionalCommit;
+use crate::error::TemplateError;
+
+#[doc(hidden)]
+#[derive(Parser)]
+#[grammar = "changelog/template.pest"]
+struct TemplateParser;
+
+/// A template token, either a literal string or a placeholder
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Token {
+    Literal(String),
+    /// `{{host}}`: the remote host, `github.com`
+    Host,
+    /// `{{owner}}`: the repository owner or organization
+    Owner,
+    /// `{{repository}}`: the repository name
+    Repository,
+    /// `{{sha}}`: a full commit sha
+    Sha,
+    /// `{{issue}}`: an issue number or key
+    Issue,
+    /// `{{previous_tag}}`: the tag of the previous release
+    PreviousTag,
+    /// `{{current_tag}}`: the tag of the release being rendered
+    CurrentTag,
+    /// `{{username}}`: a user handle on the remote
+    Username,
+    /// `{{version}}`: the version being released
+    Version,
+    /// `{{date}}`: the release date
+    Date,
+    /// A user-defined placeholder, holding its name
+    Custom(String),
+}
+
+/// Values available to fill template placeholders
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TemplateContext<'a> {
+    pub host: Option<&'a str>,
+    pub owner: Option<&'a str>,
+    pub repository: Option<&'a str>,
+    pub sha: Option<&'a str>,
+    pub issue: Option<&'a str>,
+    pub previous_tag: Option<&'a str>,
+    pub current_tag: Option<&'a str>,
+    pub username: Option<&'a str>,
+    pub version: Option<&'a str>,
+    pub date: Option<&'a str>,
+    /// Values of user-defined placeholders, by name
+    pub custom: &'a [(&'a str, &'a str)],
+}
+
+/// A parsed URL template
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UrlTemplate {
+    tokens: Vec<Token>,
+}
+
+/// The set of URL templates used by the changelog renderers, a missing template disables
+/// the corresponding links.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UrlTemplates {
+    /// Link to a single commit, typically using `{{sha}}`
+    pub commit: Option<UrlTemplate>,
+    /// Link comparing two releases, typically using `{{previous_tag}}` and `{{current_tag}}`
+    pub compare: Option<UrlTemplate>,
+    /// Link to an issue or pull request, typically using `{{issue}}`
+    pub issue: Option<UrlTemplate>,
+    /// Link to a user profile, typically using `{{username}}`
+    pub user: Option<UrlTemplate>,
+}
+
+/// The header of the commits created when bumping a version, e.g. `chore(version): {{version}}`,
+/// see [`crate::changelog::is_release_commit`]. Every placeholder matches a non-empty part of the
+/// header, `{{version}}` is the one extracted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReleaseCommitTemplate {
+    template: UrlTemplate,
+}
+
+/// A git hosting provider with built-in URL templates, see [`Provider::templates`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Provider {
+    GitHub,
+    GitLab,
+    Bitbucket,
+    Gitea,
+    /// Issues are linked to the `todo.sr.ht` tracker of the same name, SourceHut has no
+    /// compare page so compare links are disabled
+    SourceHut,
+}
+
+/// A git remote and the URL templates used to link to it
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Remote {
+    /// The remote host, `github.com`
+    pub host: String,
+    pub owner: String,
+    pub repository: String,
+    pub templates: UrlTemplates,
+}
+
+impl ReleaseCommitTemplate {
+    /// Parse a release commit header template
+    pub fn parse(template: &str) -> Result<ReleaseCommitTemplate, TemplateError> {
+        UrlTemplate::parse(template).map(|template| ReleaseCommitTemplate { template })
+    }
+
+    /// Returns the version of a release commit, `None` if the commit header does not match
+    /// the template. Templates without a `{{version}}` placeholder return an empty version.
+    ///
+    /// # Example :
+    /// ```
+    /// use conventional_commit_parser::changelog::template::ReleaseCommitTemplate;
+    /// use conventional_commit_parser::parse;
+    ///
+    /// let template = ReleaseCommitTemplate::parse("chore(release): v{{version}} [{{date}}]").unwrap();
+    ///
+    /// let commit = parse("chore(release): v1.2.0 [2022-01-17]").unwrap();
+    /// assert_eq!(template.version(&commit), Some("1.2.0".to_string()));
+    ///
+    /// let commit = parse("chore(release): prepare v1.2.0").unwrap();
+    /// assert_eq!(template.version(&commit), None);
+    /// ```
+    pub fn version(&self, commit: &ConventionalCommit) -> Option<String> {
+        let header = commit.header();
+        let mut version = None;
+        match_tokens(self.template.tokens(), &header, &mut version)
+            .then(|| version.unwrap_or_default().to_string())
+    }
+}
+
+impl Default for ReleaseCommitTemplate {
+    /// The cocogitto bump commit template, `chore(version): {{version}}`
+    fn default() -> Self {
+        ReleaseCommitTemplate::parse("chore(version): {{version}}")
+            .expect("valid built-in template")
+    }
+}
+
+/// Match `text` against template tokens, capturing the `{{version}}` placeholder.
+/// Placeholders match as few characters as possible, backtracking when the rest fails.
+fn match_tokens<'a>(tokens: &[Token], text: &'a str, version: &mut Option<&'a str>) -> bool {
+    let Some((token, rest)) = tokens.split_first() else {
+        return text.is_empty();
+    };
+
+    if let Token::Literal(literal) = token {
+        return text
+            .strip_prefix(literal.as_str())
+            .is_some_and(|text| match_tokens(rest, text, version));
+    }
+
+    text.char_indices()
+        .skip(1)
+        .map(|(end, _)| end)
+        .chain(std::iter::once(text.len()))
+        .filter(|end| *end > 0)
+        .any(|end| {
+            if !match_tokens(rest, &text[end..], version) {
+                return false;
+            }
+
+            if *token == Token::Version {
+                *version = Some(&text[..end]);
+            }
+            true
+        })
+}
+
+impl Provider {
+    /// The public instance host of the provider, self-hosted instances should override
+    /// [`Remote::host`]
+    pub fn default_host(&self) -> &'static str {
+        match self {
+            Provider::GitHub => "github.com",
+            Provider::GitLab => "gitlab.com",
+            Provider::Bitbucket => "bitbucket.org",
+            Provider::Gitea => "gitea.com",
+            Provider::SourceHut => "git.sr.ht",
+        }
+    }
+
+    /// The commit, compare, issue and user URL templates of the provider
+    ///
+    /// # Example :
+    /// ```
+    /// use conventional_commit_parser::changelog::template::{Provider, TemplateContext};
+    ///
+    /// let templates = Provider::GitLab.templates();
+    ///
+    /// let context = TemplateContext {
+    ///     host: Some("gitlab.com"),
+    ///     owner: Some("oknozor"),
+    ///     repository: Some("cocogitto"),
+    ///     sha: Some("1df053f"),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(
+    ///     templates.commit.unwrap().render(&context),
+    ///     Some("https://gitlab.com/oknozor/cocogitto/-/commit/1df053f".to_string())
+    /// );
+    /// ```
+    pub fn templates(&self) -> UrlTemplates {
+        let (commit, compare, issue, user) = match self {
+            Provider::GitHub => (
+                "https://{{host}}/{{owner}}/{{repository}}/commit/{{sha}}",
+                Some("https://{{host}}/{{owner}}/{{repository}}/compare/{{previous_tag}}...{{current_tag}}"),
+                "https://{{host}}/{{owner}}/{{repository}}/issues/{{issue}}",
+                Some("https://{{host}}/{{username}}"),
+            ),
+            Provider::GitLab => (
+                "https://{{host}}/{{owner}}/{{repository}}/-/commit/{{sha}}",
+                Some("https://{{host}}/{{owner}}/{{repository}}/-/compare/{{previous_tag}}...{{current_tag}}"),
+                "https://{{host}}/{{owner}}/{{repository}}/-/issues/{{issue}}",
+                Some("https://{{host}}/{{username}}"),
+            ),
+            Provider::Bitbucket => (
+                "https://{{host}}/{{owner}}/{{repository}}/commits/{{sha}}",
+                Some("https://{{host}}/{{owner}}/{{repository}}/branches/compare/{{current_tag}}%0D{{previous_tag}}"),
+                "https://{{host}}/{{owner}}/{{repository}}/issues/{{issue}}",
+                None,
+            ),
+            Provider::Gitea => (
+                "https://{{host}}/{{owner}}/{{repository}}/commit/{{sha}}",
+                Some("https://{{host}}/{{owner}}/{{repository}}/compare/{{previous_tag}}...{{current_tag}}"),
+                "https://{{host}}/{{owner}}/{{repository}}/issues/{{issue}}",
+                Some("https://{{host}}/{{username}}"),
+            ),
+            Provider::SourceHut => (
+                "https://{{host}}/~{{owner}}/{{repository}}/commit/{{sha}}",
+                None,
+                "https://todo.sr.ht/~{{owner}}/{{repository}}/{{issue}}",
+                Some("https://sr.ht/~{{username}}"),
+            ),
+        };
+
+        let template =
+            |template: &str| Some(UrlTemplate::parse(template).expect("valid built-in template"));
+
+        UrlTemplates {
+            commit: template(commit),
+            compare: compare.and_then(template),
+            issue: template(issue),
+            user: user.and_then(template),
+        }
+    }
+}
+
+impl Remote {
+    /// A remote on the public instance of a provider, using the provider URL templates
+    ///
+    /// # Example :
+    /// ```
+    /// use conventional_commit_parser::changelog::template::{Provider, Remote};
+    ///
+    /// let remote = Remote::from_provider(Provider::GitHub, "oknozor", "cocogitto");
+    ///
+    /// assert_eq!(
+    ///     remote.compare_url("1.0.0", "1.1.0"),
+    ///     Some("https://github.com/oknozor/cocogitto/compare/1.0.0...1.1.0".to_string())
+    /// );
+    /// ```
+    pub fn from_provider(provider: Provider, owner: &str, repository: &str) -> Self {
+        Remote {
+            host: provider.default_host().to_string(),
+            owner: owner.to_string(),
+            repository: repository.to_string(),
+            templates: provider.templates(),
+        }
+    }
+
+    /// A template context holding the remote host, owner and repository
+    pub fn context(&self) -> TemplateContext<'_> {
+        TemplateContext {
+            host: Some(&self.host),
+            owner: Some(&self.owner),
+            repository: Some(&self.repository),
+            ..Default::default()
+        }
+    }
+
+    /// Render the commit link for the given sha
+    pub fn commit_url(&self, sha: &str) -> Option<String> {
+        let context = TemplateContext {
+            sha: Some(sha),
+            ..self.context()
+        };
+
+        self.templates.commit.as_ref()?.render(&context)
+    }
+
+    /// Render the compare link between two tags
+    pub fn compare_url(&self, previous_tag: &str, current_tag: &str) -> Option<String> {
+        let context = TemplateContext {
+            previous_tag: Some(previous_tag),
+            current_tag: Some(current_tag),
+            ..self.context()
+        };
+
+        self.templates.compare.as_ref()?.render(&context)
+    }
+
+    /// Render the link to the given issue
+    pub fn issue_url(&self, issue: &str) -> Option<String> {
+        let context = TemplateContext {
+            issue: Some(issue),
+            ..self.context()
+        };
+
+        self.templates.issue.as_ref()?.render(&context)
+    }
+
+    /// Render the profile link of the given user
+    pub fn user_url(&self, username: &str) -> Option<String> {
+        let context = TemplateContext {
+            username: Some(username),
+            ..self.context()
+        };
+
+        self.templates.user.as_ref()?.render(&context)
+    }
+}
+
+impl UrlTemplate {
+    /// Parse a template string
+    ///
+    /// # Example :
+    /// ```
+    /// # use conventional_commit_parser::error::TemplateError;
+    /// # fn main() -> Result<(), TemplateError> {
+    /// use conventional_commit_parser::changelog::template::{TemplateContext, UrlTemplate};
+    ///
+    /// let template = UrlTemplate::parse("https://{{host}}/{{owner}}/{{repository}}/commit/{{sha}}")?;
+    ///
+    /// let context = TemplateContext {
+    ///     host: Some("github.com"),
+    ///     owner: Some("oknozor"),
+    ///     repository: Some("cocogitto"),
+    ///     sha: Some("1df053f"),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(
+    ///     template.render(&context),
+    ///     Some("https://github.com/oknozor/cocogitto/commit/1df053f".to_string())
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse(template: &str) -> Result<UrlTemplate, TemplateError> {
+        Self::parse_with_placeholders(template, &[])
+    }
+
+    /// Parse a template string using user-defined placeholders on top of the built-in ones,
+    /// undeclared placeholders are rejected to catch typos
+    ///
+    /// # Example :
+    /// ```
+    /// # use conventional_commit_parser::error::TemplateError;
+    /// # fn main() -> Result<(), TemplateError> {
+    /// use conventional_commit_parser::changelog::template::{TemplateContext, UrlTemplate};
+    ///
+    /// let template = UrlTemplate::parse_with_placeholders(
+    ///     "https://{{host}}/{{team}}/releases/{{version}}?raw=\\{{version}}",
+    ///     &["team"],
+    /// )?;
+    ///
+    /// let context = TemplateContext {
+    ///     host: Some("example.org"),
+    ///     version: Some("1.0.0"),
+    ///     custom: &[("team", "parser")],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(
+    ///     template.render(&context),
+    ///     Some("https://example.org/parser/releases/1.0.0?raw={{version}}".to_string())
+    /// );
+    /// assert!(UrlTemplate::parse_with_placeholders("{{teams}}", &["team"]).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_with_placeholders(
+        template: &str,
+        placeholders: &[&str],
+    ) -> Result<UrlTemplate, TemplateError> {
+        let pairs = TemplateParser::parse(Rule::template, template)
+            .map_err(TemplateError::from)?
+            .next()
+            .unwrap();
+
+        let mut tokens = vec![];
+        for pair in pairs.into_inner() {
+            match pair.as_rule() {
+                Rule::literal => tokens.push(Token::Literal(pair.as_str().to_string())),
+                Rule::escaped_braces => tokens.push(Token::Literal("{{".to_string())),
+                Rule::placeholder => {
+                    let variable = pair.into_inner().next().unwrap();
+                    tokens.push(match variable.as_rule() {
+                        Rule::host => Token::Host,
+                        Rule::owner => Token::Owner,
+                        Rule::repository => Token::Repository,
+                        Rule::sha => Token::Sha,
+                        Rule::issue => Token::Issue,
+                        Rule::previous_tag => Token::PreviousTag,
+                        Rule::current_tag => Token::CurrentTag,
+                        Rule::username => Token::Username,
+                        Rule::version => Token::Version,
+                        Rule::date => Token::Date,
+                        Rule::custom if placeholders.contains(&variable.as_str()) => {
+                            Token::Custom(variable.as_str().to_string())
+                        }
+                        Rule::custom => {
+                            let message =
+                                format!("Unknown template placeholder `{}`", variable.as_str());
+                            let error = PestError::new_from_span(
+                                ErrorVariant::CustomError { message },
+                                variable.as_span(),
+                            );
+                            return Err(TemplateError::from(error));
+                        }
+                        other => unreachable!("Unexpected template variable : `{:?}`", other),
+                    })
+                }
+                _ => (),
+            }
+        }
+
+        Ok(UrlTemplate { tokens })
+    }
+
+    /// The template tokens, in order
+    pub fn tokens(&self) -> &[Token] {
+        &self.tokens
+    }
+
+    /// Render the template, returns `None` if a placeholder has no value in the given context
+    pub fn render(&self, context: &TemplateContext) -> Option<String> {
+        let mut rendered = String::new();
+        for token in &self.tokens {
+            let value = match token {
+                Token::Literal(literal) => Some(literal.as_str()),
+                Token::Host => context.host,
+                Token::Owner => context.owner,
+                Token::Repository => context.repository,
+                Token::Sha => context.sha,
+                Token::Issue => context.issue,
+                Token::PreviousTag => context.previous_tag,
+                Token::CurrentTag => context.current_tag,
+                Token::Username => context.username,
+                Token::Version => context.version,
+                Token::Date => context.date,
+                Token::Custom(name) => context
+                    .custom
+                    .iter()
+                    .find(|(custom, _)| custom == name)
+                    .map(|(_, value)| *value),
+            };
+
+            rendered.push_str(value?);
+        }
+
+        Some(rendered)
+    }
+}
@@ -1,22 +1,39 @@
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::fmt;
 use std::fmt::Formatter;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
 
 use pest::iterators::Pair;
 
 use crate::commit::CommitType::*;
+use crate::error::ValueError;
+use crate::value::Identity;
 use crate::Rule;
 
+/// Markers asking CI systems not to build a commit, matched case-insensitively
+const CI_SKIP_MARKERS: [&str; 5] = [
+    "[skip ci]",
+    "[ci skip]",
+    "[no ci]",
+    "[skip actions]",
+    "[actions skip]",
+];
+
 /// A commit type consist of a noun describing the kind of modification made.
 /// In addition to the mandatory `fix` and `feat` type, common commit types taken from
 /// [the angular convention](https://github.com/angular/angular/blob/22b96b9/CONTRIBUTING.md#-commit-message-guidelines)
 /// as their own enum variant. Other type will be parser as [`CommitType::Custom`]
-#[derive(Hash, Eq, PartialEq, Ord, PartialOrd, Debug, Clone)]
+#[derive(Hash, Eq, PartialEq, Ord, PartialOrd, Debug, Clone, Default)]
 pub enum CommitType {
     /// *feat*: a commit of the type `feat` introduces a new feature to the codebase (this correlates with `MINOR` in Semantic Versioning).
     Feature,
     /// *fix*: a commit of the type `fix` patches a bug in your codebase (this correlates with `PATCH` in Semantic Versioning).
     BugFix,
     /// *chore*: Miscellaneous chores
+    #[default]
     Chore,
     /// See [How does Conventional Commits handle revert commits?](https://www.conventionalcommits.org/en/v1.0.0/#how-does-conventional-commits-handle-revert-commits)
     Revert,
@@ -40,7 +57,7 @@ pub enum CommitType {
 
 /// One or more footers MAY be provided one blank line after the body. Each footer MUST consist of
 /// a word token, followed by either a :<space> or <space># separator, followed by a string value.
-#[derive(Debug, Eq, PartialEq, Default, Clone)]
+#[derive(Debug, Eq, PartialEq, Hash, Default, Clone)]
 pub struct Footer {
     /// The footer token, either BREAKING CHANGE or a work token
     pub token: String,
@@ -50,10 +67,106 @@ pub struct Footer {
     pub token_separator: Separator,
 }
 
+/// An external fact attached to a commit, such as the number of files it changes,
+/// see [`ConventionalCommit::annotations`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
+pub enum Annotation {
+    Bool(bool),
+    Integer(i64),
+    Text(String),
+}
+
+/// Security metadata carried by a commit footer, see [`ConventionalCommit::security_advisories`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SecurityAdvisory<'a> {
+    /// A free form `Security` footer, for instance a GHSA identifier or a short description
+    Security(&'a str),
+    /// A `CVE` footer, holding a CVE identifier such as `CVE-2021-44228`
+    Cve(&'a str),
+}
+
+/// A bot account found in a `Signed-off-by` or `Co-authored-by` footer,
+/// see [`ConventionalCommit::bot_signature`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BotSignature<'a> {
+    /// `dependabot[bot]`
+    Dependabot,
+    /// `renovate[bot]`
+    Renovate,
+    /// `github-actions[bot]`
+    GithubActions,
+    /// Any other `<name>[bot]` account, holding the full account name
+    Other(&'a str),
+}
+
+/// Outcome of a DCO sign-off check, see [`ConventionalCommit::check_sign_off`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignOffCheck {
+    /// A `Signed-off-by` footer matches the author
+    Signed,
+    /// The commit has no `Signed-off-by` footer
+    Missing,
+    /// No `Signed-off-by` footer matches the author, holding the signing identities
+    Mismatch(Vec<Identity>),
+}
+
+/// Commit message rendering options, see [`ConventionalCommit::format`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FormatOptions {
+    /// Render every footer with this separator, `None` preserves the parsed separators
+    pub footer_separator: Option<Separator>,
+    /// Render footer tokens title-cased (`signed-off-by` -> `Signed-off-by`),
+    /// see [`Footer::title_case_token`]
+    pub title_case_tokens: bool,
+    /// Render the [`ConventionalCommit::pre_header_tag`] before the header, such messages are
+    /// only parsed back with [`crate::options::ParserOptions::accept_pre_header_tag`]
+    pub pre_header_tag: bool,
+}
+
+/// A piece of the commit summary, see [`ConventionalCommit::summary_fragments`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SummaryFragment<'a> {
+    Text(&'a str),
+    /// A backtick delimited code span, without its delimiters
+    Code(&'a str),
+    /// An issue reference number, without the leading `#`
+    Issue(&'a str),
+}
+
+/// Where a breaking change is declared, see [`ConventionalCommit::breaking_change_origin`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BreakingOrigin {
+    /// A `!` mark after the commit type and scope, the summary describes the change
+    Mark,
+    /// A `BREAKING CHANGE` footer describing the change
+    Footer,
+    /// Both a `!` mark and a `BREAKING CHANGE` footer
+    Both,
+}
+
+/// A commit message generated by `git revert`, see [`crate::parse_revert`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RevertCommit<'a> {
+    /// The header of the reverted commit, between the quotes of `Revert "<header>"`
+    pub reverted_header: &'a str,
+    /// The hashes of every `This reverts commit <sha>.` line, in message order
+    pub reverted_shas: Vec<&'a str>,
+}
+
+impl RevertCommit<'_> {
+    /// Parse the header of the reverted commit, see [`crate::parse_summary`]
+    pub fn reverted_commit(&self) -> Result<ConventionalCommit, crate::error::ParseError> {
+        crate::parse_summary(self.reverted_header)
+    }
+}
+
 /// Footer token separator the "#" separator is
 /// often use to reference github issues.
-#[derive(Debug, Eq, PartialEq, Clone)]
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Default)]
 pub enum Separator {
+    #[default]
     Colon,
     ColonWithNewLine,
     Hash,
@@ -70,13 +183,163 @@ impl From<&str> for Separator {
     }
 }
 
-impl Default for Separator {
-    fn default() -> Self {
-        Separator::Colon
+impl Separator {
+    /// Returns the separator as written in commit messages
+    ///
+    /// # Example :
+    /// ```
+    /// use conventional_commit_parser::commit::Separator;
+    ///
+    /// assert_eq!(Separator::Colon.as_str(), ": ");
+    /// assert_eq!(Separator::Hash.as_str(), " #");
+    /// assert_eq!(Separator::ColonWithNewLine.as_str(), ":\n");
+    /// ```
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Separator::Colon => ": ",
+            Separator::ColonWithNewLine => ":\n",
+            Separator::Hash => " #",
+        }
+    }
+
+    /// Colon separators followed by a space or a new line are considered equivalent,
+    /// this returns [`Separator::Colon`] for both.
+    pub fn canonical(&self) -> Separator {
+        match self {
+            Separator::Colon | Separator::ColonWithNewLine => Separator::Colon,
+            Separator::Hash => Separator::Hash,
+        }
     }
 }
 
 impl Footer {
+    /// Returns the footer token, with `BREAKING-CHANGE` normalized to `BREAKING CHANGE`
+    pub fn canonical_token(&self) -> &str {
+        if self.is_breaking_change() {
+            "BREAKING CHANGE"
+        } else {
+            &self.token
+        }
+    }
+
+    /// Returns the footer token with its first letter upper-cased and the rest lower-cased,
+    /// as written by git (`Signed-off-by`, `Co-authored-by`). Breaking change tokens are
+    /// returned unchanged.
+    ///
+    /// # Example :
+    /// ```
+    /// use conventional_commit_parser::parse_footers;
+    ///
+    /// let footers = parse_footers("signed-off-by: Z\nBREAKING CHANGE: removed").unwrap();
+    ///
+    /// assert_eq!(footers[0].title_case_token(), "Signed-off-by");
+    /// assert_eq!(footers[1].title_case_token(), "BREAKING CHANGE");
+    /// ```
+    pub fn title_case_token(&self) -> String {
+        if self.is_breaking_change() {
+            return self.token.clone();
+        }
+
+        let mut chars = self.token.chars();
+        match chars.next() {
+            Some(first) => first
+                .to_uppercase()
+                .chain(chars.flat_map(char::to_lowercase))
+                .collect(),
+            None => String::new(),
+        }
+    }
+
+    /// Return true if the footer token is `Deprecates` (case-insensitive)
+    pub fn is_deprecation(&self) -> bool {
+        self.token.eq_ignore_ascii_case("Deprecates")
+    }
+
+    /// Return the security advisory held by a `Security` or `CVE` footer (case-insensitive)
+    pub fn security_advisory(&self) -> Option<SecurityAdvisory<'_>> {
+        if self.token.eq_ignore_ascii_case("Security") {
+            Some(SecurityAdvisory::Security(&self.content))
+        } else if self.token.eq_ignore_ascii_case("CVE") {
+            Some(SecurityAdvisory::Cve(&self.content))
+        } else {
+            None
+        }
+    }
+
+    /// Return the bot account of a `Signed-off-by` or `Co-authored-by` footer (case-insensitive)
+    pub fn bot_signature(&self) -> Option<BotSignature<'_>> {
+        let is_signature = self.token.eq_ignore_ascii_case("Signed-off-by")
+            || self.token.eq_ignore_ascii_case("Co-authored-by");
+
+        if !is_signature {
+            return None;
+        }
+
+        let name = self
+            .content
+            .split_once('<')
+            .map_or(self.content.as_str(), |(name, _)| name)
+            .trim();
+
+        match name {
+            "dependabot[bot]" => Some(BotSignature::Dependabot),
+            "renovate[bot]" => Some(BotSignature::Renovate),
+            "github-actions[bot]" => Some(BotSignature::GithubActions),
+            name if name.ends_with("[bot]") => Some(BotSignature::Other(name)),
+            _ => None,
+        }
+    }
+
+    fn canonical_eq(&self, other: &Footer) -> bool {
+        self.canonical_token() == other.canonical_token()
+            && self.content == other.content
+            && self.token_separator.canonical() == other.token_separator.canonical()
+    }
+
+    fn canonical_key(&self) -> (&str, &str, &'static str) {
+        (
+            self.canonical_token(),
+            &self.content,
+            self.token_separator.canonical().as_str(),
+        )
+    }
+
+    fn canonical_hash<H: Hasher>(&self, state: &mut H) {
+        self.canonical_token().hash(state);
+        self.content.hash(state);
+        self.token_separator.canonical().hash(state);
+    }
+
+    /// Parse the footer content into any type implementing [`FromStr`],
+    /// see the [`crate::value`] module for built-in value types.
+    /// ```rust
+    /// # fn main() {
+    /// use conventional_commit_parser::commit::Footer;
+    /// use conventional_commit_parser::value::{Date, Identity};
+    ///
+    /// let footer = Footer {
+    ///     token: "Deprecated-after".to_string(),
+    ///     content: "2025-01-01".to_string(),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let date = footer.value_as::<Date>().unwrap();
+    /// assert_eq!((date.year, date.month, date.day), (2025, 1, 1));
+    ///
+    /// let footer = Footer {
+    ///     token: "Reviewed-by".to_string(),
+    ///     content: "Z <z@example.com>".to_string(),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let reviewer = footer.value_as::<Identity>().unwrap();
+    /// assert_eq!(reviewer.email, "z@example.com");
+    /// # }
+    /// ```
+    pub fn value_as<T: FromStr>(&self) -> Result<T, T::Err> {
+        self.content.parse()
+    }
+
     /// Return true if a footer as the breaking change token
     /// ```rust
     /// # fn main() {
@@ -105,8 +368,15 @@ impl Footer {
 
 /// A conventional commit compliant commit message produced by the [parse] function
 ///
+/// Equality and hashing are canonical: commit types are compared case-insensitively,
+/// colon footer separators (`: ` and `:\n`) are equivalent and so are the `BREAKING CHANGE`
+/// and `BREAKING-CHANGE` tokens. Whether a breaking change is marked with `!` is not compared,
+/// only [`ConventionalCommit::is_breaking_change`] is, and neither are annotations nor
+/// breaking change tokens.
+/// Use [`ConventionalCommit::raw_eq`] for a strict comparison.
+///
 /// [parse]: crate::ConventionalCommitParser::parse
-#[derive(Debug, Eq, PartialEq, Clone)]
+#[derive(Debug, Clone)]
 pub struct ConventionalCommit {
     /// The commit type, `fix`, `feat` etc.
     pub commit_type: CommitType,
@@ -120,6 +390,24 @@ pub struct ConventionalCommit {
     pub footers: Vec<Footer>,
     /// A commit that has a footer `BREAKING CHANGE` or a `!` after the commit type and scope
     pub is_breaking_change: bool,
+    /// The header has a `!` mark after the commit type and scope, see
+    /// [`ConventionalCommit::breaking_change_origin`]
+    pub breaking_change_mark: bool,
+    /// The original commit sha of a cherry-picked commit, taken from the
+    /// `(cherry picked from commit <sha>)` line added by `git cherry-pick -x`
+    pub cherry_picked_from: Option<String>,
+    /// A bracketed tag preceding the commit type, such as the `JIRA-1` ticket key of
+    /// `[JIRA-1] feat: summary`, see [`crate::options::ParserOptions::accept_pre_header_tag`]
+    pub pre_header_tag: Option<String>,
+    /// External facts attached to the commit by the caller, e.g. `files_changed` or
+    /// `insertions` taken from `git diff --stat`, so that [`crate::policy`] rules can combine
+    /// message and diff data. Never set by the parser.
+    pub annotations: HashMap<String, Annotation>,
+    /// Footer tokens announcing a breaking change in addition to `BREAKING CHANGE`, e.g. a
+    /// translated `CHANGEMENT MAJEUR`, set by the parser from the
+    /// [`crate::options::ParserOptions::breaking_change_tokens`] used by the footers.
+    /// See [`ConventionalCommit::is_breaking_change_footer`].
+    pub breaking_change_tokens: Vec<String>,
 }
 
 impl From<Pair<'_, Rule>> for Footer {
@@ -147,11 +435,427 @@ impl Default for ConventionalCommit {
             footers: vec![],
             summary: "".to_string(),
             is_breaking_change: false,
+            breaking_change_mark: false,
+            cherry_picked_from: None,
+            pre_header_tag: None,
+            annotations: HashMap::new(),
+            breaking_change_tokens: vec![],
         }
     }
 }
 
 impl ConventionalCommit {
+    /// Compare two commits field by field, without any normalization
+    /// ```rust
+    /// # fn main() {
+    /// use conventional_commit_parser::commit::{CommitType, ConventionalCommit};
+    ///
+    /// let commit = ConventionalCommit {
+    ///     commit_type: CommitType::Custom("wip".to_string()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let upper_case = ConventionalCommit {
+    ///     commit_type: CommitType::Custom("WIP".to_string()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(commit, upper_case);
+    /// assert!(!commit.raw_eq(&upper_case));
+    /// # }
+    /// ```
+    pub fn raw_eq(&self, other: &ConventionalCommit) -> bool {
+        self.commit_type == other.commit_type
+            && self.scope == other.scope
+            && self.summary == other.summary
+            && self.body == other.body
+            && self.footers == other.footers
+            && self.is_breaking_change == other.is_breaking_change
+            && self.breaking_change_mark == other.breaking_change_mark
+            && self.cherry_picked_from == other.cherry_picked_from
+            && self.pre_header_tag == other.pre_header_tag
+            && self.annotations == other.annotations
+            && self.breaking_change_tokens == other.breaking_change_tokens
+    }
+
+    /// Returns the migration note of a breaking change, either a `Migration:` section of the
+    /// `BREAKING CHANGE` footer (indented so it is not parsed as a footer) or a `Migration`
+    /// footer following it
+    /// ```rust
+    /// # fn main() {
+    /// use conventional_commit_parser::parse;
+    ///
+    /// let commit = parse(
+    ///     "feat!: drop parse_summary\n\n\
+    ///     BREAKING CHANGE: parse_summary was removed\n\
+    ///     Migration: use parse instead",
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(commit.breaking_change_description(), Some("parse_summary was removed"));
+    /// assert_eq!(commit.breaking_change_migration(), Some("use parse instead"));
+    /// # }
+    /// ```
+    pub fn breaking_change_migration(&self) -> Option<&str> {
+        let index = self
+            .footers
+            .iter()
+            .position(|footer| self.is_breaking_change_footer(footer))?;
+        let footer = &self.footers[index];
+
+        if let Some((_, migration)) = split_migration(&footer.content) {
+            return Some(migration);
+        }
+
+        self.footers
+            .get(index + 1)
+            .filter(|footer| footer.token.eq_ignore_ascii_case("Migration"))
+            .map(|footer| footer.content.as_str())
+    }
+
+    /// Returns the description of a breaking change: the `BREAKING CHANGE` footer content
+    /// without its migration note, or the summary when the commit is only marked with `!`
+    pub fn breaking_change_description(&self) -> Option<&str> {
+        if !self.is_breaking_change {
+            return None;
+        }
+
+        match self
+            .footers
+            .iter()
+            .find(|footer| self.is_breaking_change_footer(footer))
+        {
+            Some(footer) => Some(
+                split_migration(&footer.content)
+                    .map_or(footer.content.as_str(), |(description, _)| description),
+            ),
+            None => Some(&self.summary),
+        }
+    }
+
+    /// Returns the content of every `Deprecates` footer
+    pub fn deprecations(&self) -> Vec<&str> {
+        self.footers
+            .iter()
+            .filter(|footer| footer.is_deprecation())
+            .map(|footer| footer.content.as_str())
+            .collect()
+    }
+
+    /// Returns the security advisories declared with `Security` and `CVE` footers
+    /// ```rust
+    /// # fn main() {
+    /// use conventional_commit_parser::parse;
+    /// use conventional_commit_parser::commit::SecurityAdvisory;
+    ///
+    /// let commit = parse("fix(log): disable message lookups\n\nCVE: CVE-2021-44228").unwrap();
+    ///
+    /// assert_eq!(commit.security_advisories(), vec![SecurityAdvisory::Cve("CVE-2021-44228")]);
+    /// # }
+    /// ```
+    pub fn security_advisories(&self) -> Vec<SecurityAdvisory<'_>> {
+        self.footers
+            .iter()
+            .filter_map(Footer::security_advisory)
+            .collect()
+    }
+
+    /// Return true if the commit has at least one `Security` or `CVE` footer
+    pub fn is_security_fix(&self) -> bool {
+        self.footers
+            .iter()
+            .any(|footer| footer.security_advisory().is_some())
+    }
+
+    /// Return true if the summary or the body contains a CI skip marker such as `[skip ci]`
+    /// or `[ci skip]`
+    ///
+    /// # Example :
+    /// ```
+    /// use conventional_commit_parser::parse;
+    ///
+    /// assert!(parse("docs: fix typo [skip ci]").unwrap().ci_skip());
+    /// assert!(parse("chore: release\n\n[CI SKIP]").unwrap().ci_skip());
+    /// assert!(!parse("ci: skip flaky tests").unwrap().ci_skip());
+    /// ```
+    pub fn ci_skip(&self) -> bool {
+        let has_marker = |text: &str| {
+            let text = text.to_ascii_lowercase();
+            CI_SKIP_MARKERS.iter().any(|marker| text.contains(marker))
+        };
+
+        has_marker(&self.summary) || self.body.as_deref().is_some_and(has_marker)
+    }
+
+    /// The summary without its CI skip markers, as rendered in changelogs
+    ///
+    /// # Example :
+    /// ```
+    /// use conventional_commit_parser::parse;
+    ///
+    /// let commit = parse("docs: fix typo [skip ci]").unwrap();
+    ///
+    /// assert_eq!(commit.summary_without_ci_skip(), "fix typo");
+    /// ```
+    pub fn summary_without_ci_skip(&self) -> Cow<'_, str> {
+        let mut summary = Cow::Borrowed(self.summary.as_str());
+
+        // Markers are ASCII, byte offsets of the lowercase summary match the original ones
+        while let Some((start, len)) = CI_SKIP_MARKERS.iter().find_map(|marker| {
+            summary
+                .to_ascii_lowercase()
+                .find(marker)
+                .map(|start| (start, marker.len()))
+        }) {
+            let before = summary[..start].trim_end();
+            let after = summary[start + len..].trim_start();
+            let separator = if before.is_empty() || after.is_empty() {
+                ""
+            } else {
+                " "
+            };
+
+            summary = Cow::Owned(format!("{}{}{}", before, separator, after));
+        }
+
+        summary
+    }
+
+    /// A stable key identifying the commit change regardless of its formatting, for instance
+    /// to collapse cherry-picked duplicates into a single changelog entry.
+    ///
+    /// The key hashes the lower case commit type and scope along with the summary, lower cased,
+    /// whitespace collapsed and stripped of any trailing period or `(#123)` pull request reference.
+    /// Body, footers and the breaking change flag are ignored. The key is stable across
+    /// platforms and releases of this crate.
+    /// ```rust
+    /// # fn main() {
+    /// use conventional_commit_parser::parse;
+    ///
+    /// let commit = parse("fix(Parser): handle  tabs (#12)").unwrap();
+    /// let backport = parse("fix(parser): Handle tabs.\n\n(cherry picked from commit 8d1f0c2)").unwrap();
+    ///
+    /// assert_eq!(commit.semantic_id(), backport.semantic_id());
+    /// # }
+    /// ```
+    pub fn semantic_id(&self) -> u64 {
+        let summary = crate::dependency::strip_pull_request(&self.summary);
+        let summary = summary.trim().trim_end_matches('.').to_lowercase();
+        let summary = summary.split_whitespace().collect::<Vec<&str>>().join(" ");
+        let scope = self
+            .scope
+            .as_deref()
+            .unwrap_or_default()
+            .trim()
+            .to_lowercase();
+
+        // FNV-1a, the standard library hashers are not guaranteed to be stable
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        let parts = [
+            self.commit_type.as_ref().to_ascii_lowercase(),
+            scope,
+            summary,
+        ];
+
+        for part in parts.iter() {
+            for byte in part.bytes().chain(std::iter::once(0)) {
+                hash ^= u64::from(byte);
+                hash = hash.wrapping_mul(0x0100_0000_01b3);
+            }
+        }
+
+        hash
+    }
+
+    /// Returns the first bot account found in `Signed-off-by` or `Co-authored-by` footers
+    /// ```rust
+    /// # fn main() {
+    /// use conventional_commit_parser::parse;
+    /// use conventional_commit_parser::commit::BotSignature;
+    ///
+    /// let commit = parse("chore(deps): bump pest\n\nSigned-off-by: dependabot[bot] <support@github.com>").unwrap();
+    ///
+    /// assert_eq!(commit.bot_signature(), Some(BotSignature::Dependabot));
+    /// # }
+    /// ```
+    pub fn bot_signature(&self) -> Option<BotSignature<'_>> {
+        self.footers.iter().find_map(Footer::bot_signature)
+    }
+
+    /// Check that a `Signed-off-by` footer (case-insensitive) matches the commit author,
+    /// as required by the Developer Certificate of Origin. Names must be equal, emails are
+    /// compared case-insensitively. Sign-offs that are not `Name <email>` identities are ignored.
+    ///
+    /// # Example :
+    /// ```
+    /// use conventional_commit_parser::commit::SignOffCheck;
+    /// use conventional_commit_parser::parse;
+    /// use conventional_commit_parser::value::Identity;
+    ///
+    /// let commit = parse("fix: typo\n\nSigned-off-by: Jane Doe <jane@example.org>").unwrap();
+    ///
+    /// assert_eq!(commit.check_sign_off("Jane Doe", "Jane@Example.org"), SignOffCheck::Signed);
+    /// assert_eq!(
+    ///     commit.check_sign_off("John Doe", "john@example.org"),
+    ///     SignOffCheck::Mismatch(vec![Identity {
+    ///         name: "Jane Doe".to_string(),
+    ///         email: "jane@example.org".to_string(),
+    ///     }])
+    /// );
+    /// ```
+    pub fn check_sign_off(&self, name: &str, email: &str) -> SignOffCheck {
+        let identities: Vec<Identity> = self
+            .footers
+            .iter()
+            .filter(|footer| footer.token.eq_ignore_ascii_case("Signed-off-by"))
+            .filter_map(|footer| footer.content.parse().ok())
+            .collect();
+
+        if identities.is_empty() {
+            return SignOffCheck::Missing;
+        }
+
+        let signed = identities.iter().any(|identity| {
+            identity.name == name.trim() && identity.email.eq_ignore_ascii_case(email.trim())
+        });
+
+        if signed {
+            SignOffCheck::Signed
+        } else {
+            SignOffCheck::Mismatch(identities)
+        }
+    }
+
+    /// Split the summary into plain text, inline code spans and `#123` issue references.
+    /// Issue references inside code spans are not detected.
+    ///
+    /// # Example :
+    /// ```
+    /// use conventional_commit_parser::commit::SummaryFragment;
+    /// use conventional_commit_parser::parse;
+    ///
+    /// let commit = parse("fix: escape `#` in `Footer` tokens (#12)").unwrap();
+    ///
+    /// assert_eq!(commit.summary_fragments(), vec![
+    ///     SummaryFragment::Text("escape "),
+    ///     SummaryFragment::Code("#"),
+    ///     SummaryFragment::Text(" in "),
+    ///     SummaryFragment::Code("Footer"),
+    ///     SummaryFragment::Text(" tokens ("),
+    ///     SummaryFragment::Issue("12"),
+    ///     SummaryFragment::Text(")"),
+    /// ]);
+    /// ```
+    pub fn summary_fragments(&self) -> Vec<SummaryFragment<'_>> {
+        text_fragments(&self.summary)
+    }
+
+    /// Returns where the breaking change is declared, `None` if the commit is not a breaking change
+    ///
+    /// # Example :
+    /// ```
+    /// use conventional_commit_parser::commit::BreakingOrigin;
+    /// use conventional_commit_parser::parse;
+    ///
+    /// let mark = parse("feat!: drop parse_summary").unwrap();
+    /// let both = parse("feat!: drop parse_summary\n\nBREAKING CHANGE: use parse instead").unwrap();
+    ///
+    /// assert_eq!(mark.breaking_change_origin(), Some(BreakingOrigin::Mark));
+    /// assert_eq!(both.breaking_change_origin(), Some(BreakingOrigin::Both));
+    /// ```
+    pub fn breaking_change_origin(&self) -> Option<BreakingOrigin> {
+        let has_footer = self
+            .footers
+            .iter()
+            .any(|footer| self.is_breaking_change_footer(footer));
+        match (self.breaking_change_mark, has_footer) {
+            (true, true) => Some(BreakingOrigin::Both),
+            (false, true) => Some(BreakingOrigin::Footer),
+            (true, false) => Some(BreakingOrigin::Mark),
+            (false, false) if self.is_breaking_change => Some(BreakingOrigin::Mark),
+            (false, false) => None,
+        }
+    }
+
+    /// Returns `true` if the footer announces a breaking change, either with the `BREAKING CHANGE`
+    /// token or one of the [`ConventionalCommit::breaking_change_tokens`]
+    ///
+    /// # Example :
+    /// ```
+    /// use conventional_commit_parser::commit::BreakingOrigin;
+    /// use conventional_commit_parser::options::ParserOptions;
+    /// use conventional_commit_parser::parse_with_options;
+    ///
+    /// let options = ParserOptions {
+    ///     breaking_change_tokens: vec!["CHANGEMENT MAJEUR".to_string()],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let commit = parse_with_options("feat: x\n\nCHANGEMENT MAJEUR: supprime parse", &options).unwrap();
+    ///
+    /// assert!(commit.is_breaking_change_footer(&commit.footers[0]));
+    /// assert_eq!(commit.breaking_change_origin(), Some(BreakingOrigin::Footer));
+    /// assert_eq!(commit.breaking_change_description(), Some("supprime parse"));
+    /// ```
+    pub fn is_breaking_change_footer(&self, footer: &Footer) -> bool {
+        footer.is_breaking_change() || self.breaking_change_tokens.contains(&footer.token)
+    }
+
+    /// Returns the first footer whose token matches `token`, ignoring ASCII case
+    ///
+    /// # Example :
+    /// ```
+    /// use conventional_commit_parser::parse;
+    ///
+    /// let commit = parse("fix: typo\n\nsigned-off-by: Z <z@example.org>").unwrap();
+    ///
+    /// let footer = commit.footer_ci("Signed-off-by").unwrap();
+    ///
+    /// assert_eq!(footer.token, "signed-off-by");
+    /// assert_eq!(footer.content, "Z <z@example.org>");
+    /// ```
+    pub fn footer_ci(&self, token: &str) -> Option<&Footer> {
+        self.footers
+            .iter()
+            .find(|footer| footer.token.eq_ignore_ascii_case(token))
+    }
+
+    /// Returns the git trailers of the commit, that is every footer except breaking change
+    /// footers, see [`ConventionalCommit::is_breaking_change_footer`], in message order
+    ///
+    /// # Example :
+    /// ```
+    /// use conventional_commit_parser::parse;
+    ///
+    /// let commit = parse("feat!: drop v1\n\nBREAKING CHANGE: v1 is gone\nRefs: #12").unwrap();
+    ///
+    /// let tokens: Vec<&str> = commit.trailers().map(|footer| footer.token.as_str()).collect();
+    /// assert_eq!(tokens, vec!["Refs"]);
+    /// ```
+    pub fn trailers(&self) -> impl Iterator<Item = &Footer> {
+        self.footers
+            .iter()
+            .filter(move |footer| !self.is_breaking_change_footer(footer))
+    }
+
+    /// Returns the breaking change footers of the commit, the complement of
+    /// [`ConventionalCommit::trailers`]
+    ///
+    /// # Example :
+    /// ```
+    /// use conventional_commit_parser::parse;
+    ///
+    /// let commit = parse("feat!: drop v1\n\nBREAKING CHANGE: v1 is gone\nRefs: #12").unwrap();
+    ///
+    /// let contents: Vec<&str> = commit.metadata_footers().map(|footer| footer.content.as_str()).collect();
+    /// assert_eq!(contents, vec!["v1 is gone"]);
+    /// ```
+    pub fn metadata_footers(&self) -> impl Iterator<Item = &Footer> {
+        self.footers
+            .iter()
+            .filter(move |footer| self.is_breaking_change_footer(footer))
+    }
+
     pub(crate) fn set_summary(&mut self, pair: Pair<Rule>) {
         for pair in pair.into_inner() {
             match pair.as_rule() {
@@ -166,7 +870,8 @@ impl ConventionalCommit {
 
     fn set_breaking_change(&mut self, pair: Pair<Rule>) {
         if !pair.as_str().is_empty() {
-            self.is_breaking_change = true
+            self.is_breaking_change = true;
+            self.breaking_change_mark = true;
         }
     }
 
@@ -203,6 +908,23 @@ impl ConventionalCommit {
         }
     }
 
+    /// Move a `(cherry picked from commit <sha>)` line out of the body or the last footer
+    /// into [`ConventionalCommit::cherry_picked_from`]
+    pub(crate) fn extract_cherry_pick(&mut self) {
+        if let Some(footer) = self.footers.last_mut() {
+            if let Some((content, sha)) = take_cherry_pick_line(&footer.content) {
+                footer.content = content;
+                self.cherry_picked_from = Some(sha);
+                return;
+            }
+        }
+
+        if let Some((body, sha)) = self.body.as_deref().and_then(take_cherry_pick_line) {
+            self.body = Some(body).filter(|body| !body.is_empty());
+            self.cherry_picked_from = Some(sha);
+        }
+    }
+
     fn set_footer(&mut self, footer: Pair<Rule>) {
         let footer = Footer::from(footer);
 
@@ -214,6 +936,104 @@ impl ConventionalCommit {
     }
 }
 
+/// Split a breaking change footer content on its `Migration:` line,
+/// returning the trimmed description and migration note
+fn split_migration(content: &str) -> Option<(&str, &str)> {
+    let mut offset = 0;
+    for line in content.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        if trimmed.len() >= "Migration:".len()
+            && trimmed[.."Migration:".len()].eq_ignore_ascii_case("Migration:")
+        {
+            let start = offset + (line.len() - trimmed.len()) + "Migration:".len();
+            return Some((content[..offset].trim(), content[start..].trim()));
+        }
+
+        offset += line.len();
+    }
+
+    None
+}
+
+/// Remove the first `(cherry picked from commit <sha>)` line of a text,
+/// returning the trimmed remaining text and the sha
+pub(crate) fn take_cherry_pick_line(text: &str) -> Option<(String, String)> {
+    let sha = |line: &str| {
+        line.trim()
+            .strip_prefix("(cherry picked from commit ")?
+            .strip_suffix(')')
+            .filter(|sha| crate::value::is_valid_sha(sha))
+            .map(str::to_string)
+    };
+
+    let index = text.lines().position(|line| sha(line).is_some())?;
+    let sha = text.lines().nth(index).and_then(sha)?;
+    let remaining = text
+        .lines()
+        .enumerate()
+        .filter(|(idx, _)| *idx != index)
+        .map(|(_, line)| line)
+        .collect::<Vec<&str>>()
+        .join("\n");
+
+    Some((remaining.trim().to_string(), sha))
+}
+
+/// Split code spans and issue references out of a text, an unterminated backtick is plain text
+pub(crate) fn text_fragments(text: &str) -> Vec<SummaryFragment<'_>> {
+    let mut fragments = vec![];
+    let mut rest = text;
+
+    while let Some(start) = rest.find('`') {
+        let delimiter_len = rest[start..]
+            .find(|c| c != '`')
+            .unwrap_or(rest.len() - start);
+        let delimiter = &rest[start..start + delimiter_len];
+        let content_start = start + delimiter_len;
+
+        match rest[content_start..].find(delimiter) {
+            Some(len) => {
+                push_issue_references(&rest[..start], &mut fragments);
+                fragments.push(SummaryFragment::Code(
+                    &rest[content_start..content_start + len],
+                ));
+                rest = &rest[content_start + len + delimiter_len..];
+            }
+            None => break,
+        }
+    }
+
+    push_issue_references(rest, &mut fragments);
+    fragments
+}
+
+/// Split `#123` issue references out of a text
+fn push_issue_references<'a>(text: &'a str, fragments: &mut Vec<SummaryFragment<'a>>) {
+    let mut text_start = 0;
+    let mut search_from = 0;
+
+    while let Some(idx) = text[search_from..].find('#').map(|idx| idx + search_from) {
+        let digits = text[idx + 1..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(text.len() - idx - 1);
+        let is_word_start = !text[..idx].ends_with(|c: char| c.is_alphanumeric());
+
+        if digits > 0 && is_word_start {
+            if text_start < idx {
+                fragments.push(SummaryFragment::Text(&text[text_start..idx]));
+            }
+            fragments.push(SummaryFragment::Issue(&text[idx + 1..idx + 1 + digits]));
+            text_start = idx + 1 + digits;
+        }
+
+        search_from = idx + 1 + digits;
+    }
+
+    if text_start < text.len() {
+        fragments.push(SummaryFragment::Text(&text[text_start..]));
+    }
+}
+
 impl From<&str> for CommitType {
     fn from(commit_type: &str) -> Self {
         match commit_type.to_ascii_lowercase().as_str() {
@@ -233,12 +1053,6 @@ impl From<&str> for CommitType {
     }
 }
 
-impl Default for CommitType {
-    fn default() -> Self {
-        CommitType::Chore
-    }
-}
-
 impl AsRef<str> for CommitType {
     fn as_ref(&self) -> &str {
         match self {
@@ -258,22 +1072,205 @@ impl AsRef<str> for CommitType {
     }
 }
 
-impl ToString for ConventionalCommit {
-    fn to_string(&self) -> String {
-        let mut message = String::new();
-        message.push_str(self.commit_type.as_ref());
+impl PartialEq for ConventionalCommit {
+    fn eq(&self, other: &Self) -> bool {
+        self.commit_type
+            .as_ref()
+            .eq_ignore_ascii_case(other.commit_type.as_ref())
+            && self.scope == other.scope
+            && self.summary == other.summary
+            && self.body == other.body
+            && self.footers.len() == other.footers.len()
+            && self
+                .footers
+                .iter()
+                .zip(other.footers.iter())
+                .all(|(footer, other)| footer.canonical_eq(other))
+            && self.is_breaking_change == other.is_breaking_change
+            && self.cherry_picked_from == other.cherry_picked_from
+            && self.pre_header_tag == other.pre_header_tag
+    }
+}
 
-        if let Some(scope) = &self.scope {
-            message.push_str(&format!("({})", scope));
-        }
+impl Eq for ConventionalCommit {}
 
-        let has_breaking_change_footer = self.footers.iter().any(|f| f.is_breaking_change());
+/// Commits are ordered for changelog output: breaking changes first, then by commit type
+/// in [`CommitType`] declaration order (custom types last, alphabetically), then by scope
+/// (unscoped commits first) and summary. The remaining fields only break ties, keeping
+/// the ordering consistent with equality.
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::parse;
+///
+/// let mut commits = vec![
+///     parse("fix(parser): handle tabs").unwrap(),
+///     parse("feat(parser): add tokens").unwrap(),
+///     parse("fix!: drop parse_summary").unwrap(),
+///     parse("feat: add policies").unwrap(),
+/// ];
+///
+/// commits.sort();
+///
+/// let headers: Vec<String> = commits.iter().map(ToString::to_string).collect();
+/// assert_eq!(headers, vec![
+///     "fix!: drop parse_summary",
+///     "feat: add policies",
+///     "feat(parser): add tokens",
+///     "fix(parser): handle tabs",
+/// ]);
+/// ```
+impl Ord for ConventionalCommit {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .is_breaking_change
+            .cmp(&self.is_breaking_change)
+            .then_with(|| {
+                // Custom types are compared case-insensitively, as in `PartialEq`
+                CommitType::from(self.commit_type.as_ref())
+                    .cmp(&CommitType::from(other.commit_type.as_ref()))
+            })
+            .then_with(|| self.scope.cmp(&other.scope))
+            .then_with(|| self.summary.cmp(&other.summary))
+            .then_with(|| self.body.cmp(&other.body))
+            .then_with(|| {
+                self.footers
+                    .iter()
+                    .map(Footer::canonical_key)
+                    .cmp(other.footers.iter().map(Footer::canonical_key))
+            })
+            .then_with(|| self.cherry_picked_from.cmp(&other.cherry_picked_from))
+            .then_with(|| self.pre_header_tag.cmp(&other.pre_header_tag))
+    }
+}
+
+impl PartialOrd for ConventionalCommit {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Hash for ConventionalCommit {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.commit_type.as_ref().to_ascii_lowercase().hash(state);
+        self.scope.hash(state);
+        self.summary.hash(state);
+        self.body.hash(state);
+        self.footers.len().hash(state);
+        self.footers
+            .iter()
+            .for_each(|footer| footer.canonical_hash(state));
+        self.is_breaking_change.hash(state);
+        self.cherry_picked_from.hash(state);
+        self.pre_header_tag.hash(state);
+    }
+}
 
-        if self.is_breaking_change && !has_breaking_change_footer {
-            message.push('!');
+/// Compare two commits ignoring their formatting, for instance to deduplicate commits
+/// rewritten by different tools, or to check that reformatting a commit kept its meaning.
+///
+/// Unlike [`ConventionalCommit`] equality, footers are compared regardless of their order
+/// and separator, footer tokens and scopes case-insensitively, and whitespace is collapsed
+/// so wrapped and unwrapped paragraphs are equal. Unlike [`ConventionalCommit::semantic_id`],
+/// the body, footers and breaking change flag are compared.
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::commit::semantically_equal;
+/// use conventional_commit_parser::parse;
+///
+/// let commit = parse("fix(Parser)!: handle tabs\n\nTabs are\nexpanded.\n\nRefs: 12\nReviewed-by: Z").unwrap();
+/// let reformatted = parse("fix(parser)!: handle tabs\n\nTabs are expanded.\n\nreviewed-by: Z\nRefs #12").unwrap();
+///
+/// assert_ne!(commit, reformatted);
+/// assert!(semantically_equal(&commit, &reformatted));
+/// ```
+pub fn semantically_equal(a: &ConventionalCommit, b: &ConventionalCommit) -> bool {
+    a.commit_type
+        .as_ref()
+        .eq_ignore_ascii_case(b.commit_type.as_ref())
+        && a.scope.as_deref().map(|s| s.trim().to_lowercase())
+            == b.scope.as_deref().map(|s| s.trim().to_lowercase())
+        && collapse_whitespace(&a.summary) == collapse_whitespace(&b.summary)
+        && paragraphs(a.body.as_deref()) == paragraphs(b.body.as_deref())
+        && semantic_footers(a) == semantic_footers(b)
+        && a.is_breaking_change == b.is_breaking_change
+        && a.cherry_picked_from == b.cherry_picked_from
+        && a.pre_header_tag == b.pre_header_tag
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+/// The paragraphs of a text, whitespace collapsed
+fn paragraphs(text: Option<&str>) -> Vec<String> {
+    let mut paragraphs = vec![];
+    let mut current = String::new();
+    for line in text.unwrap_or_default().lines() {
+        if line.trim().is_empty() {
+            if !current.is_empty() {
+                paragraphs.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+
+        if !current.is_empty() {
+            current.push(' ');
         }
+        current.push_str(&collapse_whitespace(line));
+    }
+
+    if !current.is_empty() {
+        paragraphs.push(current);
+    }
 
-        message.push_str(&format!(": {}", &self.summary));
+    paragraphs
+}
+
+/// The footers of a commit as sorted `(token, content)` pairs, token lower cased and
+/// content whitespace collapsed
+fn semantic_footers(commit: &ConventionalCommit) -> Vec<(String, String)> {
+    let mut footers: Vec<(String, String)> = commit
+        .footers
+        .iter()
+        .map(|footer| {
+            (
+                footer.canonical_token().to_lowercase(),
+                collapse_whitespace(&footer.content),
+            )
+        })
+        .collect();
+    footers.sort();
+    footers
+}
+
+impl ConventionalCommit {
+    /// Render the commit message, see [`FormatOptions`]. The [`fmt::Display`] implementation
+    /// uses the default options, preserving footer separators.
+    ///
+    /// # Example :
+    /// ```
+    /// use conventional_commit_parser::commit::{FormatOptions, Separator};
+    /// use conventional_commit_parser::parse;
+    ///
+    /// let commit = parse("fix: correct typos\n\nRefs #133").unwrap();
+    ///
+    /// assert_eq!(commit.to_string(), "fix: correct typos\n\nRefs #133");
+    ///
+    /// let options = FormatOptions {
+    ///     footer_separator: Some(Separator::Colon),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(commit.format(&options), "fix: correct typos\n\nRefs: 133");
+    /// ```
+    pub fn format(&self, options: &FormatOptions) -> String {
+        let mut message = match &self.pre_header_tag {
+            Some(tag) if options.pre_header_tag => format!("[{}] ", tag),
+            _ => String::new(),
+        };
+        message.push_str(&self.header());
 
         if let Some(body) = &self.body {
             message.push_str(&format!("\n\n{}", body));
@@ -283,22 +1280,162 @@ impl ToString for ConventionalCommit {
             message.push('\n');
         }
 
-        self.footers
-            .iter()
-            .for_each(|footer| match footer.token_separator {
-                Separator::Colon => {
-                    message.push_str(&format!("\n{}: {}", footer.token, footer.content))
-                }
-                Separator::Hash => {
-                    message.push_str(&format!("\n{} #{}", footer.token, footer.content))
-                }
-                Separator::ColonWithNewLine => {
-                    message.push_str(&format!("\n{}:\n{}", footer.token, footer.content))
+        for footer in &self.footers {
+            message.push('\n');
+            let token = if options.title_case_tokens {
+                footer.title_case_token()
+            } else {
+                footer.token.clone()
+            };
+            let token_separator = options
+                .footer_separator
+                .clone()
+                .unwrap_or_else(|| footer.token_separator.clone());
+
+            message.push_str(
+                &Footer {
+                    token,
+                    token_separator,
+                    content: footer.content.clone(),
                 }
-            });
+                .to_string(),
+            );
+        }
+
+        if let Some(sha) = &self.cherry_picked_from {
+            if self.footers.is_empty() {
+                message.push('\n');
+            }
+            message.push_str(&format!("\n(cherry picked from commit {})", sha));
+        }
 
         message
     }
+
+    /// Render the commit on a single line for UI lists and tooltips :
+    /// `<type>[(scope)][!]: <summary>[ [+body]][ [n footers]]`. The `!` mark is shown for
+    /// every breaking change, including those only announced by a footer.
+    ///
+    /// # Example :
+    /// ```
+    /// use conventional_commit_parser::parse;
+    ///
+    /// let commit = parse("feat(api): add pagination\n\nPages are 50 items long.\n\nBREAKING CHANGE: lists are paginated\nRefs: #12").unwrap();
+    ///
+    /// assert_eq!(commit.one_line(), "feat(api)!: add pagination [+body] [2 footers]");
+    /// assert_eq!(parse("fix: typo").unwrap().one_line(), "fix: typo");
+    /// ```
+    pub fn one_line(&self) -> String {
+        let mut line = self.commit_type.as_ref().to_string();
+        if let Some(scope) = &self.scope {
+            line.push_str(&format!("({})", scope));
+        }
+
+        if self.is_breaking_change {
+            line.push('!');
+        }
+
+        line.push_str(&format!(": {}", self.summary));
+
+        if self.body.is_some() {
+            line.push_str(" [+body]");
+        }
+
+        match self.footers.len() {
+            0 => (),
+            1 => line.push_str(" [1 footer]"),
+            count => line.push_str(&format!(" [{} footers]", count)),
+        }
+
+        line
+    }
+
+    /// Render the commit header : `<type>[(scope)][!]: <summary>`
+    pub(crate) fn header(&self) -> String {
+        let mut header = self.commit_type.as_ref().to_string();
+
+        if let Some(scope) = &self.scope {
+            // Parenthesis are escaped, see `ParserOptions::escaped_scope_parenthesis`
+            let scope = scope.replace('(', "\\(").replace(')', "\\)");
+            header.push_str(&format!("({})", scope));
+        }
+
+        let has_breaking_change_footer = self
+            .footers
+            .iter()
+            .any(|footer| self.is_breaking_change_footer(footer));
+
+        if self.breaking_change_mark || (self.is_breaking_change && !has_breaking_change_footer) {
+            header.push('!');
+        }
+
+        header.push_str(&format!(": {}", &self.summary));
+        header
+    }
+}
+
+impl fmt::Display for ConventionalCommit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.format(&FormatOptions::default()))
+    }
+}
+
+impl fmt::Display for Footer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}{}", self.token, self.token_separator, self.content)
+    }
+}
+
+impl fmt::Display for Separator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Parse a separator either as written in commit messages (`": "`, `" #"`, `":\n"`)
+/// or from its symbolic name (`colon`, `hash`, `colon-newline`)
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::commit::Separator;
+///
+/// assert_eq!(" #".parse(), Ok(Separator::Hash));
+/// assert_eq!("colon-newline".parse(), Ok(Separator::ColonWithNewLine));
+/// ```
+impl FromStr for Separator {
+    type Err = ValueError;
+
+    fn from_str(separator: &str) -> Result<Self, Self::Err> {
+        match separator {
+            ": " | "colon" => Ok(Separator::Colon),
+            " #" | "hash" => Ok(Separator::Hash),
+            ":\n" | ":\r\n" | "colon-newline" => Ok(Separator::ColonWithNewLine),
+            other => Err(ValueError::InvalidSeparator(other.to_string())),
+        }
+    }
+}
+
+/// Serialized as the commit type string (`feat`, `fix`, ...)
+#[cfg(feature = "serde")]
+impl serde::Serialize for CommitType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_ref())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Separator {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Separator {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let separator = String::deserialize(deserializer)?;
+        separator.parse().map_err(serde::de::Error::custom)
+    }
 }
 
 impl fmt::Display for CommitType {
@@ -310,10 +1447,11 @@ impl fmt::Display for CommitType {
 #[cfg(test)]
 mod test {
     use indoc::indoc;
-    use speculoos::assert_that;
-    use speculoos::prelude::ResultAssertions;
+    use speculoos::prelude::*;
+
+    use std::collections::{HashMap, HashSet};
 
-    use crate::commit::{CommitType, ConventionalCommit, Footer, Separator};
+    use crate::commit::{semantically_equal, CommitType, ConventionalCommit, Footer, Separator};
     use crate::parse;
 
     #[test]
@@ -325,6 +1463,11 @@ mod test {
             body: None,
             footers: Vec::with_capacity(0),
             is_breaking_change: false,
+            breaking_change_mark: false,
+            cherry_picked_from: None,
+            pre_header_tag: None,
+            annotations: HashMap::new(),
+            breaking_change_tokens: vec![],
         };
 
         let expected = "feat: a feature".to_string();
@@ -347,6 +1490,11 @@ mod test {
                 ..Default::default()
             }],
             is_breaking_change: true,
+            breaking_change_mark: false,
+            cherry_picked_from: None,
+            pre_header_tag: None,
+            annotations: HashMap::new(),
+            breaking_change_tokens: vec![],
         };
 
         let expected = indoc!(
@@ -370,6 +1518,11 @@ mod test {
             body: Some("A breaking change body on\nmultiple lines".to_string()),
             footers: Vec::with_capacity(0),
             is_breaking_change: true,
+            breaking_change_mark: true,
+            cherry_picked_from: None,
+            pre_header_tag: None,
+            annotations: HashMap::new(),
+            breaking_change_tokens: vec![],
         };
 
         let expected = indoc!(
@@ -412,6 +1565,11 @@ mod test {
                 },
             ],
             is_breaking_change: false,
+            breaking_change_mark: false,
+            cherry_picked_from: None,
+            pre_header_tag: None,
+            annotations: HashMap::new(),
+            breaking_change_tokens: vec![],
         };
 
         let expected = indoc!(
@@ -431,4 +1589,87 @@ mod test {
 
         assert_that(&parsed).is_ok().is_equal_to(commit);
     }
+
+    #[test]
+    fn canonical_equality_should_deduplicate_commits() {
+        let colon = parse("feat: a feature\n\nBREAKING CHANGE: message").unwrap();
+        let newline = parse("FEAT: a feature\n\nBREAKING-CHANGE:\nmessage").unwrap();
+
+        assert_that(&colon).is_equal_to(&newline);
+        assert_that(&colon.raw_eq(&newline)).is_false();
+
+        let set: HashSet<ConventionalCommit> = vec![colon, newline].into_iter().collect();
+        assert_that(&set.len()).is_equal_to(1);
+    }
+
+    #[test]
+    fn canonical_equality_should_keep_hash_separator_distinct() {
+        let colon = parse("fix: a fix\n\nRefs: 133").unwrap();
+        let hash = parse("fix: a fix\n\nRefs #133").unwrap();
+
+        assert_that(&colon).is_not_equal_to(&hash);
+    }
+
+    #[test]
+    fn semantic_id_should_ignore_formatting_but_not_type_or_scope() {
+        let commit = parse("feat(api): Add endpoint").unwrap();
+        let reformatted = parse("FEAT(API):  add endpoint.  (#42)\n\nA body").unwrap();
+        let other_type = parse("fix(api): add endpoint").unwrap();
+        let other_scope = parse("feat(cli): add endpoint").unwrap();
+
+        assert_that(&commit.semantic_id()).is_equal_to(reformatted.semantic_id());
+        assert_that(&commit.semantic_id()).is_not_equal_to(other_type.semantic_id());
+        assert_that(&commit.semantic_id()).is_not_equal_to(other_scope.semantic_id());
+    }
+
+    #[test]
+    fn semantic_equality_should_ignore_formatting_only() {
+        let commit =
+            parse("feat(api): add endpoint\n\nA body\n\nRefs #12\nBREAKING CHANGE: gone").unwrap();
+        let reordered =
+            parse("feat(API): add  endpoint\n\nA\nbody\n\nBREAKING-CHANGE:\ngone\nRefs: 12")
+                .unwrap();
+        let other_body =
+            parse("feat(api): add endpoint\n\nA\n\nbody\n\nRefs #12\nBREAKING CHANGE: gone")
+                .unwrap();
+        let other_footer =
+            parse("feat(api): add endpoint\n\nA body\n\nRefs #13\nBREAKING CHANGE: gone").unwrap();
+
+        assert_that(&semantically_equal(&commit, &reordered)).is_true();
+        assert_that(&semantically_equal(&commit, &other_body)).is_false();
+        assert_that(&semantically_equal(&commit, &other_footer)).is_false();
+    }
+
+    #[test]
+    fn one_line_should_count_a_single_footer() {
+        let commit = parse("refactor(parser)!: drop summary parsing\n\nRefs: #12").unwrap();
+
+        assert_that(&commit.one_line())
+            .is_equal_to("refactor(parser)!: drop summary parsing [1 footer]".to_string());
+    }
+
+    #[test]
+    fn ordering_should_be_consistent_with_canonical_equality() {
+        let colon = parse("wip: a feature\n\nBREAKING CHANGE: message").unwrap();
+        let newline = parse("WIP: a feature\n\nBREAKING-CHANGE:\nmessage").unwrap();
+        let other_body = parse("wip: a feature\n\nA body\n\nBREAKING CHANGE: message").unwrap();
+
+        assert_that(&colon.cmp(&newline)).is_equal_to(std::cmp::Ordering::Equal);
+        assert_that(&colon.cmp(&other_body)).is_not_equal_to(std::cmp::Ordering::Equal);
+    }
+
+    #[test]
+    fn ordering_should_put_custom_types_last() {
+        let mut commits = [
+            parse("wip: a").unwrap(),
+            parse("ci: b").unwrap(),
+            parse("feat: c").unwrap(),
+            parse("Deploy: d").unwrap(),
+        ];
+
+        commits.sort();
+
+        let types: Vec<&str> = commits.iter().map(|c| c.commit_type.as_ref()).collect();
+        assert_that(&types).is_equal_to(vec!["feat", "ci", "deploy", "wip"]);
+    }
 }
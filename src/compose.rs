@@ -0,0 +1,563 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::Formatter;
+use std::ops::Range;
+
+use pest::iterators::Pair;
+use pest::Parser;
+
+use crate::commit::{CommitType, ConventionalCommit, Footer, FormatOptions};
+use crate::error::{ComposeError, ComposeErrorKind};
+use crate::sections::find_footer_block;
+use crate::{parse, ConventionalCommitParser, Rule};
+
+/// Maximum length of a composed commit header (`<type>[(scope)][!]: <summary>`)
+pub const HEADER_MAX_LEN: usize = 100;
+
+/// A validated conventional commit message, built from its structured parts
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CommitMessage(String);
+
+impl CommitMessage {
+    /// Compose a commit message from its parts, validating them against the specification.
+    /// The commit is a breaking change if one of the footers is a `BREAKING CHANGE` footer,
+    /// use [`CommitMessage::compose_breaking`] to add a `!` mark instead.
+    ///
+    /// # Example :
+    /// ```
+    /// use conventional_commit_parser::commit::{CommitType, Footer, Separator};
+    /// use conventional_commit_parser::compose::CommitMessage;
+    ///
+    /// let footers = vec![Footer {
+    ///     token: "Refs".to_string(),
+    ///     content: "133".to_string(),
+    ///     token_separator: Separator::Hash,
+    /// }];
+    ///
+    /// let message = CommitMessage::compose(
+    ///     CommitType::BugFix,
+    ///     Some("parser"),
+    ///     "correct minor typos in code",
+    ///     None,
+    ///     &footers,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(message.as_str(), "fix(parser): correct minor typos in code\n\nRefs #133");
+    ///
+    /// let error = CommitMessage::compose(CommitType::BugFix, Some("a scope"), "", None, &[]);
+    /// assert_eq!(error.unwrap_err().errors.len(), 2);
+    /// ```
+    pub fn compose(
+        commit_type: CommitType,
+        scope: Option<&str>,
+        summary: &str,
+        body: Option<&str>,
+        footers: &[Footer],
+    ) -> Result<CommitMessage, ComposeError> {
+        Self::compose_with_mark(commit_type, scope, summary, body, footers, false)
+    }
+
+    /// Compose a breaking change commit message, marked with a `!` after the type and scope.
+    /// See [`CommitMessage::compose`].
+    pub fn compose_breaking(
+        commit_type: CommitType,
+        scope: Option<&str>,
+        summary: &str,
+        body: Option<&str>,
+        footers: &[Footer],
+    ) -> Result<CommitMessage, ComposeError> {
+        Self::compose_with_mark(commit_type, scope, summary, body, footers, true)
+    }
+
+    fn compose_with_mark(
+        commit_type: CommitType,
+        scope: Option<&str>,
+        summary: &str,
+        body: Option<&str>,
+        footers: &[Footer],
+        breaking_change_mark: bool,
+    ) -> Result<CommitMessage, ComposeError> {
+        let mut errors = vec![];
+
+        let type_ok = {
+            let commit_type = commit_type.as_ref();
+            !commit_type.is_empty() && commit_type.chars().all(|c| c.is_ascii_alphabetic())
+        };
+
+        if !type_ok {
+            errors.push(ComposeErrorKind::InvalidType(commit_type.to_string()));
+        }
+
+        if let Some(scope) = scope {
+            let scope_ok = !scope.is_empty()
+                && !scope
+                    .chars()
+                    .any(|c| c.is_whitespace() || c == '(' || c == ')');
+
+            if !scope_ok {
+                errors.push(ComposeErrorKind::InvalidScope(scope.to_string()));
+            }
+        }
+
+        if summary.trim().is_empty() {
+            errors.push(ComposeErrorKind::EmptySummary);
+        } else if summary.contains(['\n', '\r']) {
+            errors.push(ComposeErrorKind::MultilineSummary);
+        }
+
+        if body.is_some_and(|body| body.trim().is_empty()) {
+            errors.push(ComposeErrorKind::EmptyBody);
+        }
+
+        for (index, footer) in footers.iter().enumerate() {
+            let token_ok = footer.is_breaking_change()
+                || (!footer.token.is_empty()
+                    && footer
+                        .token
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '-'));
+
+            if !token_ok {
+                errors.push(ComposeErrorKind::InvalidFooterToken {
+                    index,
+                    token: footer.token.clone(),
+                });
+            }
+
+            if footer.content.trim().is_empty() {
+                errors.push(ComposeErrorKind::EmptyFooterContent { index });
+            }
+        }
+
+        let has_breaking_change_footer = footers.iter().any(Footer::is_breaking_change);
+        let commit = ConventionalCommit {
+            commit_type,
+            scope: scope.map(str::to_string),
+            summary: summary.to_string(),
+            body: body.map(|body| body.trim().to_string()),
+            footers: footers
+                .iter()
+                .map(|footer| Footer {
+                    content: footer.content.trim().to_string(),
+                    ..footer.clone()
+                })
+                .collect(),
+            is_breaking_change: breaking_change_mark || has_breaking_change_footer,
+            breaking_change_mark,
+            cherry_picked_from: None,
+            pre_header_tag: None,
+            annotations: HashMap::new(),
+            breaking_change_tokens: vec![],
+        };
+
+        let message = commit.to_string();
+
+        let header_len = message
+            .lines()
+            .next()
+            .map_or(0, |header| header.chars().count());
+        if header_len > HEADER_MAX_LEN {
+            errors.push(ComposeErrorKind::HeaderTooLong {
+                limit: HEADER_MAX_LEN,
+                actual: header_len,
+            });
+        }
+
+        if !errors.is_empty() {
+            return Err(ComposeError { errors });
+        }
+
+        // Make sure the composed message parses back to the same commit,
+        // a body ending with footer like lines would otherwise be split
+        match parse(&message) {
+            Ok(parsed) if parsed == commit => Ok(CommitMessage(message)),
+            _ => Err(ComposeError {
+                errors: vec![ComposeErrorKind::Ambiguous],
+            }),
+        }
+    }
+
+    /// The composed message
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Consume the commit message, returning the inner string
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl AsRef<str> for CommitMessage {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<CommitMessage> for String {
+    fn from(message: CommitMessage) -> Self {
+        message.0
+    }
+}
+
+impl fmt::Display for CommitMessage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+fn render(commit: &ConventionalCommit) -> String {
+    commit.format(&FormatOptions {
+        pre_header_tag: true,
+        ..FormatOptions::default()
+    })
+}
+
+/// Rewrite an existing commit message with the content of an edited commit, replacing only
+/// the header, body and footers sections that changed. Untouched sections keep their original
+/// formatting, as do the blank lines around them.
+///
+/// The edited commit is rendered from scratch, along with its
+/// [`ConventionalCommit::pre_header_tag`], if the original message does not parse or if
+/// a `(cherry picked from commit ...)` trailer is involved in the changes.
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::compose::apply_changes;
+/// use conventional_commit_parser::parse;
+///
+/// let original = "fix(parser): typo\n\nA body  \nwrapped  by hand\n\n\nRefs #133\n";
+///
+/// let mut edited = parse(original).unwrap();
+/// edited.summary = "correct minor typos in code".to_string();
+///
+/// assert_eq!(
+///     apply_changes(original, &edited),
+///     "fix(parser): correct minor typos in code\n\nA body  \nwrapped  by hand\n\n\nRefs #133\n"
+/// );
+/// ```
+pub fn apply_changes(original: &str, edited: &ConventionalCommit) -> String {
+    let (current, sections) = match (parse(original), MessageSections::locate(original)) {
+        (Ok(current), Some(sections)) => (current, sections),
+        _ => return render(edited),
+    };
+
+    let body_changed = current.body != edited.body;
+    let footers_changed = current.footers != edited.footers;
+    if current.cherry_picked_from != edited.cherry_picked_from
+        || (current.cherry_picked_from.is_some() && (body_changed || footers_changed))
+    {
+        return render(edited);
+    }
+
+    let mut message = String::with_capacity(original.len());
+    let header = edited.header();
+    if current.header() == header {
+        message.push_str(&original[sections.header.clone()]);
+    } else {
+        message.push_str(&header);
+    }
+
+    let mut cursor = sections.header.end;
+    match (&sections.body, &edited.body) {
+        (Some(range), Some(body)) => {
+            message.push_str(&original[cursor..range.start]);
+            if body_changed {
+                message.push_str(body);
+            } else {
+                message.push_str(&original[range.clone()]);
+            }
+            cursor = range.end;
+        }
+        (Some(range), None) => cursor = range.end,
+        (None, Some(body)) => message.push_str(&format!("\n\n{}", body)),
+        (None, None) => (),
+    }
+
+    let footers = edited
+        .footers
+        .iter()
+        .map(Footer::to_string)
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    match (&sections.footers, footers.is_empty()) {
+        (Some(range), false) => {
+            message.push_str(&original[cursor..range.start]);
+            if footers_changed {
+                message.push_str(&footers);
+            } else {
+                message.push_str(&original[range.clone()]);
+            }
+            cursor = range.end;
+        }
+        (Some(range), true) => cursor = range.end,
+        (None, false) => message.push_str(&format!("\n\n{}", footers)),
+        (None, true) => (),
+    }
+
+    message.push_str(&original[cursor..]);
+    message
+}
+
+/// Byte ranges of the sections of a commit message, blank lines around the body excluded
+struct MessageSections {
+    header: Range<usize>,
+    body: Option<Range<usize>>,
+    footers: Option<Range<usize>>,
+    /// Byte range of each footer, trailing blank lines excluded, along with the parsed footer
+    footer_list: Vec<(Range<usize>, Footer)>,
+}
+
+impl MessageSections {
+    fn locate(message: &str) -> Option<MessageSections> {
+        let pairs = ConventionalCommitParser::parse(Rule::message, message)
+            .ok()?
+            .next()?;
+
+        let mut header = None;
+        let mut body = None;
+        let mut footers = None;
+        let mut footer_list = vec![];
+        for pair in pairs.into_inner() {
+            let span = pair.as_span();
+            match pair.as_rule() {
+                Rule::summary => header = Some(span.start()..span.end()),
+                Rule::body => {
+                    let text = span.as_str();
+                    let start = span.start() + (text.len() - text.trim_start().len());
+                    let end = span.start() + text.trim_end().len();
+                    body = Some(start..end).filter(|range| !range.is_empty());
+                }
+                Rule::footers => {
+                    let end = span.start() + span.as_str().trim_end().len();
+                    footers = Some(span.start()..end);
+                    footer_list = footer_ranges(pair, 0);
+                }
+                _ => (),
+            }
+        }
+
+        Some(MessageSections {
+            header: header?,
+            body,
+            footers,
+            footer_list,
+        })
+    }
+
+    /// Locate the sections of a message whose header is not conventional, e.g. `Update README`,
+    /// finding its footers with the line scanner of [`crate::sections::find_footer_block`]
+    fn scan(message: &str) -> MessageSections {
+        let header_end = message.find('\n').unwrap_or(message.len());
+        let header = 0..message[..header_end].trim_end_matches('\r').len();
+        let footers = find_footer_block(message);
+        let footer_list = footers
+            .as_ref()
+            .and_then(|range| {
+                let pairs = ConventionalCommitParser::parse(Rule::footers, &message[range.clone()]);
+                Some(footer_ranges(pairs.ok()?.next()?, range.start))
+            })
+            .unwrap_or_default();
+
+        let rest = (header_end + 1).min(message.len())
+            ..footers.as_ref().map_or(message.len(), |range| range.start);
+        let text = &message[rest.clone()];
+        let start = rest.start + (text.len() - text.trim_start().len());
+        let end = rest.start + text.trim_end().len();
+        let body = Some(start..end).filter(|range| range.start < range.end);
+
+        MessageSections {
+            header,
+            body,
+            footers: footers.filter(|_| !footer_list.is_empty()),
+            footer_list,
+        }
+    }
+
+    /// Locate the sections of a message, falling back to [`MessageSections::scan`] when the
+    /// message is not a conventional commit
+    fn locate_or_scan(message: &str) -> MessageSections {
+        MessageSections::locate(message).unwrap_or_else(|| MessageSections::scan(message))
+    }
+}
+
+/// Byte range of each footer of a `footers` pair, trailing blank lines excluded, along with
+/// the parsed footer. Ranges are shifted by `offset`.
+fn footer_ranges(pair: Pair<'_, Rule>, offset: usize) -> Vec<(Range<usize>, Footer)> {
+    pair.into_inner()
+        .map(|footer| {
+            let span = footer.as_span();
+            let start = offset + span.start();
+            let end = start + span.as_str().trim_end().len();
+            (start..end, Footer::from(footer))
+        })
+        .collect()
+}
+
+/// Add a `token: value` trailer to a commit message like `git interpret-trailers --trailer`,
+/// leaving the rest of the message untouched. The trailer is appended to the existing
+/// footers, or after a blank line if the message has none. Footers of a message that is not
+/// a conventional commit are found with [`crate::sections::find_footer_block`].
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::compose::append_trailer;
+///
+/// assert_eq!(
+///     append_trailer("fix: typo\n\nA body\n", "Reviewed-by", "Z"),
+///     "fix: typo\n\nA body\n\nReviewed-by: Z\n"
+/// );
+///
+/// assert_eq!(
+///     append_trailer("fix: typo\n\nRefs #12\n", "Reviewed-by", "Z"),
+///     "fix: typo\n\nRefs #12\nReviewed-by: Z\n"
+/// );
+/// ```
+pub fn append_trailer(message: &str, token: &str, value: &str) -> String {
+    let trailer = Footer {
+        token: token.to_string(),
+        content: value.to_string(),
+        ..Default::default()
+    };
+
+    let content_end = message.trim_end().len();
+    let (position, separator) = match MessageSections::locate_or_scan(message) {
+        MessageSections {
+            footers: Some(footers),
+            ..
+        } => (footers.end, "\n"),
+        _ if content_end == 0 => (0, ""),
+        _ => (content_end, "\n\n"),
+    };
+
+    format!(
+        "{}{}{}{}",
+        &message[..position],
+        separator,
+        trailer,
+        &message[position..]
+    )
+}
+
+/// Iterate over the footers of a commit message in their original order, along with their
+/// index and their byte range in the message. Ranges exclude the trailing newline, so an
+/// edit can be spliced with `String::replace_range` without touching the surrounding lines.
+/// A message that is not a valid conventional commit has no footers.
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::compose::footers_enumerated;
+///
+/// let message = "fix: typo\n\nRefs #12\nReviewed-by: Z\n";
+/// let footers: Vec<_> = footers_enumerated(message).collect();
+///
+/// let (index, range, footer) = &footers[1];
+/// assert_eq!(*index, 1);
+/// assert_eq!(&message[range.clone()], "Reviewed-by: Z");
+/// assert_eq!(footer.token, "Reviewed-by");
+/// ```
+pub fn footers_enumerated(message: &str) -> impl Iterator<Item = (usize, Range<usize>, Footer)> {
+    MessageSections::locate(message)
+        .map(|sections| sections.footer_list)
+        .unwrap_or_default()
+        .into_iter()
+        .enumerate()
+        .map(|(index, (range, footer))| (index, range, footer))
+}
+
+/// Remove every trailer with the given token, compared case-insensitively, leaving the rest
+/// of the message untouched. Returns the message along with the removed trailers. Trailers
+/// of a message that is not a conventional commit are found like [`append_trailer`] does.
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::compose::remove_trailer;
+///
+/// let (message, removed) = remove_trailer("fix: typo\n\nRefs #12\nReviewed-by: Z\n", "reviewed-by");
+///
+/// assert_eq!(message, "fix: typo\n\nRefs #12\n");
+/// assert_eq!(removed[0].content, "Z");
+/// ```
+pub fn remove_trailer(message: &str, token: &str) -> (String, Vec<Footer>) {
+    edit_trailers(message, token, None)
+}
+
+/// Replace the value of every trailer with the given token, compared case-insensitively,
+/// keeping their token spelling and separator. The trailer is appended with
+/// [`append_trailer`] if the message has none. Returns the message along with the
+/// replaced trailers.
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::compose::replace_trailer;
+///
+/// let (message, replaced) = replace_trailer("fix: typo\n\nRefs #12\nReviewed-by: Z\n", "Refs", "13");
+///
+/// assert_eq!(message, "fix: typo\n\nRefs #13\nReviewed-by: Z\n");
+/// assert_eq!(replaced[0].content, "12");
+/// ```
+pub fn replace_trailer(message: &str, token: &str, value: &str) -> (String, Vec<Footer>) {
+    let (edited, replaced) = edit_trailers(message, token, Some(value));
+    if replaced.is_empty() {
+        return (append_trailer(message, token, value), replaced);
+    }
+
+    (edited, replaced)
+}
+
+/// Remove the trailers matching `token` or replace their value, returning the edited trailers
+fn edit_trailers(message: &str, token: &str, value: Option<&str>) -> (String, Vec<Footer>) {
+    let sections = MessageSections::locate_or_scan(message);
+    let footers = match sections.footers {
+        Some(footers) => footers,
+        None => return (message.to_string(), vec![]),
+    };
+
+    // Each footer owns the text up to the next footer, blank lines included
+    let mut section = String::with_capacity(footers.len());
+    let mut edited = vec![];
+    for (index, (range, footer)) in sections.footer_list.iter().enumerate() {
+        let end = sections
+            .footer_list
+            .get(index + 1)
+            .map_or(footers.end, |(next, _)| next.start);
+        let text = &message[range.start..end];
+
+        if !footer.token.eq_ignore_ascii_case(token) {
+            section.push_str(text);
+            continue;
+        }
+
+        if let Some(value) = value {
+            let replacement = Footer {
+                content: value.to_string(),
+                ..footer.clone()
+            };
+            section.push_str(&replacement.to_string());
+            section.push_str(&text[text.trim_end().len()..]);
+        }
+        edited.push(footer.clone());
+    }
+
+    if edited.is_empty() {
+        return (message.to_string(), edited);
+    }
+
+    let section = section.trim_end();
+    let start = if section.is_empty() {
+        // No footer left, drop the blank lines separating them from the rest of the message
+        message[..footers.start].trim_end().len()
+    } else {
+        footers.start
+    };
+
+    let message = format!(
+        "{}{}{}",
+        &message[..start],
+        section,
+        &message[footers.end..]
+    );
+    (message, edited)
+}
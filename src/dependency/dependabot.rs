@@ -0,0 +1,110 @@
+//! Decoding of the dependabot `updated-dependencies` footer, requires the `dependabot` feature.
+//!
+//! ```text
+//! updated-dependencies:
+//! - dependency-name: org.springframework.boot:spring-boot-starter-parent
+//!   dependency-type: direct:production
+//!   update-type: version-update:semver-patch
+//! ...
+//! ```
+
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+use crate::commit::ConventionalCommit;
+use crate::error::ValueError;
+
+/// Token of the dependabot dependency metadata footer
+pub const UPDATED_DEPENDENCIES_TOKEN: &str = "updated-dependencies";
+
+/// A dependency listed in an `updated-dependencies` footer
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct UpdatedDependency {
+    pub dependency_name: String,
+    pub dependency_type: Option<DependencyType>,
+    pub update_type: Option<UpdateType>,
+    /// The dependabot group, for group updates
+    pub dependency_group: Option<String>,
+}
+
+/// The `dependency-type` of an [`UpdatedDependency`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+pub enum DependencyType {
+    #[serde(rename = "direct:production")]
+    DirectProduction,
+    #[serde(rename = "direct:development")]
+    DirectDevelopment,
+    #[serde(rename = "indirect")]
+    Indirect,
+    #[serde(other)]
+    Unknown,
+}
+
+/// The `update-type` of an [`UpdatedDependency`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+pub enum UpdateType {
+    #[serde(rename = "version-update:semver-major")]
+    SemverMajor,
+    #[serde(rename = "version-update:semver-minor")]
+    SemverMinor,
+    #[serde(rename = "version-update:semver-patch")]
+    SemverPatch,
+    #[serde(other)]
+    Unknown,
+}
+
+/// The content of an `updated-dependencies` footer, to be used with
+/// [`crate::commit::Footer::value_as`]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct UpdatedDependencies(pub Vec<UpdatedDependency>);
+
+impl FromStr for UpdatedDependencies {
+    type Err = ValueError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        serde_yaml::from_str(value)
+            .map(UpdatedDependencies)
+            .map_err(|err| ValueError::InvalidDependencies(err.to_string()))
+    }
+}
+
+impl ConventionalCommit {
+    /// Decode the dependabot `updated-dependencies` footer, returning an empty list
+    /// when the commit has no such footer
+    ///
+    /// # Example :
+    /// ```
+    /// use conventional_commit_parser::dependency::dependabot::{DependencyType, UpdateType};
+    /// use conventional_commit_parser::parse;
+    ///
+    /// let commit = parse(
+    ///     "chore(deps): bump pest from 2.1.0 to 2.1.3\n\n\
+    ///     updated-dependencies:\n\
+    ///     - dependency-name: pest\n  \
+    ///       dependency-type: direct:production\n  \
+    ///       update-type: version-update:semver-patch\n\
+    ///     ...",
+    /// )
+    /// .unwrap();
+    ///
+    /// let dependencies = commit.updated_dependencies().unwrap();
+    ///
+    /// assert_eq!(dependencies[0].dependency_name, "pest");
+    /// assert_eq!(dependencies[0].dependency_type, Some(DependencyType::DirectProduction));
+    /// assert_eq!(dependencies[0].update_type, Some(UpdateType::SemverPatch));
+    /// ```
+    pub fn updated_dependencies(&self) -> Result<Vec<UpdatedDependency>, ValueError> {
+        match self
+            .footers
+            .iter()
+            .find(|footer| footer.token == UPDATED_DEPENDENCIES_TOKEN)
+        {
+            Some(footer) => footer
+                .value_as::<UpdatedDependencies>()
+                .map(|dependencies| dependencies.0),
+            None => Ok(vec![]),
+        }
+    }
+}
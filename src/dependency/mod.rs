@@ -0,0 +1,270 @@
+//! Helpers for dependency update commits, as authored by dependabot or renovate.
+
+use crate::commit::ConventionalCommit;
+
+#[cfg(feature = "dependabot")]
+pub mod dependabot;
+
+/// A dependabot group update, parsed from summaries such as
+/// `bump the npm group with 12 updates (#456)`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DependabotGroup<'a> {
+    /// The dependabot group name
+    pub name: &'a str,
+    /// The updated directory, for `bump the pip group in /docs with 3 updates`
+    pub directory: Option<&'a str>,
+    /// The number of directories, for `bump the npm group across 2 directories with 5 updates`
+    pub directory_count: Option<usize>,
+    /// The number of updated dependencies
+    pub update_count: usize,
+    /// The pull request number, see [`pull_request_number`]
+    pub pull_request: Option<u64>,
+}
+
+impl<'a> DependabotGroup<'a> {
+    /// Parse a dependabot group update summary
+    ///
+    /// # Example :
+    /// ```
+    /// use conventional_commit_parser::dependency::DependabotGroup;
+    ///
+    /// let group = DependabotGroup::from_summary("bump the npm group with 12 updates (#456)").unwrap();
+    ///
+    /// assert_eq!(group.name, "npm");
+    /// assert_eq!(group.update_count, 12);
+    /// assert_eq!(group.pull_request, Some(456));
+    /// ```
+    pub fn from_summary(summary: &'a str) -> Option<DependabotGroup<'a>> {
+        let pull_request = pull_request_number(summary);
+        let summary = strip_pull_request(summary);
+
+        let rest = summary
+            .strip_prefix("bump the ")
+            .or_else(|| summary.strip_prefix("Bump the "))?;
+        let (name, rest) = rest.split_once(" group ")?;
+        let (location, update_count) = rest.rsplit_once("with ")?;
+
+        let update_count = update_count
+            .strip_suffix(" updates")
+            .or_else(|| update_count.strip_suffix(" update"))?
+            .parse()
+            .ok()?;
+
+        let (directory, directory_count) = match location.trim_end() {
+            "" => (None, None),
+            location => {
+                if let Some(directory) = location.strip_prefix("in ") {
+                    (Some(directory), None)
+                } else {
+                    let count = location
+                        .strip_prefix("across ")?
+                        .strip_suffix(" directories")?
+                        .parse()
+                        .ok()?;
+                    (None, Some(count))
+                }
+            }
+        };
+
+        Some(DependabotGroup {
+            name,
+            directory,
+            directory_count,
+            update_count,
+            pull_request,
+        })
+    }
+}
+
+/// Returns the pull request number referenced by a trailing `(#123)`, as appended to
+/// summaries by squash merges
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::dependency::pull_request_number;
+///
+/// assert_eq!(pull_request_number("bump pest from 2.1 to 2.2 (#11)"), Some(11));
+/// assert_eq!(pull_request_number("bump pest from 2.1 to 2.2"), None);
+/// ```
+pub fn pull_request_number(summary: &str) -> Option<u64> {
+    summary
+        .trim_end()
+        .strip_suffix(')')?
+        .rsplit_once("(#")?
+        .1
+        .parse()
+        .ok()
+}
+
+/// Remove a trailing `(#123)` pull request reference
+pub(crate) fn strip_pull_request(summary: &str) -> &str {
+    match pull_request_number(summary) {
+        Some(_) => summary
+            .rsplit_once("(#")
+            .map_or(summary, |(summary, _)| summary.trim_end()),
+        None => summary.trim_end(),
+    }
+}
+
+/// A single dependency version change
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DependencyUpdate {
+    /// The dependency name
+    pub name: String,
+    /// The previous version, when known
+    pub from: Option<String>,
+    /// The new version
+    pub to: String,
+}
+
+/// Extract dependency updates from a renovate update table, found in renovate bodies:
+///
+/// ```text
+/// | Package | Type | Update | Change |
+/// |---|---|---|---|
+/// | [pest](https://pest.rs) | dependencies | minor | `2.1.0` -> `2.5.0` |
+/// ```
+fn renovate_table_updates(body: &str) -> Vec<DependencyUpdate> {
+    let cells = |line: &str| -> Vec<String> {
+        line.trim()
+            .trim_start_matches('|')
+            .trim_end_matches('|')
+            .split('|')
+            .map(|cell| cell.trim().to_string())
+            .collect()
+    };
+
+    let mut lines = body.lines().map(str::trim);
+    let mut updates = vec![];
+
+    while let Some(line) = lines.next() {
+        if !line.starts_with('|') {
+            continue;
+        }
+
+        let header = cells(line);
+        let column = |title: &str| {
+            header
+                .iter()
+                .position(|cell| cell.eq_ignore_ascii_case(title))
+        };
+
+        let (Some(package), Some(change)) = (column("Package"), column("Change")) else {
+            continue;
+        };
+
+        for row in lines.by_ref().skip(1) {
+            if !row.starts_with('|') {
+                break;
+            }
+
+            let row = cells(row);
+            let (Some(name), Some(change)) = (row.get(package), row.get(change)) else {
+                continue;
+            };
+
+            let (from, to) = match change.split_once("->").or_else(|| change.split_once('→')) {
+                Some((from, to)) => (Some(version(from)), version(to)),
+                None => (None, version(change)),
+            };
+
+            updates.push(DependencyUpdate {
+                name: package_name(name),
+                from,
+                to,
+            });
+        }
+    }
+
+    updates
+}
+
+/// Extract a dependency update from summaries such as `bump pest from 2.1.0 to 2.5.0`
+/// or `update rust crate pest to 2.5`
+fn summary_update(summary: &str) -> Option<DependencyUpdate> {
+    let summary = strip_pull_request(summary);
+    let lower_case = summary.to_lowercase();
+
+    if lower_case.starts_with("bump ") {
+        let (name, versions) = summary["bump ".len()..].split_once(" from ")?;
+        let (from, to) = versions.split_once(" to ")?;
+        let to = to.split_once(" in ").map_or(to, |(to, _)| to);
+
+        return Some(DependencyUpdate {
+            name: name.trim().to_string(),
+            from: Some(version(from)),
+            to: version(to),
+        });
+    }
+
+    let rest = &summary[lower_case
+        .strip_prefix("update ")
+        .map(|_| "update ".len())?..];
+    let (name, to) = rest.rsplit_once(" to ")?;
+    let name = name.rsplit(' ').next()?;
+
+    Some(DependencyUpdate {
+        name: name.to_string(),
+        from: None,
+        to: version(to),
+    })
+}
+
+/// `[pest](https://pest.rs) ([source](...))` -> `pest`
+fn package_name(cell: &str) -> String {
+    match cell.strip_prefix('[').and_then(|cell| cell.split_once(']')) {
+        Some((name, _)) => name.to_string(),
+        None => cell
+            .split_once(" (")
+            .map_or(cell, |(name, _)| name)
+            .to_string(),
+    }
+}
+
+fn version(version: &str) -> String {
+    version.trim().trim_matches('`').to_string()
+}
+
+impl ConventionalCommit {
+    /// Returns the pull request number referenced at the end of the summary,
+    /// see [`pull_request_number`]
+    pub fn pull_request_number(&self) -> Option<u64> {
+        pull_request_number(&self.summary)
+    }
+
+    /// Returns the dependabot group update described by the summary, if any
+    pub fn dependabot_group(&self) -> Option<DependabotGroup<'_>> {
+        DependabotGroup::from_summary(&self.summary)
+    }
+
+    /// Returns the dependency updates described by a renovate update table in the body, or
+    /// by the summary of a single update (`bump pest from 2.1.0 to 2.5.0`,
+    /// `update rust crate pest to 2.5`)
+    ///
+    /// # Example :
+    /// ```
+    /// use conventional_commit_parser::dependency::DependencyUpdate;
+    /// use conventional_commit_parser::parse;
+    ///
+    /// let commit = parse("chore(deps): bump pest from 2.1.0 to 2.5.0 (#12)").unwrap();
+    ///
+    /// assert_eq!(commit.dependency_updates(), vec![DependencyUpdate {
+    ///     name: "pest".to_string(),
+    ///     from: Some("2.1.0".to_string()),
+    ///     to: "2.5.0".to_string(),
+    /// }]);
+    /// ```
+    pub fn dependency_updates(&self) -> Vec<DependencyUpdate> {
+        let updates = self
+            .body
+            .as_deref()
+            .map(renovate_table_updates)
+            .unwrap_or_default();
+
+        if updates.is_empty() {
+            summary_update(&self.summary).into_iter().collect()
+        } else {
+            updates
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use std::borrow::Cow;
+
+use crate::commit::ConventionalCommit;
+use crate::error::{ParseError, ParseErrorKind};
+
+/// Strategy used to turn raw commit message bytes into a string before parsing.
+///
+/// Git stores commit messages as raw bytes, some histories contain ISO-8859-1 or other
+/// legacy encodings.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum MessageEncoding {
+    /// Reject any message that is not valid UTF-8
+    #[default]
+    Utf8,
+    /// Replace invalid UTF-8 sequences with `U+FFFD REPLACEMENT CHARACTER`
+    Utf8Lossy,
+    /// Decode the message with an explicit encoding, for instance [`encoding_rs::WINDOWS_1252`]
+    /// for Latin-1 messages
+    #[cfg(feature = "encoding_rs")]
+    Explicit(&'static encoding_rs::Encoding),
+}
+
+/// An owned, decoded commit message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedMessage {
+    /// The decoded message
+    pub message: String,
+    /// True if the original bytes were transcoded or lossily replaced
+    pub reencoded: bool,
+}
+
+/// A commit parsed from raw bytes, produced by the [`crate::parse_bytes`] function
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedCommit {
+    /// The parsed commit
+    pub commit: ConventionalCommit,
+    /// True if the original bytes were transcoded or lossily replaced
+    pub reencoded: bool,
+}
+
+/// A commit parsed from a raw git commit object, produced by the [`crate::parse_git_raw`] function
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawCommit {
+    /// The parsed commit message
+    pub commit: ConventionalCommit,
+    /// True if the original message bytes were transcoded or lossily replaced
+    pub reencoded: bool,
+    /// True if the object holds a `gpgsig` header or a signature block after the message
+    pub is_signed: bool,
+    /// The `parent` commit hashes, in header order
+    pub parents: Vec<String>,
+    /// The `author` header, `Name <email> timestamp timezone`
+    pub author: Option<String>,
+    /// The `committer` header, `Name <email> timestamp timezone`
+    pub committer: Option<String>,
+    /// The `encoding` header, the message encoding when it is not UTF-8
+    pub encoding: Option<String>,
+}
+
+/// Decode raw commit message bytes according to the given [`MessageEncoding`]
+///
+/// # Example :
+/// ```
+/// # use conventional_commit_parser::error::ParseError;
+/// # fn main() -> Result<(), ParseError> {
+///
+/// use conventional_commit_parser::encoding::{decode_message, MessageEncoding};
+///
+/// let decoded = decode_message("feat: café".as_bytes(), &MessageEncoding::Utf8)?;
+///
+/// assert_eq!(decoded.message, "feat: café");
+/// assert!(!decoded.reencoded);
+/// # Ok(())
+/// # }
+/// ```
+pub fn decode_message(
+    bytes: &[u8],
+    encoding: &MessageEncoding,
+) -> Result<DecodedMessage, ParseError> {
+    let (message, reencoded) = match encoding {
+        MessageEncoding::Utf8 => match std::str::from_utf8(bytes) {
+            Ok(message) => (message.to_string(), false),
+            Err(err) => {
+                let lossy = String::from_utf8_lossy(bytes);
+                return Err(ParseError::custom(
+                    ParseErrorKind::InvalidEncoding,
+                    &lossy,
+                    err.valid_up_to(),
+                ));
+            }
+        },
+        MessageEncoding::Utf8Lossy => match String::from_utf8_lossy(bytes) {
+            Cow::Borrowed(message) => (message.to_string(), false),
+            Cow::Owned(message) => (message, true),
+        },
+        #[cfg(feature = "encoding_rs")]
+        MessageEncoding::Explicit(encoding) => {
+            let (message, _, _) = encoding.decode(bytes);
+            match message {
+                Cow::Borrowed(message) => (message.to_string(), false),
+                Cow::Owned(message) => (message, true),
+            }
+        }
+    };
+
+    #[cfg(feature = "tracing")]
+    if reencoded {
+        tracing::debug!(encoding = ?encoding, "commit message re-encoded to UTF-8");
+    }
+
+    Ok(DecodedMessage { message, reencoded })
+}
@@ -1,5 +1,7 @@
+use crate::changelog::template;
 use crate::Rule;
-use pest::error::Error as PestError;
+use pest::error::{Error as PestError, ErrorVariant};
+use pest::Position;
 use std::error::Error;
 use std::fmt::{Debug, Display, Formatter};
 
@@ -7,17 +9,171 @@ use std::fmt::{Debug, Display, Formatter};
 pub struct ParseError {
     pub inner: PestError<Rule>,
     pub kind: ParseErrorKind,
+    /// The accepted value replacing a disallowed scope or type, see [`ParseError::suggestion`]
+    replacement: Option<String>,
+}
+
+/// A stable public name of a grammar rule, see [`ParseError::rule_context`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GrammarRule {
+    /// The whole commit message
+    Message,
+    /// The `<type>[(scope)][!]: <description>` header
+    Header,
+    CommitType,
+    /// A parenthesized scope
+    Scope,
+    /// The content of a scope, between parenthesis
+    ScopeContent,
+    /// The `!` breaking change mark
+    BreakingChangeMark,
+    /// A `!` mark placed between the type and the scope
+    BreakingMarkBeforeScope,
+    /// The `:` following the type and scope
+    TypeSeparator,
+    /// The space following the type separator
+    SeparatorWhitespace,
+    /// The header description
+    Description,
+    Body,
+    Footer,
+    FooterToken,
+    /// A footer token separator, `: ` or ` #`
+    FooterSeparator,
+    FooterValue,
+    /// A parenthesis inside a scope
+    Parenthesis,
+    /// A whitespace, such as one inside a scope
+    Whitespace,
+    /// The end of the message
+    EndOfInput,
+}
+
+impl GrammarRule {
+    /// A stable snake case name of the rule
+    pub fn name(&self) -> &'static str {
+        match self {
+            GrammarRule::Message => "message",
+            GrammarRule::Header => "header",
+            GrammarRule::CommitType => "commit_type",
+            GrammarRule::Scope => "scope",
+            GrammarRule::ScopeContent => "scope_content",
+            GrammarRule::BreakingChangeMark => "breaking_change_mark",
+            GrammarRule::BreakingMarkBeforeScope => "breaking_mark_before_scope",
+            GrammarRule::TypeSeparator => "type_separator",
+            GrammarRule::SeparatorWhitespace => "separator_whitespace",
+            GrammarRule::Description => "description",
+            GrammarRule::Body => "body",
+            GrammarRule::Footer => "footer",
+            GrammarRule::FooterToken => "footer_token",
+            GrammarRule::FooterSeparator => "footer_separator",
+            GrammarRule::FooterValue => "footer_value",
+            GrammarRule::Parenthesis => "parenthesis",
+            GrammarRule::Whitespace => "whitespace",
+            GrammarRule::EndOfInput => "end_of_input",
+        }
+    }
+}
+
+impl From<Rule> for GrammarRule {
+    fn from(rule: Rule) -> Self {
+        match rule {
+            Rule::message | Rule::blank_line => GrammarRule::Message,
+            Rule::summary => GrammarRule::Header,
+            Rule::commit_type => GrammarRule::CommitType,
+            Rule::scope | Rule::parent_left | Rule::parent_right => GrammarRule::Scope,
+            Rule::scope_content => GrammarRule::ScopeContent,
+            Rule::breaking_change_mark | Rule::invalid_breaking_change_mark => {
+                GrammarRule::BreakingChangeMark
+            }
+            Rule::breaking_mark_before_scope => GrammarRule::BreakingMarkBeforeScope,
+            Rule::type_separator => GrammarRule::TypeSeparator,
+            Rule::whitespace_terminal => GrammarRule::SeparatorWhitespace,
+            Rule::summary_content | Rule::description_start => GrammarRule::Description,
+            Rule::body | Rule::body_chunk | Rule::blank_run => GrammarRule::Body,
+            Rule::footers | Rule::footer | Rule::footer_start => GrammarRule::Footer,
+            Rule::token | Rule::breaking_change_token | Rule::lower_case_breaking_change_token => {
+                GrammarRule::FooterToken
+            }
+            Rule::token_separator => GrammarRule::FooterSeparator,
+            Rule::footer_content => GrammarRule::FooterValue,
+            Rule::no_parenthesis => GrammarRule::Parenthesis,
+            Rule::no_whitespace | Rule::WHITESPACE => GrammarRule::Whitespace,
+            Rule::EOI => GrammarRule::EndOfInput,
+        }
+    }
+}
+
+/// The grammar rules the parser expected, or did not expect, where it failed
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RuleContext {
+    /// Rules that would have matched at the error position
+    pub expected: Vec<GrammarRule>,
+    /// Rules that matched at the error position but are forbidden there
+    pub unexpected: Vec<GrammarRule>,
 }
 
 /// Common conventional commit formatting errors are wrapped in this struct to produce an additional hint
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub enum ParseErrorKind {
     MissingSeparator,
     MissingWhiteSpace,
     UnexpectedParenthesis,
     UnexpectedWhitespaceOrNewLine,
     MalformedScope,
+    /// The scope parenthesis is not closed on the header line, e.g. `feat(scope: x`
+    UnclosedScope,
     MalformedOrUnexpectedFooterSeparator,
+    EmptyDescription,
+    /// The message is empty or only contains whitespace, see [`crate::is_effectively_empty`]
+    EmptyCommitMessage,
+    /// More than one `!` mark before the type separator, e.g. `feat!!: x`
+    InvalidBreakingChangeMark,
+    /// A `!` mark placed between the type and the scope, e.g. `feat!(scope): x`
+    BreakingMarkBeforeScope,
+    MissingBlankLineBeforeBody,
+    /// A footer uses the ` #` token separator, e.g. `Refs #12`, see
+    /// [`crate::options::ParserOptions::hash_footer_separator`]
+    HashFooterSeparator,
+    /// A header passed to [`crate::parse_summary`] is followed by more lines
+    UnexpectedNewLineInSummary,
+    /// A paragraph follows footers placed right after the summary,
+    /// see [`crate::options::ParserOptions::body_after_footers`]
+    BodyAfterFooters,
+    InvalidEncoding,
+    SummaryTooLong {
+        limit: usize,
+        actual: usize,
+    },
+    SummaryTooShort {
+        limit: usize,
+        actual: usize,
+    },
+    /// The custom commit type is rejected by
+    /// [`crate::options::ParserOptions::custom_type_validator`]
+    DisallowedType {
+        commit_type: String,
+    },
+    /// The scope is rejected by [`crate::options::ParserOptions::scope_validator`]
+    DisallowedScope {
+        scope: String,
+    },
+    /// The message is longer than [`crate::options::ParserOptions::max_message_len`] bytes
+    MessageTooLarge {
+        limit: usize,
+        actual: usize,
+    },
+    /// A `git log --oneline` line does not start with an abbreviated commit hash,
+    /// see [`crate::parse_oneline`]
+    MissingCommitSha,
+    /// The header does not follow git's `Revert "<header>"` layout, see [`crate::parse_revert`]
+    MalformedRevertHeader,
+    /// A `This reverts commit <sha>.` line does not hold a plausible hex commit hash
+    InvalidRevertedSha {
+        sha: String,
+    },
+    /// A revert message has no `This reverts commit <sha>.` line
+    MissingRevertedSha,
     Other,
 }
 
@@ -35,15 +191,437 @@ impl AsRef<str> for ParseErrorKind {
                 "A scope value must not contain whitespace or new line"
             }
             ParseErrorKind::MalformedScope => "Malformed commit scope",
+            ParseErrorKind::UnclosedScope => {
+                "Missing closing parenthesis `)` after the commit scope \
+            \n(e.g. `feat(scope): add a new feature`)"
+            }
             ParseErrorKind::MalformedOrUnexpectedFooterSeparator => {
                 "Either token separator (` #` or `: `) \
             \nis missing from the footer or a footer was not expected at this point"
             }
+            ParseErrorKind::EmptyDescription => {
+                "Missing commit description after the type separator `: ` \
+            \n(e.g. `feat: add a new feature`)"
+            }
+            ParseErrorKind::InvalidBreakingChangeMark => {
+                "A single breaking change mark `!` is allowed before the type separator `:`"
+            }
+            ParseErrorKind::BreakingMarkBeforeScope => {
+                "The breaking change mark `!` must be placed after the scope \
+            \n(e.g. `feat(scope)!: drop support`)"
+            }
+            ParseErrorKind::EmptyCommitMessage => "Commit message is empty",
+            ParseErrorKind::BodyAfterFooters => "The body must be placed before the footers",
+            ParseErrorKind::HashFooterSeparator => {
+                "Footer token separator ` #` is not allowed, use `: ` instead \
+            \n(e.g. `Refs: #12`)"
+            }
+            ParseErrorKind::UnexpectedNewLineInSummary => {
+                "Commit summary must be a single line, use `parse` to parse a full message"
+            }
+            ParseErrorKind::MissingBlankLineBeforeBody => {
+                "A blank line is expected between the commit summary and its body or footers"
+            }
+            ParseErrorKind::InvalidEncoding => {
+                "Commit message is not valid in the configured encoding"
+            }
+            ParseErrorKind::SummaryTooLong { .. } => "Commit summary is too long",
+            ParseErrorKind::SummaryTooShort { .. } => "Commit summary is too short",
+            ParseErrorKind::MessageTooLarge { .. } => "Commit message is too large",
+            ParseErrorKind::DisallowedType { .. } => "Commit type is not allowed",
+            ParseErrorKind::DisallowedScope { .. } => "Commit scope is not allowed",
+            ParseErrorKind::MissingCommitSha => {
+                "Missing abbreviated commit hash before the commit header \
+            \n(e.g. `abc1234 feat: add a new feature`)"
+            }
+            ParseErrorKind::MalformedRevertHeader => {
+                "Expected a `Revert \"<reverted header>\"` header"
+            }
+            ParseErrorKind::InvalidRevertedSha { .. } => "Reverted commit hash is invalid",
+            ParseErrorKind::MissingRevertedSha => {
+                "Missing `This reverts commit <sha>.` line in revert message"
+            }
             ParseErrorKind::Other => "Parse error",
         }
     }
 }
 
+impl ParseError {
+    /// Returns a corrected version of the offending line when the error can be fixed
+    /// mechanically, e.g. `feat(scope)!: x` for `feat!(scope): x`
+    ///
+    /// # Example :
+    /// ```
+    /// use conventional_commit_parser::error::ParseErrorKind;
+    /// use conventional_commit_parser::parse;
+    ///
+    /// let error = parse("feat!!(parser): add suggestions").unwrap_err();
+    ///
+    /// assert_eq!(error.kind, ParseErrorKind::InvalidBreakingChangeMark);
+    /// assert_eq!(error.suggestion(), Some("feat(parser)!: add suggestions".to_string()));
+    /// ```
+    ///
+    /// A rejected scope or custom type is replaced by the nearest accepted value used in the
+    /// commit history, when [`crate::options::ParserOptions::suggestion_index`] is set :
+    /// ```
+    /// use std::sync::Arc;
+    /// use conventional_commit_parser::options::{ParserOptions, Validator};
+    /// use conventional_commit_parser::scope::ScopeIndex;
+    /// use conventional_commit_parser::{parse, parse_with_options};
+    ///
+    /// let history = vec![parse("feat(parser): add scope index").unwrap()];
+    ///
+    /// let options = ParserOptions {
+    ///     scope_validator: Some(Validator::one_of(&["parser"])),
+    ///     suggestion_index: Some(Arc::new(ScopeIndex::build(&history))),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let error = parse_with_options("fix(parsre): typo", &options).unwrap_err();
+    ///
+    /// assert_eq!(error.suggestion(), Some("fix(parser): typo".to_string()));
+    /// ```
+    pub fn suggestion(&self) -> Option<String> {
+        match &self.kind {
+            ParseErrorKind::InvalidBreakingChangeMark | ParseErrorKind::BreakingMarkBeforeScope => {
+                fix_breaking_change_marks(self.inner.line())
+            }
+            ParseErrorKind::UnclosedScope => close_scope(self.inner.line()),
+            ParseErrorKind::DisallowedScope { scope } => {
+                let replacement = self.replacement.as_deref()?;
+                let header = self.inner.line();
+                let scope = format!("({})", scope);
+                let start = header.find(&scope)?;
+                Some(format!(
+                    "{}({}){}",
+                    &header[..start],
+                    replacement,
+                    &header[start + scope.len()..]
+                ))
+            }
+            ParseErrorKind::DisallowedType { commit_type } => {
+                let replacement = self.replacement.as_deref()?;
+                let header = self.inner.line();
+                let start = header.find(commit_type.as_str())?;
+                Some(format!(
+                    "{}{}{}",
+                    &header[..start],
+                    replacement,
+                    &header[start + commit_type.len()..]
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the grammar rules expected and unexpected at the error position, `None` for
+    /// errors that are not produced by the grammar such as [`ParseErrorKind::SummaryTooLong`]
+    ///
+    /// # Example :
+    /// ```
+    /// use conventional_commit_parser::error::GrammarRule;
+    /// use conventional_commit_parser::parse;
+    ///
+    /// let error = parse("feat(parser) add rule context").unwrap_err();
+    /// let context = error.rule_context().unwrap();
+    ///
+    /// assert!(context.expected.contains(&GrammarRule::TypeSeparator));
+    /// assert_eq!(GrammarRule::TypeSeparator.name(), "type_separator");
+    /// ```
+    pub fn rule_context(&self) -> Option<RuleContext> {
+        let ErrorVariant::ParsingError {
+            positives,
+            negatives,
+        } = &self.inner.variant
+        else {
+            return None;
+        };
+
+        let rules = |rules: &[Rule]| {
+            let mut mapped: Vec<GrammarRule> = vec![];
+            for rule in rules.iter().copied().map(GrammarRule::from) {
+                if !mapped.contains(&rule) {
+                    mapped.push(rule);
+                }
+            }
+            mapped
+        };
+
+        Some(RuleContext {
+            expected: rules(positives),
+            unexpected: rules(negatives),
+        })
+    }
+
+    /// Build an error that is not produced by the grammar, pointing at byte offset `pos` in `input`
+    pub(crate) fn custom(kind: ParseErrorKind, input: &str, pos: usize) -> Self {
+        let position = Position::new(input, pos).unwrap_or_else(|| Position::from_start(input));
+        let inner = PestError::new_from_pos(
+            ErrorVariant::CustomError {
+                message: kind.as_ref().to_string(),
+            },
+            position,
+        );
+
+        ParseError {
+            inner,
+            kind,
+            replacement: None,
+        }
+    }
+
+    /// Attach the accepted value suggested for a disallowed scope or type
+    pub(crate) fn with_replacement(mut self, replacement: Option<&str>) -> Self {
+        self.replacement = replacement.map(str::to_string);
+        self
+    }
+}
+
+/// Rewrite `type!!(scope)!: summary` headers as `type(scope)!: summary`
+fn fix_breaking_change_marks(header: &str) -> Option<String> {
+    let type_end = header
+        .find(|c: char| !c.is_ascii_alphabetic())
+        .unwrap_or(header.len());
+    let (commit_type, rest) = header.split_at(type_end);
+    let before_scope = rest.trim_start_matches('!');
+    let mut marks = rest.len() - before_scope.len();
+
+    let scope_end = match before_scope.strip_prefix('(') {
+        Some(scope) => scope.find(')').map(|end| end + 2)?,
+        None => 0,
+    };
+    let (scope, rest) = before_scope.split_at(scope_end);
+    let summary = rest.trim_start_matches('!');
+    marks += rest.len() - summary.len();
+
+    let fixed = format!(
+        "{}{}{}{}",
+        commit_type,
+        scope,
+        if marks > 0 { "!" } else { "" },
+        summary
+    );
+
+    Some(fixed).filter(|fixed| fixed != header)
+}
+
+/// Whether a header opens a scope right after the commit type without closing it. Errors
+/// located on a new line, shown by pest as `␊`, are new lines inside the scope instead.
+fn is_unclosed_scope(header: &str) -> bool {
+    if header.ends_with('␊') {
+        return false;
+    }
+
+    let type_end = header
+        .find(|c: char| !c.is_ascii_alphanumeric())
+        .unwrap_or(header.len());
+    type_end > 0
+        && header[type_end..]
+            .strip_prefix('(')
+            .is_some_and(|scope| !scope.contains(')'))
+}
+
+/// Close the scope of `type(scope: summary` headers before the breaking change mark or
+/// the type separator
+fn close_scope(header: &str) -> Option<String> {
+    let open = header.find('(')?;
+    let end = header[open..]
+        .find([':', '!', ' ', '\t'])
+        .map_or(header.len(), |end| open + end);
+    Some(format!("{}){}", &header[..end], &header[end..]))
+}
+
+/// A recoverable formatting issue, reported instead of a [`ParseError`] when the matching
+/// [`crate::options::Policy`] is set to `Warn`
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ParseWarning {
+    pub kind: ParseErrorKind,
+    /// Byte offset of the issue in the parsed message
+    pub offset: usize,
+}
+
+impl Display for ParseWarning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.kind.as_ref())
+    }
+}
+
+/// Error returned by [`crate::compose::CommitMessage::compose`], holding every violation found
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ComposeError {
+    pub errors: Vec<ComposeErrorKind>,
+}
+
+/// A single commit message composition error
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ComposeErrorKind {
+    /// The commit type must be a non empty alphabetic noun
+    InvalidType(String),
+    /// A scope must not be empty nor contain whitespace or parenthesis
+    InvalidScope(String),
+    EmptySummary,
+    MultilineSummary,
+    /// The body is present but blank
+    EmptyBody,
+    /// A footer token must be `BREAKING CHANGE` or a word made of alphanumeric characters and `-`
+    InvalidFooterToken {
+        index: usize,
+        token: String,
+    },
+    EmptyFooterContent {
+        index: usize,
+    },
+    HeaderTooLong {
+        limit: usize,
+        actual: usize,
+    },
+    /// The composed message would not parse back to the same commit,
+    /// typically because the body ends with footer like lines
+    Ambiguous,
+}
+
+impl Display for ComposeErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ComposeErrorKind::InvalidType(commit_type) => {
+                write!(f, "Invalid commit type `{}`", commit_type)
+            }
+            ComposeErrorKind::InvalidScope(scope) => write!(f, "Invalid scope `{}`", scope),
+            ComposeErrorKind::EmptySummary => write!(f, "Summary must not be empty"),
+            ComposeErrorKind::MultilineSummary => {
+                write!(f, "Summary must not contain new lines")
+            }
+            ComposeErrorKind::EmptyBody => write!(f, "Body must not be blank"),
+            ComposeErrorKind::InvalidFooterToken { index, token } => {
+                write!(f, "Invalid token `{}` for footer {}", token, index)
+            }
+            ComposeErrorKind::EmptyFooterContent { index } => {
+                write!(f, "Footer {} must have a value", index)
+            }
+            ComposeErrorKind::HeaderTooLong { limit, actual } => write!(
+                f,
+                "Header is {} characters long, maximum is {}",
+                actual, limit
+            ),
+            ComposeErrorKind::Ambiguous => {
+                write!(f, "Composed message does not parse back to the same commit")
+            }
+        }
+    }
+}
+
+impl Display for ComposeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let errors: Vec<String> = self.errors.iter().map(ToString::to_string).collect();
+        write!(f, "{}", errors.join("\n"))
+    }
+}
+
+impl std::error::Error for ComposeError {}
+
+/// Error returned when a [`crate::changelog::template::UrlTemplate`] is malformed
+#[derive(Debug, Clone)]
+pub struct TemplateError {
+    pub inner: PestError<template::Rule>,
+}
+
+impl Display for TemplateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Malformed template: {}", self.inner)
+    }
+}
+
+impl std::error::Error for TemplateError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.inner)
+    }
+}
+
+impl From<PestError<template::Rule>> for TemplateError {
+    fn from(inner: PestError<template::Rule>) -> Self {
+        TemplateError { inner }
+    }
+}
+
+/// Error returned when a footer value cannot be converted to one of the types of
+/// the [`crate::value`] module
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ValueError {
+    InvalidVersion(String),
+    InvalidDate(String),
+    InvalidUrl(String),
+    InvalidIdentity(String),
+    /// Not an abbreviated or full commit hash, see [`crate::value::is_valid_sha`]
+    InvalidSha(String),
+    /// A malformed dependabot `updated-dependencies` footer, holding the decoder message
+    InvalidDependencies(String),
+    /// Not a footer separator nor a separator name, see [`crate::commit::Separator`]
+    InvalidSeparator(String),
+}
+
+impl Display for ValueError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValueError::InvalidVersion(value) => write!(f, "`{}` is not a semantic version", value),
+            ValueError::InvalidDate(value) => write!(f, "`{}` is not a RFC 3339 date", value),
+            ValueError::InvalidUrl(value) => write!(f, "`{}` is not an absolute URL", value),
+            ValueError::InvalidIdentity(value) => {
+                write!(f, "`{}` is not of the form `Name <email>`", value)
+            }
+            ValueError::InvalidSha(value) => write!(f, "`{}` is not a commit hash", value),
+            ValueError::InvalidDependencies(reason) => {
+                write!(f, "Invalid `updated-dependencies` footer: {}", reason)
+            }
+            ValueError::InvalidSeparator(value) => {
+                write!(f, "`{}` is not a footer separator", value)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ValueError {}
+
+/// Error returned when a lint plugin cannot be loaded,
+/// see [`crate::lint::plugin::DynamicPlugin`]
+#[cfg(feature = "plugins")]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum PluginError {
+    /// The shared object cannot be loaded, holding the loader message
+    Load(String),
+    /// The shared object does not export [`crate::lint::plugin::PLUGIN_ENTRY_POINT`]
+    MissingEntryPoint(String),
+    /// The plugin implements another version of the C ABI
+    IncompatibleAbi { expected: u32, found: u32 },
+    /// The plugin descriptor holds null or non UTF-8 strings
+    InvalidDescriptor(String),
+}
+
+#[cfg(feature = "plugins")]
+impl Display for PluginError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PluginError::Load(reason) => write!(f, "Cannot load lint plugin: {}", reason),
+            PluginError::MissingEntryPoint(path) => write!(
+                f,
+                "`{}` does not export `{}`",
+                path,
+                crate::lint::plugin::PLUGIN_ENTRY_POINT
+            ),
+            PluginError::IncompatibleAbi { expected, found } => write!(
+                f,
+                "Lint plugin ABI version {} is not supported, expected {}",
+                found, expected
+            ),
+            PluginError::InvalidDescriptor(reason) => {
+                write!(f, "Invalid lint plugin descriptor: {}", reason)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "plugins")]
+impl std::error::Error for PluginError {}
+
 impl std::error::Error for ParseError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         Some(&self.inner)
@@ -52,15 +630,62 @@ impl std::error::Error for ParseError {
 
 impl Display for ParseError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.kind.as_ref())
+        match &self.kind {
+            ParseErrorKind::SummaryTooLong { limit, actual } => write!(
+                f,
+                "{} ({} characters, maximum is {})",
+                self.kind.as_ref(),
+                actual,
+                limit
+            ),
+            ParseErrorKind::SummaryTooShort { limit, actual } => write!(
+                f,
+                "{} ({} characters, minimum is {})",
+                self.kind.as_ref(),
+                actual,
+                limit
+            ),
+            ParseErrorKind::MessageTooLarge { limit, actual } => write!(
+                f,
+                "{} ({} bytes, maximum is {})",
+                self.kind.as_ref(),
+                actual,
+                limit
+            ),
+            ParseErrorKind::DisallowedType { commit_type } => {
+                write!(f, "{} (`{}`)", self.kind.as_ref(), commit_type)
+            }
+            ParseErrorKind::DisallowedScope { scope } => {
+                write!(f, "{} (`{}`)", self.kind.as_ref(), scope)
+            }
+            kind => write!(f, "{}", kind.as_ref()),
+        }
     }
 }
 
 impl From<PestError<Rule>> for ParseError {
     fn from(pest_error: PestError<Rule>) -> Self {
         let kind = match pest_error.variant {
-            pest::error::ErrorVariant::ParsingError { ref positives, .. } => {
-                if positives.contains(&Rule::type_separator) {
+            pest::error::ErrorVariant::ParsingError {
+                ref positives,
+                ref negatives,
+            } => {
+                let in_scope = [
+                    Rule::scope,
+                    Rule::scope_content,
+                    Rule::no_whitespace,
+                    Rule::no_parenthesis,
+                ]
+                .iter()
+                .any(|rule| positives.contains(rule));
+
+                if negatives.contains(&Rule::breaking_mark_before_scope) {
+                    ParseErrorKind::BreakingMarkBeforeScope
+                } else if in_scope && is_unclosed_scope(pest_error.line()) {
+                    ParseErrorKind::UnclosedScope
+                } else if negatives.contains(&Rule::invalid_breaking_change_mark) {
+                    ParseErrorKind::InvalidBreakingChangeMark
+                } else if positives.contains(&Rule::type_separator) {
                     ParseErrorKind::MissingSeparator
                 } else if positives.contains(&Rule::no_parenthesis) {
                     ParseErrorKind::UnexpectedParenthesis
@@ -70,6 +695,10 @@ impl From<PestError<Rule>> for ParseError {
                     ParseErrorKind::MissingWhiteSpace
                 } else if positives.contains(&Rule::scope_content) {
                     ParseErrorKind::MalformedScope
+                } else if positives.contains(&Rule::summary_content)
+                    || positives.contains(&Rule::description_start)
+                {
+                    ParseErrorKind::EmptyDescription
                 } else if positives.contains(&Rule::token_separator) {
                     ParseErrorKind::MalformedOrUnexpectedFooterSeparator
                 } else {
@@ -79,9 +708,19 @@ impl From<PestError<Rule>> for ParseError {
             pest::error::ErrorVariant::CustomError { .. } => ParseErrorKind::Other,
         };
 
+        #[cfg(feature = "tracing")]
+        {
+            let (line, column) = match pest_error.line_col {
+                pest::error::LineColLocation::Pos(pos) => pos,
+                pest::error::LineColLocation::Span(start, _) => start,
+            };
+            tracing::debug!(kind = ?kind, line, column, "commit message rule failure");
+        }
+
         ParseError {
             inner: pest_error,
             kind,
+            replacement: None,
         }
     }
 }
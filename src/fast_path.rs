@@ -0,0 +1,82 @@
+//! A fast path for one-line messages, by far the most common in large histories.
+//!
+//! Separator positions are found with `memchr`, which scans several bytes at once with SIMD
+//! instructions where available. Only plain headers are handled here, anything else, errors
+//! included, falls back to the grammar so both paths always agree.
+
+use memchr::{memchr, memchr2};
+
+use crate::commit::{CommitType, ConventionalCommit};
+
+/// The parts of a one-line header, borrowed from the message
+pub(crate) struct OneLineHeader<'a> {
+    pub(crate) commit_type: &'a str,
+    pub(crate) scope: Option<&'a str>,
+    pub(crate) summary: &'a str,
+    pub(crate) breaking_change_mark: bool,
+}
+
+/// Parse a single line `<type>[(scope)][!]: <description>` header, returning `None` when the
+/// message must go through the grammar
+pub(crate) fn parse_one_line(message: &str) -> Option<ConventionalCommit> {
+    let header = split_one_line(message)?;
+    Some(ConventionalCommit {
+        commit_type: CommitType::from(header.commit_type),
+        scope: header.scope.map(str::to_string),
+        summary: header.summary.to_string(),
+        is_breaking_change: header.breaking_change_mark,
+        breaking_change_mark: header.breaking_change_mark,
+        ..Default::default()
+    })
+}
+
+/// Split a single line header into its parts, see [`parse_one_line`]
+pub(crate) fn split_one_line(message: &str) -> Option<OneLineHeader<'_>> {
+    let bytes = message.as_bytes();
+    if memchr2(b'\n', b'\r', bytes).is_some() {
+        return None;
+    }
+
+    // The type and scope never hold a colon in the headers handled here
+    let colon = memchr(b':', bytes)?;
+    if bytes.get(colon + 1) != Some(&b' ') {
+        return None;
+    }
+
+    let summary = &message[colon + 2..];
+    if summary.trim_start_matches([' ', '\t']).is_empty() {
+        return None;
+    }
+
+    let prefix = &message[..colon];
+    let (prefix, breaking_change_mark) = match prefix.strip_suffix('!') {
+        Some(prefix) => (prefix, true),
+        None => (prefix, false),
+    };
+
+    let (commit_type, scope) = match memchr2(b'(', b')', prefix.as_bytes()) {
+        None => (prefix, None),
+        Some(open) => {
+            let scope = prefix[open..].strip_prefix('(')?.strip_suffix(')')?;
+            let valid_scope = !scope.is_empty()
+                && memchr2(b'(', b')', scope.as_bytes()).is_none()
+                && !scope.contains(char::is_whitespace);
+            if !valid_scope {
+                return None;
+            }
+
+            (&prefix[..open], Some(scope))
+        }
+    };
+
+    if commit_type.is_empty() || !commit_type.bytes().all(|b| b.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    Some(OneLineHeader {
+        commit_type,
+        scope,
+        summary,
+        breaking_change_mark,
+    })
+}
@@ -0,0 +1,425 @@
+//! A curated corpus of real-world commit messages along with their expected parse results,
+//! so downstream crates can run conformance tests against the same data as this crate.
+//!
+//! # Example :
+//! ```
+//! use conventional_commit_parser::fixtures::run;
+//! use conventional_commit_parser::parse;
+//!
+//! let mismatches = run(parse);
+//!
+//! assert!(mismatches.is_empty(), "{:?}", mismatches);
+//! ```
+//!
+//! With the `json` feature, the corpus is exported as language agnostic test vectors with
+//! [`to_json`], checked in as `conformance/vectors.json` so implementations in other
+//! languages can verify parity with this crate.
+
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::commit::{CommitType, ConventionalCommit, Footer, Separator};
+use crate::error::{ParseError, ParseErrorKind};
+
+/// The origin of a fixture message
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Category {
+    /// Dependency updates written by dependabot, with a YAML `updated-dependencies` footer
+    Dependabot,
+    /// Dependency updates written by renovate, with a markdown table body
+    Renovate,
+    /// Merge commits written by git or a forge, which are not conventional commits
+    Merge,
+    /// Commits using [gitmoji](https://gitmoji.dev) shortcodes or emojis
+    Gitmoji,
+    /// Commits with non ASCII scopes and summaries
+    Unicode,
+}
+
+impl Category {
+    /// A stable kebab-case identifier of the category
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Category::Dependabot => "dependabot",
+            Category::Renovate => "renovate",
+            Category::Merge => "merge",
+            Category::Gitmoji => "gitmoji",
+            Category::Unicode => "unicode",
+        }
+    }
+}
+
+/// The expected result of parsing a fixture message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expected {
+    /// The message parses into this commit, compared with [`ConventionalCommit::raw_eq`]
+    Commit(Box<ConventionalCommit>),
+    /// The message is rejected with this error kind
+    Error(ParseErrorKind),
+}
+
+/// A commit message of the corpus, see [`corpus`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fixture {
+    /// A unique kebab-case name, to report mismatches
+    pub name: &'static str,
+    pub category: Category,
+    pub message: &'static str,
+    pub expected: Expected,
+}
+
+/// A fixture whose parse result differs from the expected one, see [`Fixture::check`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixtureMismatch {
+    /// Name of the fixture
+    pub name: &'static str,
+    /// The expected result, debug formatted
+    pub expected: String,
+    /// The actual result, debug formatted
+    pub actual: String,
+}
+
+impl fmt::Display for FixtureMismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "fixture `{}`: expected {}, got {}",
+            self.name, self.expected, self.actual
+        )
+    }
+}
+
+impl Fixture {
+    /// Check a parse result of the fixture message against the expected result
+    ///
+    /// # Example :
+    /// ```
+    /// use conventional_commit_parser::fixtures::{corpus, Category};
+    /// use conventional_commit_parser::parse;
+    ///
+    /// for fixture in corpus().iter().filter(|f| f.category == Category::Merge) {
+    ///     assert!(fixture.check(&parse(fixture.message)).is_ok());
+    /// }
+    /// ```
+    pub fn check(
+        &self,
+        result: &Result<ConventionalCommit, ParseError>,
+    ) -> Result<(), FixtureMismatch> {
+        let matches = match (&self.expected, result) {
+            (Expected::Commit(expected), Ok(commit)) => expected.raw_eq(commit),
+            (Expected::Error(kind), Err(error)) => *kind == error.kind,
+            _ => false,
+        };
+
+        if matches {
+            return Ok(());
+        }
+
+        let expected = match &self.expected {
+            Expected::Commit(commit) => format!("Ok({:?})", commit),
+            Expected::Error(kind) => format!("Err({:?})", kind),
+        };
+
+        Err(FixtureMismatch {
+            name: self.name,
+            expected,
+            actual: format!("{:?}", result.as_ref().map_err(|error| &error.kind)),
+        })
+    }
+}
+
+/// Run a parser against every fixture of the [`corpus`], returning the mismatches in
+/// corpus order
+pub fn run<F>(parser: F) -> Vec<FixtureMismatch>
+where
+    F: Fn(&str) -> Result<ConventionalCommit, ParseError>,
+{
+    corpus()
+        .iter()
+        .filter_map(|fixture| fixture.check(&parser(fixture.message)).err())
+        .collect()
+}
+
+/// Export the [`corpus`] as JSON test vectors, along with a `format_version` bumped on
+/// incompatible layout changes. Each vector holds the fixture `name`,
+/// `category` and `message`, along with either the expected `commit` or the expected `error`
+/// kind, named after its [`ParseErrorKind`] variant. Footer separators are written as
+/// [`Separator::as_str`].
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::fixtures::to_json;
+///
+/// let vectors = to_json();
+/// let merge = vectors["vectors"]
+///     .as_array()
+///     .unwrap()
+///     .iter()
+///     .find(|vector| vector["name"] == "merge-branch")
+///     .unwrap();
+///
+/// assert_eq!(merge["message"], "Merge branch 'develop' into main");
+/// assert_eq!(merge["expected"]["error"], "MissingSeparator");
+/// ```
+#[cfg(feature = "json")]
+pub fn to_json() -> serde_json::Value {
+    use serde_json::json;
+
+    let vectors: Vec<serde_json::Value> = corpus()
+        .iter()
+        .map(|fixture| {
+            let expected = match &fixture.expected {
+                Expected::Commit(commit) => json!({
+                    "commit": {
+                        "type": commit.commit_type.as_ref(),
+                        "scope": commit.scope,
+                        "summary": commit.summary,
+                        "body": commit.body,
+                        "footers": commit.footers.iter().map(|footer| json!({
+                            "token": footer.token,
+                            "separator": footer.token_separator.as_str(),
+                            "content": footer.content,
+                        })).collect::<Vec<_>>(),
+                        "breaking_change": commit.is_breaking_change,
+                        "breaking_change_mark": commit.breaking_change_mark,
+                    }
+                }),
+                Expected::Error(kind) => {
+                    let name = format!("{:?}", kind);
+                    let name = name.split([' ', '{']).next().unwrap_or_default();
+                    json!({ "error": name })
+                }
+            };
+
+            json!({
+                "name": fixture.name,
+                "category": fixture.category.as_str(),
+                "message": fixture.message,
+                "expected": expected,
+            })
+        })
+        .collect();
+
+    json!({
+        "format_version": 1,
+        "vectors": vectors,
+    })
+}
+
+/// The fixture corpus, grouped by [`Category`]. Expected results are those of
+/// [`crate::parse`] with the default options.
+pub fn corpus() -> Vec<Fixture> {
+    vec![
+        Fixture {
+            name: "dependabot-maven",
+            category: Category::Dependabot,
+            message: "chore(deps): bump spring-boot-starter-parent from 2.5.5 to 2.5.6\n\
+                \n\
+                Bumps [spring-boot-starter-parent](https://github.com/spring-projects/spring-boot) from 2.5.5 to 2.5.6.\n\
+                - [Release notes](https://github.com/spring-projects/spring-boot/releases)\n\
+                - [Commits](spring-projects/spring-boot@v2.5.5...v2.5.6)\n\
+                \n\
+                ---\n\
+                updated-dependencies:\n\
+                - dependency-name: org.springframework.boot:spring-boot-starter-parent\n  \
+                  dependency-type: direct:production\n  \
+                  update-type: version-update:semver-patch\n\
+                ...\n\
+                \n\
+                Signed-off-by: dependabot[bot] <support@github.com>",
+            expected: Expected::Commit(Box::new(ConventionalCommit {
+                body: Some(
+                    "Bumps [spring-boot-starter-parent](https://github.com/spring-projects/spring-boot) from 2.5.5 to 2.5.6.\n\
+                    - [Release notes](https://github.com/spring-projects/spring-boot/releases)\n\
+                    - [Commits](spring-projects/spring-boot@v2.5.5...v2.5.6)\n\
+                    \n\
+                    ---"
+                        .to_string(),
+                ),
+                footers: vec![
+                    footer(
+                        "updated-dependencies",
+                        Separator::ColonWithNewLine,
+                        "- dependency-name: org.springframework.boot:spring-boot-starter-parent\n  \
+                          dependency-type: direct:production\n  \
+                          update-type: version-update:semver-patch\n\
+                        ...",
+                    ),
+                    footer(
+                        "Signed-off-by",
+                        Separator::Colon,
+                        "dependabot[bot] <support@github.com>",
+                    ),
+                ],
+                ..commit(
+                    CommitType::Chore,
+                    Some("deps"),
+                    "bump spring-boot-starter-parent from 2.5.5 to 2.5.6",
+                )
+            })),
+        },
+        Fixture {
+            name: "dependabot-github-actions",
+            category: Category::Dependabot,
+            message: "ci(deps): bump actions/checkout from 3 to 4\n\
+                \n\
+                Bumps [actions/checkout](https://github.com/actions/checkout) from 3 to 4.\n\
+                \n\
+                ---\n\
+                updated-dependencies:\n\
+                - dependency-name: actions/checkout\n  \
+                  dependency-type: direct:production\n  \
+                  update-type: version-update:semver-major\n\
+                ...\n\
+                \n\
+                Signed-off-by: dependabot[bot] <support@github.com>",
+            expected: Expected::Commit(Box::new(ConventionalCommit {
+                body: Some(
+                    "Bumps [actions/checkout](https://github.com/actions/checkout) from 3 to 4.\n\
+                    \n\
+                    ---"
+                    .to_string(),
+                ),
+                footers: vec![
+                    footer(
+                        "updated-dependencies",
+                        Separator::ColonWithNewLine,
+                        "- dependency-name: actions/checkout\n  \
+                          dependency-type: direct:production\n  \
+                          update-type: version-update:semver-major\n\
+                        ...",
+                    ),
+                    footer(
+                        "Signed-off-by",
+                        Separator::Colon,
+                        "dependabot[bot] <support@github.com>",
+                    ),
+                ],
+                ..commit(
+                    CommitType::Ci,
+                    Some("deps"),
+                    "bump actions/checkout from 3 to 4",
+                )
+            })),
+        },
+        Fixture {
+            name: "renovate-table",
+            category: Category::Renovate,
+            message: "chore(deps): update rust crate serde to v1.0.152\n\
+                \n\
+                This PR contains the following updates:\n\
+                \n\
+                | Package | Type | Update | Change |\n\
+                |---|---|---|---|\n\
+                | serde | dependencies | patch | `1.0.151` -> `1.0.152` |",
+            expected: Expected::Commit(Box::new(ConventionalCommit {
+                body: Some(
+                    "This PR contains the following updates:\n\
+                    \n\
+                    | Package | Type | Update | Change |\n\
+                    |---|---|---|---|\n\
+                    | serde | dependencies | patch | `1.0.151` -> `1.0.152` |"
+                        .to_string(),
+                ),
+                ..commit(
+                    CommitType::Chore,
+                    Some("deps"),
+                    "update rust crate serde to v1.0.152",
+                )
+            })),
+        },
+        Fixture {
+            name: "renovate-squashed",
+            category: Category::Renovate,
+            message: "fix(deps): update rust crate lru to 0.12 (#64)",
+            expected: Expected::Commit(Box::new(commit(
+                CommitType::BugFix,
+                Some("deps"),
+                "update rust crate lru to 0.12 (#64)",
+            ))),
+        },
+        Fixture {
+            name: "merge-branch",
+            category: Category::Merge,
+            message: "Merge branch 'develop' into main",
+            expected: Expected::Error(ParseErrorKind::MissingSeparator),
+        },
+        Fixture {
+            name: "merge-remote-tracking-branch",
+            category: Category::Merge,
+            message: "Merge remote-tracking branch 'origin/main'",
+            expected: Expected::Error(ParseErrorKind::MissingSeparator),
+        },
+        Fixture {
+            name: "merge-pull-request",
+            category: Category::Merge,
+            message: "Merge pull request #42 from oknozor/feat/fixtures\n\nfeat: add fixtures",
+            expected: Expected::Error(ParseErrorKind::MissingSeparator),
+        },
+        Fixture {
+            name: "gitmoji-shortcode-summary",
+            category: Category::Gitmoji,
+            message: "feat: :sparkles: add fixtures",
+            expected: Expected::Commit(Box::new(commit(
+                CommitType::Feature,
+                None,
+                ":sparkles: add fixtures",
+            ))),
+        },
+        Fixture {
+            name: "gitmoji-shortcode-prefix",
+            category: Category::Gitmoji,
+            message: ":sparkles: feat: add fixtures",
+            expected: Expected::Error(ParseErrorKind::Other),
+        },
+        Fixture {
+            name: "gitmoji-emoji-prefix",
+            category: Category::Gitmoji,
+            message: "✨ feat(parser): add fixtures",
+            expected: Expected::Error(ParseErrorKind::Other),
+        },
+        Fixture {
+            name: "unicode-cjk-summary",
+            category: Category::Unicode,
+            message: "feat(i18n): 添加中文支持",
+            expected: Expected::Commit(Box::new(commit(
+                CommitType::Feature,
+                Some("i18n"),
+                "添加中文支持",
+            ))),
+        },
+        Fixture {
+            name: "unicode-cjk-scope",
+            category: Category::Unicode,
+            message: "feat(日本語): サポート",
+            expected: Expected::Commit(Box::new(commit(CommitType::Feature, Some("日本語"), "サポート"))),
+        },
+        Fixture {
+            name: "unicode-accents",
+            category: Category::Unicode,
+            message: "fix(ui): afficher l'état « prêt »",
+            expected: Expected::Commit(Box::new(commit(
+                CommitType::BugFix,
+                Some("ui"),
+                "afficher l'état « prêt »",
+            ))),
+        },
+    ]
+}
+
+fn commit(commit_type: CommitType, scope: Option<&str>, summary: &str) -> ConventionalCommit {
+    ConventionalCommit {
+        commit_type,
+        scope: scope.map(str::to_string),
+        summary: summary.to_string(),
+        ..Default::default()
+    }
+}
+
+fn footer(token: &str, token_separator: Separator, content: &str) -> Footer {
+    Footer {
+        token: token.to_string(),
+        content: content.to_string(),
+        token_separator,
+    }
+}
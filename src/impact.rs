@@ -0,0 +1,122 @@
+//! Commit impact scoring, combining the breaking change flag, the commit type
+//! and configured scope weights.
+
+use std::collections::HashMap;
+
+use crate::commit::{CommitType, ConventionalCommit};
+
+/// Weights used to compute a commit [`Impact`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImpactWeights {
+    /// Added to the score of breaking changes
+    pub breaking_change: u32,
+    /// Weight of each commit type
+    pub types: HashMap<CommitType, u32>,
+    /// Weight of commit types missing from `types`
+    pub default_type: u32,
+    /// Added to the score of commits touching a scope, for instance `api` or `security`
+    pub scopes: HashMap<String, u32>,
+    /// Minimum score of an [`ImpactLevel::Medium`] impact
+    pub medium_threshold: u32,
+    /// Minimum score of an [`ImpactLevel::High`] impact
+    pub high_threshold: u32,
+}
+
+/// Coarse impact level, see [`ImpactWeights`] thresholds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ImpactLevel {
+    Low,
+    Medium,
+    High,
+}
+
+/// The impact of a commit, see [`ConventionalCommit::impact`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Impact {
+    pub score: u32,
+    pub level: ImpactLevel,
+}
+
+impl Default for ImpactWeights {
+    fn default() -> Self {
+        let types = vec![
+            (CommitType::Feature, 20),
+            (CommitType::BugFix, 10),
+            (CommitType::Performances, 10),
+            (CommitType::Revert, 10),
+            (CommitType::Refactor, 5),
+            (CommitType::Build, 5),
+        ]
+        .into_iter()
+        .collect();
+
+        ImpactWeights {
+            breaking_change: 50,
+            types,
+            default_type: 1,
+            scopes: HashMap::new(),
+            medium_threshold: 10,
+            high_threshold: 50,
+        }
+    }
+}
+
+impl ImpactWeights {
+    fn level(&self, score: u32) -> ImpactLevel {
+        if score >= self.high_threshold {
+            ImpactLevel::High
+        } else if score >= self.medium_threshold {
+            ImpactLevel::Medium
+        } else {
+            ImpactLevel::Low
+        }
+    }
+}
+
+impl ConventionalCommit {
+    /// Score the commit impact: the commit type weight plus the breaking change and
+    /// scope weights when relevant
+    ///
+    /// # Example :
+    /// ```
+    /// use conventional_commit_parser::impact::{ImpactLevel, ImpactWeights};
+    /// use conventional_commit_parser::parse;
+    ///
+    /// let mut weights = ImpactWeights::default();
+    /// weights.scopes.insert("api".to_string(), 30);
+    ///
+    /// let impact = parse("fix(api): reject empty tokens").unwrap().impact(&weights);
+    ///
+    /// assert_eq!(impact.score, 40);
+    /// assert_eq!(impact.level, ImpactLevel::Medium);
+    /// ```
+    pub fn impact(&self, weights: &ImpactWeights) -> Impact {
+        let type_weight = weights
+            .types
+            .get(&self.commit_type)
+            .copied()
+            .unwrap_or(weights.default_type);
+
+        let breaking_change_weight = if self.is_breaking_change {
+            weights.breaking_change
+        } else {
+            0
+        };
+
+        let scope_weight = self
+            .scope
+            .as_ref()
+            .and_then(|scope| weights.scopes.get(scope))
+            .copied()
+            .unwrap_or_default();
+
+        let score = type_weight
+            .saturating_add(breaking_change_weight)
+            .saturating_add(scope_weight);
+
+        Impact {
+            score,
+            level: weights.level(score),
+        }
+    }
+}
@@ -0,0 +1,160 @@
+//! Parse profiling, requires the `instrumentation` feature.
+//!
+//! [`parse_instrumented`] parses a commit message like [`crate::parse`], reporting the
+//! duration of each parse section to a [`ParseObserver`]. Allocation counts are reported
+//! when the observer provides them, typically from a counting global allocator.
+//!
+//! # Example :
+//! ```
+//! use std::time::Duration;
+//!
+//! use conventional_commit_parser::instrumentation::{
+//!     parse_instrumented, ParseSection, SectionProfile,
+//! };
+//!
+//! let mut total = Duration::ZERO;
+//! let mut sections = vec![];
+//!
+//! let commit = parse_instrumented("fix: typo\n\nRefs: #12", &mut |profile: SectionProfile| {
+//!     total += profile.duration;
+//!     sections.push(profile.section);
+//! })
+//! .unwrap();
+//!
+//! assert_eq!(commit.footers.len(), 1);
+//! assert_eq!(sections[0], ParseSection::Grammar);
+//! assert_eq!(sections[2], ParseSection::Footers);
+//! ```
+
+use std::time::{Duration, Instant};
+
+use pest::Parser;
+
+use crate::commit::ConventionalCommit;
+use crate::error::{ParseError, ParseErrorKind};
+use crate::{is_effectively_empty, ConventionalCommitParser, Rule};
+
+/// A step of the parsing of a commit message
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseSection {
+    /// Matching the message against the grammar
+    Grammar,
+    /// Building the commit type, scope and summary
+    Header,
+    /// Building the body
+    Body,
+    /// Building the footers
+    Footers,
+    /// Extracting the cherry-pick line
+    PostProcessing,
+}
+
+/// The cost of a parse section, see [`ParseObserver`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SectionProfile {
+    pub section: ParseSection,
+    pub duration: Duration,
+    /// Allocations performed during the section, `None` when the observer does not count them
+    pub allocations: Option<usize>,
+}
+
+/// Receives the profile of each parse section, implemented for `FnMut(SectionProfile)`
+/// closures
+pub trait ParseObserver {
+    /// Called after each parse section, sections missing from the message are not reported
+    fn section(&mut self, profile: SectionProfile);
+
+    /// The number of allocations performed so far, e.g. read from a counting global
+    /// allocator. Allocation counts are not reported when `None`, the default.
+    fn allocations(&self) -> Option<usize> {
+        None
+    }
+}
+
+impl<F: FnMut(SectionProfile)> ParseObserver for F {
+    fn section(&mut self, profile: SectionProfile) {
+        self(profile)
+    }
+}
+
+/// Times parse sections, reporting them to the observer
+struct Profiler<'a, O: ParseObserver> {
+    observer: &'a mut O,
+    started: Instant,
+    allocations: Option<usize>,
+}
+
+impl<'a, O: ParseObserver> Profiler<'a, O> {
+    fn new(observer: &'a mut O) -> Self {
+        let allocations = observer.allocations();
+        Profiler {
+            observer,
+            started: Instant::now(),
+            allocations,
+        }
+    }
+
+    fn record(&mut self, section: ParseSection) {
+        let duration = self.started.elapsed();
+        let allocations = self.observer.allocations();
+        let profile = SectionProfile {
+            section,
+            duration,
+            allocations: allocations
+                .zip(self.allocations)
+                .map(|(now, before)| now.saturating_sub(before)),
+        };
+
+        self.observer.section(profile);
+        self.allocations = self.observer.allocations();
+        self.started = Instant::now();
+    }
+}
+
+/// Parse a commit message like [`crate::parse`], reporting the profile of each parse
+/// section to `observer`. Sections are reported until the parse fails, the grammar
+/// section is not reported for rejected messages.
+pub fn parse_instrumented<O: ParseObserver>(
+    commit_message: &str,
+    observer: &mut O,
+) -> Result<ConventionalCommit, ParseError> {
+    if is_effectively_empty(commit_message) {
+        return Err(ParseError::custom(
+            ParseErrorKind::EmptyCommitMessage,
+            commit_message,
+            0,
+        ));
+    }
+
+    let mut profiler = Profiler::new(observer);
+    let pairs = ConventionalCommitParser::parse(Rule::message, commit_message)
+        .map_err(ParseError::from)?
+        .next()
+        .unwrap();
+    profiler.record(ParseSection::Grammar);
+
+    let mut commit = ConventionalCommit::default();
+
+    for pair in pairs.into_inner() {
+        match pair.as_rule() {
+            Rule::summary => {
+                commit.set_summary(pair);
+                profiler.record(ParseSection::Header);
+            }
+            Rule::body => {
+                commit.set_commit_body(pair);
+                profiler.record(ParseSection::Body);
+            }
+            Rule::footers => {
+                commit.set_footers(pair);
+                profiler.record(ParseSection::Footers);
+            }
+            _ => (),
+        }
+    }
+
+    commit.extract_cherry_pick();
+    profiler.record(ParseSection::PostProcessing);
+
+    Ok(commit)
+}
@@ -0,0 +1,147 @@
+//! Issues closed by a commit, referenced with GitHub style closing keywords
+
+use crate::commit::{ConventionalCommit, Separator};
+
+/// Keywords closing the issue that follows them, matched case-insensitively
+const CLOSING_KEYWORDS: [&str; 9] = [
+    "close", "closes", "closed", "fix", "fixes", "fixed", "resolve", "resolves", "resolved",
+];
+
+/// An issue referenced after a closing keyword (`closes #1`, `fixes org/repo#2`,
+/// `resolves ABC-3`)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ClosedIssue {
+    /// A `#1` issue number, optionally prefixed with an `owner/repository`
+    Number {
+        repository: Option<String>,
+        number: u64,
+    },
+    /// An issue tracker key, such as a Jira `ABC-3` issue
+    Key(String),
+}
+
+impl ClosedIssue {
+    /// Parse a single issue reference, trailing punctuation is ignored
+    ///
+    /// # Example :
+    /// ```
+    /// use conventional_commit_parser::issue::ClosedIssue;
+    ///
+    /// assert_eq!(ClosedIssue::parse("oknozor/cocogitto#2,"), Some(ClosedIssue::Number {
+    ///     repository: Some("oknozor/cocogitto".to_string()),
+    ///     number: 2,
+    /// }));
+    /// assert_eq!(ClosedIssue::parse("ABC-3"), Some(ClosedIssue::Key("ABC-3".to_string())));
+    /// assert_eq!(ClosedIssue::parse("the"), None);
+    /// ```
+    pub fn parse(reference: &str) -> Option<ClosedIssue> {
+        let reference = reference.trim_end_matches([',', '.', ';', ')']);
+        let reference = reference.trim_start_matches('(');
+
+        if let Some((repository, number)) = reference.split_once('#') {
+            let number = number.parse().ok()?;
+            let repository = match repository {
+                "" => None,
+                repository if is_repository(repository) => Some(repository.to_string()),
+                _ => return None,
+            };
+
+            return Some(ClosedIssue::Number { repository, number });
+        }
+
+        let (project, number) = reference.split_once('-')?;
+        let is_key = project.starts_with(|c: char| c.is_ascii_uppercase())
+            && project
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+            && !number.is_empty()
+            && number.chars().all(|c| c.is_ascii_digit());
+
+        is_key.then(|| ClosedIssue::Key(reference.to_string()))
+    }
+}
+
+fn is_repository(repository: &str) -> bool {
+    let valid = |part: &str| {
+        !part.is_empty()
+            && part
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+    };
+
+    matches!(repository.split_once('/'), Some((owner, name)) if valid(owner) && valid(name))
+}
+
+fn is_closing_keyword(word: &str) -> bool {
+    let word = word.strip_suffix(':').unwrap_or(word);
+    CLOSING_KEYWORDS
+        .iter()
+        .any(|keyword| keyword.eq_ignore_ascii_case(word))
+}
+
+/// Find the issues following closing keywords in a free text, comma separated references
+/// are all closed: `fixes #1, #2`
+fn text_closed_issues(text: &str, issues: &mut Vec<ClosedIssue>) {
+    let mut words = text.split_whitespace().peekable();
+    while let Some(word) = words.next() {
+        if !is_closing_keyword(word) {
+            continue;
+        }
+
+        while let Some(issue) = words.peek().and_then(|word| ClosedIssue::parse(word)) {
+            issues.push(issue);
+            let continues = words.next().is_some_and(|word| word.ends_with(','));
+            if !continues {
+                break;
+            }
+        }
+    }
+}
+
+impl ConventionalCommit {
+    /// Returns the issues closed by this commit, referenced after a closing keyword
+    /// (`close`, `fix` or `resolve` and their variants) in the summary, the body or a footer.
+    /// Duplicates are removed, keeping the first occurrence.
+    ///
+    /// # Example :
+    /// ```
+    /// use conventional_commit_parser::issue::ClosedIssue;
+    /// use conventional_commit_parser::parse;
+    ///
+    /// let commit = parse("fix: handle tabs, fixes #1\n\nResolves ABC-3\nCloses #1").unwrap();
+    ///
+    /// assert_eq!(commit.closed_issues(), vec![
+    ///     ClosedIssue::Number { repository: None, number: 1 },
+    ///     ClosedIssue::Key("ABC-3".to_string()),
+    /// ]);
+    /// ```
+    pub fn closed_issues(&self) -> Vec<ClosedIssue> {
+        let mut issues = vec![];
+        text_closed_issues(&self.summary, &mut issues);
+
+        if let Some(body) = &self.body {
+            text_closed_issues(body, &mut issues);
+        }
+
+        for footer in &self.footers {
+            if !is_closing_keyword(&footer.token) {
+                continue;
+            }
+
+            let references = match footer.token_separator {
+                Separator::Hash => format!("{} #{}", footer.token, footer.content),
+                _ => format!("{} {}", footer.token, footer.content),
+            };
+            text_closed_issues(&references, &mut issues);
+        }
+
+        let mut closed_issues: Vec<ClosedIssue> = vec![];
+        for issue in issues {
+            if !closed_issues.contains(&issue) {
+                closed_issues.push(issue);
+            }
+        }
+
+        closed_issues
+    }
+}
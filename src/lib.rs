@@ -44,17 +44,35 @@
 //! # }
 //! ```
 //!
-#[macro_use]
-extern crate pest_derive;
+//! ## Untrusted input
+//!
+//! The grammar has no recursive rules and its lookaheads never scan past the next line,
+//! parsing time is linear in the message size and the stack depth does not depend on the input.
+//! Services parsing untrusted messages should bound the input size with
+//! [`options::ParserOptions::max_message_len`].
+//!
+//! ## Tracing
+//!
+//! With the `tracing` feature enabled, parsing functions open a `parse` span and emit debug
+//! events for grammar rule failures and for recovery decisions (header rewrites, lossy decoding,
+//! size limits and [`options::Policy`] outcomes).
+//!
+//! ## Instrumentation
+//!
+//! With the `instrumentation` feature enabled, `instrumentation::parse_instrumented` reports
+//! the duration and allocation count of each parse section to a pluggable callback.
+//!
+#![allow(clippy::result_large_err)]
 
-#[cfg(test)]
 #[macro_use]
-extern crate speculoos;
+extern crate pest_derive;
 
 use pest::Parser;
 
-use crate::commit::{ConventionalCommit, Footer};
-use crate::error::ParseError;
+use crate::commit::{ConventionalCommit, Footer, RevertCommit};
+use crate::encoding::{DecodedCommit, RawCommit};
+use crate::error::{ParseError, ParseErrorKind};
+use crate::options::{DiagnosedCommit, ParserOptions};
 
 /// Conventional commit representation, produced by the [parse] function
 ///
@@ -63,13 +81,99 @@ pub mod commit;
 
 pub mod error;
 
+/// Decoding of raw, possibly non UTF-8, commit message bytes
+pub mod encoding;
+
+/// Options controlling the parser behavior
+pub mod options;
+
+pub mod value;
+
+pub mod changelog;
+
+/// Compose commit messages from structured input
+pub mod compose;
+
+pub mod lint;
+
+pub mod policy;
+
+pub mod dependency;
+
+pub mod impact;
+
+pub mod monorepo;
+
+pub mod issue;
+
+pub mod query;
+
+pub mod release;
+
+pub mod scope;
+
+pub mod stats;
+
+pub mod sections;
+
+pub mod token;
+
+pub mod template;
+
+#[cfg(feature = "cache")]
+pub mod cache;
+
+#[cfg(feature = "fixtures")]
+pub mod fixtures;
+
+#[cfg(feature = "ansi")]
+pub mod ansi;
+
+#[cfg(feature = "instrumentation")]
+pub mod instrumentation;
+
+#[cfg(feature = "bumpalo")]
+pub mod arena;
+
+mod fast_path;
+
 #[doc(hidden)]
 #[derive(Parser)]
 #[grammar = "grammar.pest"]
 struct ConventionalCommitParser;
 
-/// Parse a commit message into a [`commit::ConventionalCommit`]
+/// Parse a commit message into a [`commit::ConventionalCommit`], empty and whitespace only
+/// messages are rejected with [`ParseErrorKind::EmptyCommitMessage`]
 pub fn parse(commit_message: &str) -> Result<ConventionalCommit, ParseError> {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::debug_span!("parse", len = commit_message.len()).entered();
+
+    if is_effectively_empty(commit_message) {
+        return Err(ParseError::custom(
+            ParseErrorKind::EmptyCommitMessage,
+            commit_message,
+            0,
+        ));
+    }
+
+    let commit = match fast_path::parse_one_line(commit_message) {
+        Some(commit) => commit,
+        None => parse_with_grammar(commit_message)?,
+    };
+
+    #[cfg(feature = "tracing")]
+    tracing::debug!(
+        commit_type = commit.commit_type.as_ref(),
+        breaking_change = commit.is_breaking_change,
+        footers = commit.footers.len(),
+        "commit message parsed"
+    );
+
+    Ok(commit)
+}
+
+/// Parse a message with the grammar, messages the fast path does not handle end up here
+fn parse_with_grammar(commit_message: &str) -> Result<ConventionalCommit, ParseError> {
     let pairs = ConventionalCommitParser::parse(Rule::message, commit_message)
         .map_err(ParseError::from)?
         .next()
@@ -86,9 +190,113 @@ pub fn parse(commit_message: &str) -> Result<ConventionalCommit, ParseError> {
         }
     }
 
+    commit.extract_cherry_pick();
     Ok(commit)
 }
 
+/// Parse a commit message into a [`commit::ConventionalCommit`], enforcing the policies
+/// configured in [`ParserOptions`]
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::parse_with_options;
+/// use conventional_commit_parser::error::ParseErrorKind;
+/// use conventional_commit_parser::options::ParserOptions;
+///
+/// let options = ParserOptions {
+///     summary_max_len: Some(20),
+///     ..Default::default()
+/// };
+///
+/// let error = parse_with_options("feat: a summary that is way too long", &options).unwrap_err();
+///
+/// assert_eq!(error.kind, ParseErrorKind::SummaryTooLong { limit: 20, actual: 30 });
+/// ```
+///
+/// Alternative header styles are mapped onto the same [`commit::ConventionalCommit`] model,
+/// error positions then refer to the rewritten conventional header :
+/// ```
+/// use conventional_commit_parser::parse_with_options;
+/// use conventional_commit_parser::options::{HeaderStyle, ParserOptions};
+///
+/// let options = ParserOptions {
+///     header_style: HeaderStyle::SlashScope,
+///     ..Default::default()
+/// };
+///
+/// let commit = parse_with_options("feat/parser: add header styles", &options).unwrap();
+///
+/// assert_eq!(commit.scope, Some("parser".to_string()));
+/// assert_eq!(commit.summary, "add header styles");
+/// ```
+pub fn parse_with_options(
+    commit_message: &str,
+    options: &ParserOptions,
+) -> Result<ConventionalCommit, ParseError> {
+    parse_with_diagnostics(commit_message, options).map(|diagnosed| diagnosed.commit)
+}
+
+/// Parse a commit message like [`parse_with_options`], also returning the warnings
+/// reported by options set to [`options::Policy::Warn`]
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::parse_with_diagnostics;
+/// use conventional_commit_parser::error::ParseErrorKind;
+/// use conventional_commit_parser::options::{ParserOptions, Policy};
+///
+/// let options = ParserOptions {
+///     missing_blank_line_before_body: Policy::Warn,
+///     ..Default::default()
+/// };
+///
+/// let diagnosed = parse_with_diagnostics("fix: typo\nin the readme", &options).unwrap();
+///
+/// assert_eq!(diagnosed.commit.body, Some("in the readme".to_string()));
+/// assert_eq!(diagnosed.warnings[0].kind, ParseErrorKind::MissingBlankLineBeforeBody);
+/// ```
+pub fn parse_with_diagnostics(
+    commit_message: &str,
+    options: &ParserOptions,
+) -> Result<DiagnosedCommit, ParseError> {
+    options.check_message_len(commit_message, commit_message.len())?;
+    let (pre_header_tag, commit_message) = options.split_pre_header_tag(commit_message);
+    let commit_message = options.header_style.rewrite_header(commit_message);
+
+    #[cfg(feature = "tracing")]
+    if let std::borrow::Cow::Owned(_) = commit_message {
+        tracing::debug!(header_style = ?options.header_style, "header rewritten to the conventional style");
+    }
+
+    let commit_message = options.mask_breaking_change_tokens(commit_message);
+    let (commit_message, custom_type) = options.mask_custom_type(commit_message);
+    let commit_message = options.mask_escaped_scope_parenthesis(commit_message);
+
+    let mut commit = parse(&commit_message)?;
+    commit.pre_header_tag = pre_header_tag.map(str::to_string);
+    options.unmask_custom_type(custom_type, &mut commit);
+    options.apply_breaking_change_tokens(&mut commit);
+    options.unmask_scope_parenthesis(&mut commit);
+    options.footer_continuation.apply(&mut commit);
+    let warnings = options.validate(&commit_message, &commit)?;
+    Ok(DiagnosedCommit { commit, warnings })
+}
+
+/// Returns `true` if the message is empty or only contains whitespace, such messages
+/// are rejected with [`ParseErrorKind::EmptyCommitMessage`]
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::is_effectively_empty;
+///
+/// assert!(is_effectively_empty(""));
+/// assert!(is_effectively_empty(" \n\t\r\n"));
+/// assert!(!is_effectively_empty("feat: add a feature"));
+/// ```
+pub fn is_effectively_empty(commit_message: &str) -> bool {
+    commit_message.trim().is_empty()
+}
+
 /// Parse a commit summary of the following form : `<type>[optional scope]: <description>`
 /// Returns a [`ConventionalCommit`] struct with a `None` body and empty footers.
 ///
@@ -97,6 +305,8 @@ pub fn parse(commit_message: &str) -> Result<ConventionalCommit, ParseError> {
 /// # use conventional_commit_parser::error::ParseError;
 /// # fn main() -> Result<(), ParseError> {
 ///
+/// use std::collections::HashMap;
+///
 /// use conventional_commit_parser::parse_summary;
 /// use conventional_commit_parser::commit::*;
 ///
@@ -110,22 +320,149 @@ pub fn parse(commit_message: &str) -> Result<ConventionalCommit, ParseError> {
 ///     summary: "implement parse_summary".to_string(),
 ///     body: None,
 ///     footers: vec![],
-///     is_breaking_change: false
+///     is_breaking_change: false,
+///     breaking_change_mark: false,
+///     cherry_picked_from: None,
+///     pre_header_tag: None,
+///     annotations: HashMap::new(),
+///     breaking_change_tokens: vec![],
 /// });
 /// # Ok(())
 /// # }
 pub fn parse_summary(summary: &str) -> Result<ConventionalCommit, ParseError> {
+    if is_effectively_empty(summary) {
+        return Err(ParseError::custom(
+            ParseErrorKind::EmptyCommitMessage,
+            summary,
+            0,
+        ));
+    }
+
+    if let Some(commit) = fast_path::parse_one_line(summary) {
+        return Ok(commit);
+    }
+
     let pair = ConventionalCommitParser::parse(Rule::summary, summary)
         .map_err(ParseError::from)?
         .next()
         .unwrap();
 
+    // The summary rule stops at the first line break, only a trailing one is accepted
+    let end = pair.as_span().end();
+    if !summary[end..].trim().is_empty() {
+        return Err(ParseError::custom(
+            ParseErrorKind::UnexpectedNewLineInSummary,
+            summary,
+            end,
+        ));
+    }
+
     let mut commit = ConventionalCommit::default();
     commit.set_summary(pair);
 
     Ok(commit)
 }
 
+/// Parse a `git log --oneline` line of the following form : `<sha> <type>[optional scope]: <description>`
+/// Returns the abbreviated commit hash along with the parsed header, ref names printed
+/// by `--decorate` between the hash and the header are skipped.
+/// Header error positions are relative to the header, not to the whole line.
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::parse_oneline;
+/// use conventional_commit_parser::commit::CommitType;
+///
+/// let (sha, commit) = parse_oneline("abc1234 (HEAD -> main) feat(x): summary").unwrap();
+///
+/// assert_eq!(sha, "abc1234");
+/// assert_eq!(commit.commit_type, CommitType::Feature);
+/// assert_eq!(commit.scope, Some("x".to_string()));
+/// assert_eq!(commit.summary, "summary");
+/// ```
+pub fn parse_oneline(line: &str) -> Result<(&str, ConventionalCommit), ParseError> {
+    let line = line.trim_end_matches(['\r', '\n']);
+    let (sha, header) = line
+        .split_once(' ')
+        .filter(|(sha, _)| value::is_valid_sha(sha))
+        .ok_or_else(|| ParseError::custom(ParseErrorKind::MissingCommitSha, line, 0))?;
+
+    // `git log --oneline --decorate` prints the ref names between the hash and the header
+    let header = match header
+        .strip_prefix('(')
+        .and_then(|refs| refs.split_once(") "))
+    {
+        Some((_, header)) => header,
+        None => header,
+    };
+
+    parse_summary(header).map(|commit| (sha, commit))
+}
+
+/// Parse a message generated by `git revert`, a `Revert "<header>"` header followed by one
+/// or more `This reverts commit <sha>.` lines. Each hash must be valid according to
+/// [`value::is_valid_sha`].
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::parse_revert;
+/// use conventional_commit_parser::commit::CommitType;
+///
+/// let message = "Revert \"feat(parser): add reverts\"\n\n\
+///     This reverts commit 4dd3c95a1b.\n\
+///     This reverts commit bdca127c2e.";
+///
+/// let revert = parse_revert(message).unwrap();
+///
+/// assert_eq!(revert.reverted_header, "feat(parser): add reverts");
+/// assert_eq!(revert.reverted_shas, vec!["4dd3c95a1b", "bdca127c2e"]);
+/// assert_eq!(revert.reverted_commit().unwrap().commit_type, CommitType::Feature);
+/// ```
+pub fn parse_revert(commit_message: &str) -> Result<RevertCommit<'_>, ParseError> {
+    let header = commit_message.lines().next().unwrap_or_default();
+    let reverted_header = header
+        .trim_end()
+        .strip_prefix("Revert \"")
+        .and_then(|header| header.strip_suffix('"'))
+        .filter(|header| !header.is_empty())
+        .ok_or_else(|| {
+            ParseError::custom(ParseErrorKind::MalformedRevertHeader, commit_message, 0)
+        })?;
+
+    let mut reverted_shas = vec![];
+    let mut offset = 0;
+    for line in commit_message.split_inclusive('\n') {
+        let trimmed = line.trim();
+        if let Some(sha) = trimmed.strip_prefix("This reverts commit ") {
+            // Merge reverts read `This reverts commit <sha>, reversing`
+            let sha = sha
+                .split_whitespace()
+                .next()
+                .unwrap_or_default()
+                .trim_end_matches(['.', ',']);
+            if !value::is_valid_sha(sha) {
+                let kind = ParseErrorKind::InvalidRevertedSha {
+                    sha: sha.to_string(),
+                };
+                return Err(ParseError::custom(kind, commit_message, offset));
+            }
+            reverted_shas.push(sha);
+        }
+
+        offset += line.len();
+    }
+
+    if reverted_shas.is_empty() {
+        let kind = ParseErrorKind::MissingRevertedSha;
+        return Err(ParseError::custom(kind, commit_message, header.len()));
+    }
+
+    Ok(RevertCommit {
+        reverted_header,
+        reverted_shas,
+    })
+}
+
 /// Parse a commit body only returning an `Option<String>` on a non empty trimmed value
 ///
 /// # Example :
@@ -200,3 +537,159 @@ pub fn parse_footers(footers: &str) -> Result<Vec<Footer>, ParseError> {
 
     Ok(footers)
 }
+
+/// Decode a raw commit message according to [`ParserOptions::encoding`] and parse it
+/// into an owned [`commit::ConventionalCommit`].
+///
+/// The returned [`DecodedCommit`] tells whether the message bytes had to be re-encoded
+/// (transcoded or lossily replaced) to produce valid UTF-8.
+///
+/// # Example :
+/// ```
+/// # use conventional_commit_parser::error::ParseError;
+/// # fn main() -> Result<(), ParseError> {
+///
+/// use conventional_commit_parser::parse_bytes;
+/// use conventional_commit_parser::encoding::MessageEncoding;
+/// use conventional_commit_parser::options::ParserOptions;
+///
+/// // "feat: café" with a Latin-1 encoded 'é'
+/// let message = b"feat: caf\xe9";
+///
+/// let options = ParserOptions {
+///     encoding: MessageEncoding::Utf8Lossy,
+///     ..Default::default()
+/// };
+///
+/// let decoded = parse_bytes(message, &options)?;
+///
+/// assert_eq!(decoded.commit.summary, "caf\u{FFFD}");
+/// assert!(decoded.reencoded);
+/// # Ok(())
+/// # }
+/// ```
+pub fn parse_bytes(
+    commit_message: &[u8],
+    options: &ParserOptions,
+) -> Result<DecodedCommit, ParseError> {
+    // Check the raw size first, decoding a pathological input is already costly
+    options.check_message_len("", commit_message.len())?;
+    let decoded = encoding::decode_message(commit_message, &options.encoding)?;
+    let commit = parse_with_options(&decoded.message, options)?;
+
+    Ok(DecodedCommit {
+        commit,
+        reencoded: decoded.reencoded,
+    })
+}
+
+/// Parse a raw git commit object, as printed by `git cat-file commit <sha>`. The object
+/// headers are skipped, including multi-line `gpgsig` and `mergetag` headers, and so is an
+/// armored signature block following the message. The message is decoded and parsed
+/// according to [`ParserOptions`]. Input that does not start with a `tree` header is
+/// parsed as a plain commit message.
+///
+/// With the `encoding_rs` feature, a message declaring its encoding with an `encoding` header
+/// (e.g. `ISO-8859-1`) is decoded with it instead of [`ParserOptions::encoding`].
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::parse_git_raw;
+/// use conventional_commit_parser::options::ParserOptions;
+///
+/// let object = b"tree 9bedf67800b2923982bdf60c89c57ce6b1c3ac1b\n\
+/// parent 4dd3c95a1b2c3d4e5f60718293a4b5c6d7e8f901\n\
+/// author Jane Doe <jane@example.org> 1658150000 +0200\n\
+/// committer Jane Doe <jane@example.org> 1658150000 +0200\n\
+/// gpgsig -----BEGIN PGP SIGNATURE-----\n \n iQEzBAABCAAdFiEE\n -----END PGP SIGNATURE-----\n\
+/// \n\
+/// feat(parser): parse raw commit objects\n";
+///
+/// let raw = parse_git_raw(object, &ParserOptions::default()).unwrap();
+///
+/// assert!(raw.is_signed);
+/// assert_eq!(raw.parents, vec!["4dd3c95a1b2c3d4e5f60718293a4b5c6d7e8f901"]);
+/// assert_eq!(raw.commit.summary, "parse raw commit objects");
+/// ```
+pub fn parse_git_raw(object: &[u8], options: &ParserOptions) -> Result<RawCommit, ParseError> {
+    let (headers, message) = if object.starts_with(b"tree ") {
+        match object.windows(2).position(|window| window == b"\n\n") {
+            Some(end) => (&object[..end], &object[end + 2..]),
+            None => (object, &b""[..]),
+        }
+    } else {
+        (&b""[..], object)
+    };
+
+    let mut raw = RawCommit {
+        commit: ConventionalCommit::default(),
+        reencoded: false,
+        is_signed: false,
+        parents: vec![],
+        author: None,
+        committer: None,
+        encoding: None,
+    };
+
+    let headers = String::from_utf8_lossy(headers);
+    // Continuation lines of multi-line headers start with a space and are skipped
+    for header in headers.lines().filter(|line| !line.starts_with(' ')) {
+        let (key, value) = header.split_once(' ').unwrap_or((header, ""));
+        match key {
+            "parent" => raw.parents.push(value.to_string()),
+            "author" => raw.author = Some(value.to_string()),
+            "committer" => raw.committer = Some(value.to_string()),
+            "encoding" => raw.encoding = Some(value.to_string()),
+            "gpgsig" | "gpgsig-sha256" => raw.is_signed = true,
+            _ => (),
+        }
+    }
+
+    options.check_message_len("", message.len())?;
+    let decoded = encoding::decode_message(message, &raw_message_encoding(&raw, options))?;
+    let message = match strip_signature_block(&decoded.message) {
+        Some(message) => {
+            raw.is_signed = true;
+            message
+        }
+        None => &decoded.message,
+    };
+
+    // Git terminates messages with a new line the grammar does not expect after a header
+    raw.commit = parse_with_options(message.trim_end(), options)?;
+    raw.reencoded = decoded.reencoded;
+    Ok(raw)
+}
+
+/// The encoding declared by the `encoding` header of a raw commit, falling back to
+/// [`ParserOptions::encoding`] for unknown labels
+#[cfg(feature = "encoding_rs")]
+fn raw_message_encoding(raw: &RawCommit, options: &ParserOptions) -> encoding::MessageEncoding {
+    raw.encoding
+        .as_deref()
+        .and_then(|label| encoding_rs::Encoding::for_label(label.trim().as_bytes()))
+        .filter(|encoding| *encoding != encoding_rs::UTF_8)
+        .map(encoding::MessageEncoding::Explicit)
+        .unwrap_or_else(|| options.encoding.clone())
+}
+
+#[cfg(not(feature = "encoding_rs"))]
+fn raw_message_encoding(_raw: &RawCommit, options: &ParserOptions) -> encoding::MessageEncoding {
+    options.encoding.clone()
+}
+
+/// Remove a trailing armored PGP or SSH signature block from a message
+fn strip_signature_block(message: &str) -> Option<&str> {
+    [
+        "-----BEGIN PGP SIGNATURE-----",
+        "-----BEGIN SSH SIGNATURE-----",
+    ]
+    .iter()
+    .find_map(|begin| {
+        let start = message.rfind(begin)?;
+        let at_line_start = start == 0 || message[..start].ends_with('\n');
+        let end_marker = begin.replace("BEGIN", "END");
+        let ends_block = message[start..].trim_end().ends_with(end_marker.as_str());
+        (at_line_start && ends_block).then(|| &message[..start])
+    })
+}
@@ -0,0 +1,94 @@
+//! JUnit XML output of a [`LintReport`], so CI systems such as GitLab or Jenkins display
+//! commit message checks as test results.
+
+use std::fmt::Write;
+
+use crate::changelog::html::escape;
+use crate::lint::LintReport;
+
+/// JUnit output options
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JunitOptions {
+    /// Name of the test suite, defaults to `conventional commits`
+    pub suite_name: String,
+}
+
+impl Default for JunitOptions {
+    fn default() -> Self {
+        JunitOptions {
+            suite_name: "conventional commits".to_string(),
+        }
+    }
+}
+
+impl LintReport {
+    /// Render a JUnit XML report with one test case per linted commit, in input order.
+    /// Each violation is reported as a `failure` of its commit test case.
+    ///
+    /// # Example :
+    /// ```
+    /// use conventional_commit_parser::lint::{lint_all, LintConfig, LintRule};
+    /// use conventional_commit_parser::lint::junit::JunitOptions;
+    /// use conventional_commit_parser::parse;
+    ///
+    /// let config = LintConfig {
+    ///     rules: vec![LintRule::ScopeRequired],
+    /// };
+    ///
+    /// let commits = vec![parse("feat(lint): add junit").unwrap(), parse("fix: typo").unwrap()];
+    /// let junit = lint_all(commits, &config).to_junit(&JunitOptions::default());
+    ///
+    /// assert!(junit.contains(r#"<testsuite name="conventional commits" tests="2" failures="1">"#));
+    /// assert!(junit.contains(r#"<failure type="scope-empty" message="Commit scope is required"/>"#));
+    /// ```
+    pub fn to_junit(&self, options: &JunitOptions) -> String {
+        let tests = self.passed + self.failed;
+        let suite_name = escape(&options.suite_name);
+        let mut xml = String::new();
+
+        writeln!(xml, r#"<?xml version="1.0" encoding="UTF-8"?>"#).unwrap();
+        writeln!(
+            xml,
+            r#"<testsuites name="{}" tests="{}" failures="{}">"#,
+            suite_name, tests, self.failed
+        )
+        .unwrap();
+        writeln!(
+            xml,
+            r#"  <testsuite name="{}" tests="{}" failures="{}">"#,
+            suite_name, tests, self.failed
+        )
+        .unwrap();
+
+        for commit in &self.commits {
+            write!(
+                xml,
+                r#"    <testcase classname="commit-{}" name="{}""#,
+                commit.index,
+                escape(&commit.header)
+            )
+            .unwrap();
+
+            if commit.is_success() {
+                writeln!(xml, "/>").unwrap();
+                continue;
+            }
+
+            writeln!(xml, ">").unwrap();
+            for violation in &commit.violations {
+                writeln!(
+                    xml,
+                    r#"      <failure type="{}" message="{}"/>"#,
+                    escape(violation.rule.id()),
+                    escape(&violation.message)
+                )
+                .unwrap();
+            }
+            writeln!(xml, "    </testcase>").unwrap();
+        }
+
+        writeln!(xml, "  </testsuite>").unwrap();
+        writeln!(xml, "</testsuites>").unwrap();
+        xml
+    }
+}
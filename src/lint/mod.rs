@@ -0,0 +1,855 @@
+//! Lint rules checked against parsed commits, on top of the specification enforced by the parser.
+//!
+//! Rules are configured with a [`LintConfig`], see [`crate::options::Preset`] for
+//! common rule sets.
+
+use std::borrow::Borrow;
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::commit::{CommitType, ConventionalCommit, Footer, Separator};
+use crate::lint::plugin::PluginRule;
+use crate::token::footer_token_len;
+use crate::value::{is_full_sha, is_valid_sha, Identity, Url};
+
+pub mod junit;
+pub mod plugin;
+#[cfg(feature = "sarif")]
+pub mod sarif;
+
+/// A lint rule
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum LintRule {
+    /// The commit type must be one of the given types
+    TypeEnum(Vec<CommitType>),
+    /// A scope must be provided
+    ScopeRequired,
+    /// The summary must not start with an upper case letter
+    SummaryLowerCase,
+    /// The summary must not end with a period
+    SummaryNoTrailingPeriod,
+    /// The header must not be longer than the given number of characters
+    HeaderMaxLength(usize),
+    /// Body lines must not be longer than the given number of characters,
+    /// see [`normalize`] to wrap them
+    BodyMaxLineLength(usize),
+    /// Body lines must not be longer than the given number of characters, unless they
+    /// contain a URL or belong to a verbatim block such as code, which cannot be wrapped,
+    /// see [`normalize`]
+    BodyLineLength(usize),
+    /// Footer value lines must not be longer than the given number of characters,
+    /// see [`normalize`] to wrap them
+    FooterMaxLineLength(usize),
+    /// Footer tokens must not be longer than the given number of characters
+    FooterTokenMaxLength(usize),
+    /// The value of the given footers, e.g. `Fixes` or `Reverts`, must start with a full
+    /// commit hash rather than an abbreviated one, footer tokens are matched case-insensitively
+    FullShaTrailers(Vec<String>),
+    /// Footers must follow their declared schema, each offending footer being reported
+    /// on its own
+    FooterSchema(Vec<FooterSchema>),
+    /// Footers with the given tokens, e.g. an internal ticket system in a public repository,
+    /// are forbidden. Tokens are matched case-insensitively, see [`normalize`] to drop them.
+    ForbiddenFooters(Vec<String>),
+    /// A custom rule, see [`plugin`]
+    Plugin(PluginRule),
+}
+
+/// The declaration of a footer, see [`LintRule::FooterSchema`]
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::lint::{lint, FooterSchema, FooterValue, LintConfig, LintRule};
+/// use conventional_commit_parser::parse;
+///
+/// let config = LintConfig {
+///     rules: vec![LintRule::FooterSchema(vec![
+///         FooterSchema {
+///             token: "Refs".to_string(),
+///             required: true,
+///             value: FooterValue::IssueReference,
+///         },
+///         FooterSchema {
+///             token: "Reviewed-by".to_string(),
+///             required: false,
+///             value: FooterValue::Identity,
+///         },
+///     ])],
+/// };
+///
+/// let commit = parse("fix: typo\n\nRefs #12\nReviewed-by: Jane").unwrap();
+/// let violations = lint(&commit, &config);
+///
+/// assert_eq!(violations.len(), 1);
+/// assert_eq!(
+///     violations[0].message,
+///     "Footer `Reviewed-by` value `Jane` is not a `Name <email>` identity"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct FooterSchema {
+    /// The footer token, matched case-insensitively
+    pub token: String,
+    /// Whether every commit must have the footer
+    pub required: bool,
+    /// The expected value of each footer with this token
+    pub value: FooterValue,
+}
+
+/// The expected value of a footer, values of `#` separated footers start with the `#`
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum FooterValue {
+    /// Any value
+    Any,
+    /// Comma separated issue references such as `#12, #13`
+    IssueReference,
+    /// A `Name <email>` identity, see [`Identity`]
+    Identity,
+    /// A full or abbreviated commit hash
+    Sha,
+    /// A URL, see [`Url`]
+    Url,
+    /// A value entirely matching the regular expression, requires the `regex` feature
+    #[cfg(feature = "regex")]
+    Regex(FooterPattern),
+}
+
+/// A regular expression compiled once, footer values must match it entirely.
+/// Requires the `regex` feature.
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::lint::FooterPattern;
+///
+/// assert!(FooterPattern::new(r"#\d+").is_ok());
+/// assert!(FooterPattern::new(r"#\d+(").is_err());
+/// ```
+#[cfg(feature = "regex")]
+#[derive(Clone)]
+pub struct FooterPattern {
+    pattern: String,
+    regex: regex::Regex,
+}
+
+#[cfg(feature = "regex")]
+impl FooterPattern {
+    /// Compile a pattern, returning an error if it is not a valid regular expression
+    pub fn new(pattern: &str) -> Result<Self, regex::Error> {
+        let regex = regex::Regex::new(&format!("^(?:{})$", pattern))?;
+        Ok(FooterPattern {
+            pattern: pattern.to_string(),
+            regex,
+        })
+    }
+
+    /// The pattern as written, without the anchors added to match entire values
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+
+    fn is_match(&self, value: &str) -> bool {
+        self.regex.is_match(value)
+    }
+}
+
+#[cfg(feature = "regex")]
+impl fmt::Debug for FooterPattern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("FooterPattern").field(&self.pattern).finish()
+    }
+}
+
+#[cfg(feature = "regex")]
+impl PartialEq for FooterPattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.pattern == other.pattern
+    }
+}
+
+#[cfg(feature = "regex")]
+impl Eq for FooterPattern {}
+
+/// Patterns are serialized as written
+#[cfg(all(feature = "regex", feature = "serde"))]
+impl serde::Serialize for FooterPattern {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.pattern)
+    }
+}
+
+impl FooterValue {
+    fn accepts(&self, value: &str) -> bool {
+        match self {
+            FooterValue::Any => true,
+            FooterValue::IssueReference => value.split(',').all(|reference| {
+                reference.trim().strip_prefix('#').is_some_and(|number| {
+                    !number.is_empty() && number.chars().all(|c| c.is_ascii_digit())
+                })
+            }),
+            FooterValue::Identity => value.parse::<Identity>().is_ok(),
+            FooterValue::Sha => is_valid_sha(value),
+            FooterValue::Url => value.parse::<Url>().is_ok(),
+            #[cfg(feature = "regex")]
+            FooterValue::Regex(pattern) => pattern.is_match(value),
+        }
+    }
+
+    fn description(&self) -> String {
+        match self {
+            FooterValue::Any => "a value".to_string(),
+            FooterValue::IssueReference => "an issue reference".to_string(),
+            FooterValue::Identity => "a `Name <email>` identity".to_string(),
+            FooterValue::Sha => "a commit hash".to_string(),
+            FooterValue::Url => "a URL".to_string(),
+            #[cfg(feature = "regex")]
+            FooterValue::Regex(pattern) => format!("a value matching `{}`", pattern.as_str()),
+        }
+    }
+}
+
+/// A set of lint rules
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LintConfig {
+    pub rules: Vec<LintRule>,
+}
+
+/// A lint rule violation
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct LintViolation {
+    /// The violated rule
+    pub rule: LintRule,
+    /// Human readable description of the violation
+    pub message: String,
+}
+
+impl fmt::Display for LintViolation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+/// Check a commit against every rule of a [`LintConfig`], returning the violations in rule order
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::lint::{lint, LintConfig, LintRule};
+/// use conventional_commit_parser::parse;
+///
+/// let config = LintConfig {
+///     rules: vec![LintRule::ScopeRequired, LintRule::SummaryNoTrailingPeriod],
+/// };
+///
+/// let violations = lint(&parse("feat: add lint rules.").unwrap(), &config);
+///
+/// assert_eq!(violations.len(), 2);
+/// assert_eq!(violations[0].rule, LintRule::ScopeRequired);
+/// ```
+pub fn lint(commit: &ConventionalCommit, config: &LintConfig) -> Vec<LintViolation> {
+    config
+        .rules
+        .iter()
+        .flat_map(|rule| {
+            rule.violations(commit)
+                .into_iter()
+                .map(move |message| LintViolation {
+                    rule: rule.clone(),
+                    message,
+                })
+        })
+        .collect()
+}
+
+/// Apply the automatic fixes of the configured rules, returning the fixed commit.
+///
+/// [`LintRule::FooterMaxLineLength`] wraps long footer value lines on whitespace,
+/// continuation lines being indented with a single space so they are not mistaken for a new
+/// footer. [`LintRule::BodyMaxLineLength`] and [`LintRule::BodyLineLength`] wrap long body
+/// lines the same way, without indentation. Words longer than the limit, such as URLs, are
+/// never split. [`LintRule::ForbiddenFooters`] drops the forbidden footers.
+///
+/// Verbatim blocks are left untouched: footer values starting on the line following their
+/// token, such as dependabot's YAML `updated-dependencies`, and in the body fenced code
+/// blocks, indented code and sections following a `---` line up to a `...` line.
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::lint::{lint, normalize, LintConfig, LintRule};
+/// use conventional_commit_parser::parse;
+///
+/// let config = LintConfig {
+///     rules: vec![LintRule::FooterMaxLineLength(20)],
+/// };
+///
+/// let commit = parse("fix: typo\n\nReviewed-by: Jane Doe, John Doe and Z").unwrap();
+/// let normalized = normalize(&commit, &config);
+///
+/// assert_eq!(normalized.footers[0].content, "Jane Doe, John Doe\n and Z");
+/// assert!(lint(&normalized, &config).is_empty());
+/// assert_eq!(parse(&normalized.to_string()).unwrap(), normalized);
+/// ```
+pub fn normalize(commit: &ConventionalCommit, config: &LintConfig) -> ConventionalCommit {
+    let mut commit = commit.clone();
+
+    for rule in &config.rules {
+        match rule {
+            LintRule::FooterMaxLineLength(limit) => {
+                for footer in &mut commit.footers {
+                    if footer.token_separator != Separator::ColonWithNewLine {
+                        footer.content = wrap_lines(&footer.content, *limit);
+                    }
+                }
+            }
+            LintRule::BodyMaxLineLength(limit) | LintRule::BodyLineLength(limit) => {
+                let exempt_urls = matches!(rule, LintRule::BodyLineLength(_));
+                if let Some(body) = &commit.body {
+                    commit.body = Some(wrap_body(body, *limit, exempt_urls));
+                }
+            }
+            LintRule::ForbiddenFooters(tokens) => {
+                commit
+                    .footers
+                    .retain(|footer| !is_forbidden_footer(footer, tokens));
+                commit.is_breaking_change = commit.breaking_change_mark
+                    || commit
+                        .footers
+                        .iter()
+                        .any(|footer| commit.is_breaking_change_footer(footer));
+            }
+            _ => (),
+        }
+    }
+
+    commit
+}
+
+/// Wrap the lines longer than `limit` characters, indenting continuation lines with a space
+fn wrap_lines(text: &str, limit: usize) -> String {
+    let mut lines: Vec<String> = vec![];
+    for line in text.lines() {
+        if line.chars().count() <= limit {
+            lines.push(line.to_string());
+            continue;
+        }
+
+        // Keep the indentation of lines that are already continuation lines
+        let indent = if line.starts_with(char::is_whitespace) {
+            " "
+        } else {
+            ""
+        };
+        lines.extend(wrap_words(line, limit, indent, " "));
+    }
+
+    lines.join("\n")
+}
+
+/// Wrap the body lines longer than `limit` characters, leaving verbatim blocks untouched.
+/// Lines holding a URL are left untouched as well if `exempt_urls` is set, matching
+/// [`LintRule::BodyLineLength`].
+fn wrap_body(body: &str, limit: usize, exempt_urls: bool) -> String {
+    let mut lines: Vec<String> = vec![];
+    for (line, verbatim) in verbatim_lines(body) {
+        if verbatim || line.chars().count() <= limit || (exempt_urls && has_url(line)) {
+            lines.push(line.to_string());
+        } else {
+            lines.extend(wrap_words(line, limit, "", ""));
+        }
+    }
+
+    lines.join("\n")
+}
+
+/// Split a body into lines, flagging the lines of verbatim blocks: fenced code blocks,
+/// indented code, and sections following a `---` line up to a `...` line. Fences and `---`
+/// lines without a closing line are not verbatim, so a stray marker does not exempt the
+/// rest of the body.
+fn verbatim_lines(body: &str) -> Vec<(&str, bool)> {
+    let lines: Vec<&str> = body.lines().collect();
+    let mut verbatim = vec![false; lines.len()];
+
+    let mut index = 0;
+    while index < lines.len() {
+        let trimmed = lines[index].trim();
+        let rest = &lines[index + 1..];
+        let closing = if let Some(marker) = ["```", "~~~"].iter().find(|m| trimmed.starts_with(*m))
+        {
+            rest.iter().position(|line| line.trim().starts_with(marker))
+        } else if trimmed == "---" {
+            rest.iter().position(|line| line.trim() == "...")
+        } else {
+            None
+        };
+
+        match closing {
+            Some(offset) => {
+                let end = index + 1 + offset;
+                verbatim[index..=end]
+                    .iter_mut()
+                    .for_each(|line| *line = true);
+                index = end + 1;
+            }
+            None => {
+                verbatim[index] =
+                    lines[index].starts_with("    ") || lines[index].starts_with('\t');
+                index += 1;
+            }
+        }
+    }
+
+    lines.into_iter().zip(verbatim).collect()
+}
+
+/// Split a line on whitespace into lines of at most `limit` characters, unless a single word
+/// is longer. The first line is prefixed with `indent`, the following ones with `continuation`.
+fn wrap_words(line: &str, limit: usize, indent: &str, continuation: &str) -> Vec<String> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    let mut lines = vec![];
+    let mut current = String::new();
+    for (index, word) in words.iter().enumerate() {
+        if current.is_empty() {
+            let prefix = if lines.is_empty() {
+                indent
+            } else {
+                continuation
+            };
+            current = format!("{}{}", prefix, word);
+        } else if current.chars().count() + 1 + word.chars().count() <= limit
+            || is_list_marker(current.trim())
+            // Never start an unindented line with something looking like a footer
+            || (continuation.is_empty() && starts_like_footer(word, words.get(index + 1).copied()))
+        {
+            current.push(' ');
+            current.push_str(word);
+        } else {
+            lines.push(std::mem::take(&mut current));
+            current = format!("{}{}", continuation, word);
+        }
+    }
+    lines.push(current);
+    lines
+}
+
+/// Whether a word is a markdown list item marker, `-`, `*`, `+` or `1.`
+fn is_list_marker(word: &str) -> bool {
+    match word.strip_suffix(['.', ')']) {
+        Some(number) => !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()),
+        None => ["-", "*", "+"].contains(&word),
+    }
+}
+
+/// Whether a line starting with `word`, followed by `next`, would be read as a footer
+fn starts_like_footer(word: &str, next: Option<&str>) -> bool {
+    let next = next.unwrap_or_default();
+    if word == "BREAKING" && next.starts_with("CHANGE:") {
+        return true;
+    }
+
+    let token_len = footer_token_len(word);
+    token_len > 0
+        && (word[token_len..] == *":" || (token_len == word.len() && next.starts_with('#')))
+}
+
+/// Violations of a batch of commits, see [`lint_all`]. With the `serde` feature the report
+/// can be serialized, e.g. to produce CI annotations.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct LintReport {
+    /// Every linted commit, in input order, see [`CommitLintReport::is_success`]
+    pub commits: Vec<CommitLintReport>,
+    /// Violation count of each configured rule, in rule order
+    pub rules: Vec<RuleStats>,
+    /// Number of commits without violations
+    pub passed: usize,
+    /// Number of commits with at least one violation
+    pub failed: usize,
+}
+
+/// Violations of a single commit of a [`LintReport`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct CommitLintReport {
+    /// Position of the commit in the linted batch
+    pub index: usize,
+    /// The commit header, to locate the commit in annotations
+    pub header: String,
+    pub violations: Vec<LintViolation>,
+}
+
+impl CommitLintReport {
+    /// Returns `true` if the commit violates no rule
+    pub fn is_success(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+/// Number of commits violating a rule
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct RuleStats {
+    pub rule: LintRule,
+    pub violations: usize,
+}
+
+impl LintReport {
+    /// Returns `true` if no commit violates a rule
+    pub fn is_success(&self) -> bool {
+        self.failed == 0
+    }
+
+    /// Returns at most `count` commits with the most violations, the earliest commit first
+    /// among commits with as many violations
+    pub fn worst_offenders(&self, count: usize) -> Vec<&CommitLintReport> {
+        let mut offenders: Vec<&CommitLintReport> = self
+            .commits
+            .iter()
+            .filter(|commit| !commit.is_success())
+            .collect();
+        offenders.sort_by_key(|commit| std::cmp::Reverse(commit.violations.len()));
+        offenders.truncate(count);
+        offenders
+    }
+}
+
+/// Check a batch of commits against a [`LintConfig`], aggregating the violations
+/// per commit and per rule
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::lint::{lint_all, LintConfig, LintRule};
+/// use conventional_commit_parser::parse;
+///
+/// let config = LintConfig {
+///     rules: vec![LintRule::ScopeRequired, LintRule::SummaryNoTrailingPeriod],
+/// };
+///
+/// let commits = vec![
+///     parse("feat(lint): add lint_all").unwrap(),
+///     parse("fix: typo.").unwrap(),
+///     parse("docs: document lint_all").unwrap(),
+/// ];
+///
+/// let report = lint_all(&commits, &config);
+///
+/// assert_eq!(report.passed, 1);
+/// assert_eq!(report.failed, 2);
+/// assert_eq!(report.rules[0].violations, 2);
+/// assert_eq!(report.worst_offenders(1)[0].header, "fix: typo.");
+/// ```
+pub fn lint_all<I>(commits: I, config: &LintConfig) -> LintReport
+where
+    I: IntoIterator,
+    I::Item: Borrow<ConventionalCommit>,
+{
+    let mut report = LintReport {
+        rules: config
+            .rules
+            .iter()
+            .map(|rule| RuleStats {
+                rule: rule.clone(),
+                violations: 0,
+            })
+            .collect(),
+        ..Default::default()
+    };
+
+    for (index, commit) in commits.into_iter().enumerate() {
+        let commit = commit.borrow();
+        let violations = lint(commit, config);
+        let header = commit
+            .to_string()
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .to_string();
+
+        if violations.is_empty() {
+            report.passed += 1;
+        } else {
+            report.failed += 1;
+        }
+
+        // A rule reporting several violations, such as one per footer, counts the commit once
+        for stats in &mut report.rules {
+            if violations
+                .iter()
+                .any(|violation| violation.rule == stats.rule)
+            {
+                stats.violations += 1;
+            }
+        }
+
+        report.commits.push(CommitLintReport {
+            index,
+            header,
+            violations,
+        });
+    }
+
+    report
+}
+
+impl LintRule {
+    /// A stable kebab-case identifier of the rule, named after the matching commitlint rule
+    ///
+    /// # Example :
+    /// ```
+    /// use conventional_commit_parser::lint::LintRule;
+    ///
+    /// assert_eq!(LintRule::HeaderMaxLength(72).id(), "header-max-length");
+    /// ```
+    pub fn id(&self) -> &str {
+        match self {
+            LintRule::TypeEnum(_) => "type-enum",
+            LintRule::ScopeRequired => "scope-empty",
+            LintRule::SummaryLowerCase => "subject-case",
+            LintRule::SummaryNoTrailingPeriod => "subject-full-stop",
+            LintRule::HeaderMaxLength(_) => "header-max-length",
+            LintRule::BodyMaxLineLength(_) => "body-max-line-length",
+            LintRule::BodyLineLength(_) => "body-line-length",
+            LintRule::FooterMaxLineLength(_) => "footer-max-line-length",
+            LintRule::FooterTokenMaxLength(_) => "footer-token-max-length",
+            LintRule::FullShaTrailers(_) => "trailer-full-sha",
+            LintRule::FooterSchema(_) => "footer-schema",
+            LintRule::ForbiddenFooters(_) => "footer-forbidden",
+            LintRule::Plugin(plugin) => plugin.id(),
+        }
+    }
+
+    /// A short description of what the rule enforces
+    pub fn description(&self) -> String {
+        match self {
+            LintRule::TypeEnum(types) => format!(
+                "The commit type must be one of {}",
+                types
+                    .iter()
+                    .map(CommitType::as_ref)
+                    .collect::<Vec<&str>>()
+                    .join(", ")
+            ),
+            LintRule::ScopeRequired => "A scope must be provided".to_string(),
+            LintRule::SummaryLowerCase => {
+                "The summary must not start with an upper case letter".to_string()
+            }
+            LintRule::SummaryNoTrailingPeriod => {
+                "The summary must not end with a period".to_string()
+            }
+            LintRule::HeaderMaxLength(limit) => {
+                format!("The header must not be longer than {} characters", limit)
+            }
+            LintRule::BodyMaxLineLength(limit) => {
+                format!("Body lines must not be longer than {} characters", limit)
+            }
+            LintRule::BodyLineLength(limit) => format!(
+                "Body lines must not be longer than {} characters, URLs and code excepted",
+                limit
+            ),
+            LintRule::FooterMaxLineLength(limit) => {
+                format!(
+                    "Footer value lines must not be longer than {} characters",
+                    limit
+                )
+            }
+            LintRule::FooterTokenMaxLength(limit) => {
+                format!("Footer tokens must not be longer than {} characters", limit)
+            }
+            LintRule::FullShaTrailers(tokens) => {
+                format!(
+                    "Footers {} must reference full commit hashes",
+                    tokens.join(", ")
+                )
+            }
+            LintRule::FooterSchema(schemas) => format!(
+                "Footers {} must follow their schema",
+                schemas
+                    .iter()
+                    .map(|schema| schema.token.as_str())
+                    .collect::<Vec<&str>>()
+                    .join(", ")
+            ),
+            LintRule::ForbiddenFooters(tokens) => {
+                format!("Footers {} are forbidden", tokens.join(", "))
+            }
+            LintRule::Plugin(plugin) => plugin.description(),
+        }
+    }
+
+    /// Check a single rule, returning every violation message
+    fn violations(&self, commit: &ConventionalCommit) -> Vec<String> {
+        let schemas = match self {
+            LintRule::FooterSchema(schemas) => schemas,
+            LintRule::Plugin(plugin) => return plugin.check(commit),
+            _ => return self.check(commit).into_iter().collect(),
+        };
+
+        let mut messages = vec![];
+        for schema in schemas {
+            let footers: Vec<&Footer> = commit
+                .footers
+                .iter()
+                .filter(|footer| footer.token.eq_ignore_ascii_case(&schema.token))
+                .collect();
+
+            if schema.required && footers.is_empty() {
+                messages.push(format!("Footer `{}` is required", schema.token));
+            }
+
+            for footer in footers {
+                let value = match footer.token_separator {
+                    Separator::Hash => format!("#{}", footer.content.trim()),
+                    _ => footer.content.trim().to_string(),
+                };
+
+                if !schema.value.accepts(&value) {
+                    messages.push(format!(
+                        "Footer `{}` value `{}` is not {}",
+                        footer.token,
+                        value.lines().next().unwrap_or_default(),
+                        schema.value.description()
+                    ));
+                }
+            }
+        }
+
+        messages
+    }
+
+    /// Check a single rule, returning the violation message if any
+    fn check(&self, commit: &ConventionalCommit) -> Option<String> {
+        match self {
+            LintRule::TypeEnum(types) if !types.contains(&commit.commit_type) => Some(format!(
+                "Commit type `{}` is not one of {}",
+                commit.commit_type,
+                types
+                    .iter()
+                    .map(|commit_type| format!("`{}`", commit_type))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            )),
+            LintRule::ScopeRequired if commit.scope.is_none() => {
+                Some("Commit scope is required".to_string())
+            }
+            LintRule::SummaryLowerCase
+                if commit.summary.starts_with(|c: char| c.is_uppercase()) =>
+            {
+                Some("Commit summary must not start with an upper case letter".to_string())
+            }
+            LintRule::SummaryNoTrailingPeriod if commit.summary.ends_with('.') => {
+                Some("Commit summary must not end with a period".to_string())
+            }
+            LintRule::HeaderMaxLength(limit) => {
+                let message = commit.to_string();
+                let actual = message.lines().next().unwrap_or_default().chars().count();
+                (actual > *limit).then(|| {
+                    format!(
+                        "Commit header is too long ({} characters, maximum is {})",
+                        actual, limit
+                    )
+                })
+            }
+            LintRule::BodyMaxLineLength(limit) => commit
+                .body
+                .as_deref()
+                .into_iter()
+                .flat_map(str::lines)
+                .position(|line| line.chars().count() > *limit)
+                .map(|line| {
+                    format!(
+                        "Commit body line {} is longer than {} characters",
+                        line + 1,
+                        limit
+                    )
+                }),
+            LintRule::BodyLineLength(limit) => commit
+                .body
+                .as_deref()
+                .map(verbatim_lines)
+                .unwrap_or_default()
+                .into_iter()
+                .position(|(line, verbatim)| {
+                    line.chars().count() > *limit && !verbatim && !has_url(line)
+                })
+                .map(|line| {
+                    format!(
+                        "Commit body line {} is longer than {} characters",
+                        line + 1,
+                        limit
+                    )
+                }),
+            LintRule::FooterMaxLineLength(limit) => commit.footers.iter().find_map(|footer| {
+                footer
+                    .content
+                    .lines()
+                    .position(|line| line.chars().count() > *limit)
+                    .map(|line| {
+                        format!(
+                            "Footer `{}` line {} is longer than {} characters",
+                            footer.token,
+                            line + 1,
+                            limit
+                        )
+                    })
+            }),
+            LintRule::FooterTokenMaxLength(limit) => commit
+                .footers
+                .iter()
+                .find(|footer| footer.token.chars().count() > *limit)
+                .map(|footer| {
+                    format!(
+                        "Footer token `{}` is longer than {} characters",
+                        footer.token, limit
+                    )
+                }),
+            LintRule::FullShaTrailers(tokens) => commit
+                .footers
+                .iter()
+                .filter(|footer| tokens.iter().any(|t| footer.token.eq_ignore_ascii_case(t)))
+                .find(|footer| {
+                    let sha = footer.content.split_whitespace().next().unwrap_or_default();
+                    !is_full_sha(sha)
+                })
+                .map(|footer| {
+                    format!(
+                        "Footer `{}` must reference a full commit hash, found `{}`",
+                        footer.token,
+                        footer.content.lines().next().unwrap_or_default()
+                    )
+                }),
+            LintRule::ForbiddenFooters(tokens) => {
+                let mut forbidden: Vec<&str> = vec![];
+                for footer in &commit.footers {
+                    if is_forbidden_footer(footer, tokens) && !forbidden.contains(&&*footer.token) {
+                        forbidden.push(&footer.token);
+                    }
+                }
+
+                (!forbidden.is_empty()).then(|| {
+                    format!(
+                        "Forbidden footer(s) {}",
+                        forbidden
+                            .iter()
+                            .map(|token| format!("`{}`", token))
+                            .collect::<Vec<String>>()
+                            .join(", ")
+                    )
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+fn is_forbidden_footer(footer: &Footer, tokens: &[String]) -> bool {
+    tokens
+        .iter()
+        .any(|token| footer.token.eq_ignore_ascii_case(token))
+}
+
+/// Whether a line holds a URL, which cannot be wrapped
+fn has_url(line: &str) -> bool {
+    line.split_whitespace()
+        .any(|word| word.contains("http://") || word.contains("https://"))
+}
@@ -0,0 +1,266 @@
+//! Custom lint rules, for instance proprietary rules shipped as a separate crate.
+//!
+//! Implement [`LintPlugin`] and add it to a [`LintConfig`](super::LintConfig) with
+//! [`LintRule::Plugin`](super::LintRule::Plugin). With the `plugins` feature, rules compiled
+//! into a shared object are loaded at runtime with [`DynamicPlugin::load`], through a stable
+//! C ABI independent of the Rust compiler and of this crate version.
+//!
+//! # Example :
+//! ```
+//! use conventional_commit_parser::commit::ConventionalCommit;
+//! use conventional_commit_parser::lint::plugin::{LintPlugin, PluginRule};
+//! use conventional_commit_parser::lint::{lint, LintConfig, LintRule};
+//! use conventional_commit_parser::parse;
+//!
+//! struct NoWip;
+//!
+//! impl LintPlugin for NoWip {
+//!     fn id(&self) -> &str {
+//!         "acme/no-wip"
+//!     }
+//!
+//!     fn description(&self) -> String {
+//!         "The summary must not mention WIP".to_string()
+//!     }
+//!
+//!     fn check(&self, commit: &ConventionalCommit) -> Vec<String> {
+//!         if commit.summary.to_lowercase().contains("wip") {
+//!             vec!["Commit summary mentions WIP".to_string()]
+//!         } else {
+//!             vec![]
+//!         }
+//!     }
+//! }
+//!
+//! let config = LintConfig {
+//!     rules: vec![LintRule::Plugin(PluginRule::new(NoWip))],
+//! };
+//!
+//! let violations = lint(&parse("feat: WIP plugins").unwrap(), &config);
+//!
+//! assert_eq!(violations[0].rule.id(), "acme/no-wip");
+//! assert_eq!(violations[0].message, "Commit summary mentions WIP");
+//! ```
+
+use std::fmt;
+use std::sync::Arc;
+
+use crate::commit::ConventionalCommit;
+
+/// A custom lint rule
+pub trait LintPlugin: Send + Sync {
+    /// A stable identifier of the rule, prefixing it with the organization name avoids
+    /// clashes with builtin rules, e.g. `acme/no-wip`
+    fn id(&self) -> &str;
+
+    /// A short description of what the rule enforces
+    fn description(&self) -> String;
+
+    /// Check a commit, returning a message for each violation
+    fn check(&self, commit: &ConventionalCommit) -> Vec<String>;
+}
+
+/// A shared [`LintPlugin`], rules are compared by identifier
+#[derive(Clone)]
+pub struct PluginRule(Arc<dyn LintPlugin>);
+
+impl PluginRule {
+    pub fn new<P: LintPlugin + 'static>(plugin: P) -> Self {
+        PluginRule(Arc::new(plugin))
+    }
+
+    pub fn id(&self) -> &str {
+        self.0.id()
+    }
+
+    pub fn description(&self) -> String {
+        self.0.description()
+    }
+
+    pub fn check(&self, commit: &ConventionalCommit) -> Vec<String> {
+        self.0.check(commit)
+    }
+}
+
+impl fmt::Debug for PluginRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PluginRule").field(&self.id()).finish()
+    }
+}
+
+impl PartialEq for PluginRule {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl Eq for PluginRule {}
+
+/// Plugin rules are serialized as their identifier
+#[cfg(feature = "serde")]
+impl serde::Serialize for PluginRule {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.id())
+    }
+}
+
+#[cfg(feature = "plugins")]
+pub use self::dynamic::*;
+
+#[cfg(feature = "plugins")]
+mod dynamic {
+    use std::ffi::{c_char, c_void, CStr};
+    use std::path::Path;
+
+    use super::LintPlugin;
+    use crate::commit::ConventionalCommit;
+    use crate::error::PluginError;
+
+    /// The C ABI version implemented by this crate, see [`PluginDescriptor::abi_version`]
+    pub const PLUGIN_ABI_VERSION: u32 = 1;
+
+    /// The symbol exported by plugin shared objects, an
+    /// `extern "C" fn() -> *const PluginDescriptor` returning a descriptor valid for the
+    /// lifetime of the shared object
+    pub const PLUGIN_ENTRY_POINT: &str = "conventional_commit_lint_plugin";
+
+    /// Called by a plugin for each violation with the UTF-8 message and its length in bytes,
+    /// the message is copied before the call returns. A null message or a zero length reports
+    /// an empty message.
+    pub type ReportFn = unsafe extern "C" fn(context: *mut c_void, message: *const u8, len: usize);
+
+    /// Checks the UTF-8 commit message of the given length in bytes, rendered like
+    /// [`ConventionalCommit`]'s `Display` implementation, calling `report` with `context`
+    /// for each violation
+    pub type CheckFn = unsafe extern "C" fn(
+        message: *const u8,
+        len: usize,
+        report: ReportFn,
+        context: *mut c_void,
+    );
+
+    /// The C ABI description of a plugin, returned by its [`PLUGIN_ENTRY_POINT`]
+    #[repr(C)]
+    #[derive(Debug, Clone, Copy)]
+    pub struct PluginDescriptor {
+        /// Must be [`PLUGIN_ABI_VERSION`]
+        pub abi_version: u32,
+        /// NUL-terminated UTF-8 rule identifier, see [`LintPlugin::id`]
+        pub id: *const c_char,
+        /// NUL-terminated UTF-8 rule description
+        pub description: *const c_char,
+        pub check: CheckFn,
+    }
+
+    /// A [`LintPlugin`] implemented behind the C ABI, typically loaded from a shared object
+    pub struct DynamicPlugin {
+        id: String,
+        description: String,
+        check: CheckFn,
+        // Keeps the shared object loaded as long as the plugin is used
+        _library: Option<libloading::Library>,
+    }
+
+    impl DynamicPlugin {
+        /// Load the plugin exported by a shared object
+        ///
+        /// # Safety
+        ///
+        /// Loading a shared object runs its initialization code, and its
+        /// [`PLUGIN_ENTRY_POINT`] must follow the documented signature and contract.
+        pub unsafe fn load<P: AsRef<Path>>(path: P) -> Result<Self, PluginError> {
+            let path = path.as_ref();
+            let library = libloading::Library::new(path)
+                .map_err(|err| PluginError::Load(format!("{}: {}", path.display(), err)))?;
+
+            let descriptor = {
+                let entry_point: libloading::Symbol<
+                    '_,
+                    unsafe extern "C" fn() -> *const PluginDescriptor,
+                > = library
+                    .get(PLUGIN_ENTRY_POINT.as_bytes())
+                    .map_err(|_| PluginError::MissingEntryPoint(path.display().to_string()))?;
+
+                entry_point()
+                    .as_ref()
+                    .copied()
+                    .ok_or_else(|| PluginError::InvalidDescriptor("null descriptor".to_string()))?
+            };
+
+            let mut plugin = DynamicPlugin::from_descriptor(&descriptor)?;
+            plugin._library = Some(library);
+            Ok(plugin)
+        }
+
+        /// Wrap a plugin descriptor, e.g. one linked statically
+        ///
+        /// # Safety
+        ///
+        /// The descriptor strings must be valid NUL-terminated strings and its check function
+        /// must follow the [`CheckFn`] contract for as long as the plugin is used.
+        pub unsafe fn from_descriptor(descriptor: &PluginDescriptor) -> Result<Self, PluginError> {
+            if descriptor.abi_version != PLUGIN_ABI_VERSION {
+                return Err(PluginError::IncompatibleAbi {
+                    expected: PLUGIN_ABI_VERSION,
+                    found: descriptor.abi_version,
+                });
+            }
+
+            let string = |value: *const c_char, field: &str| {
+                if value.is_null() {
+                    return Err(PluginError::InvalidDescriptor(format!("null {}", field)));
+                }
+
+                CStr::from_ptr(value)
+                    .to_str()
+                    .map(str::to_string)
+                    .map_err(|_| PluginError::InvalidDescriptor(format!("non UTF-8 {}", field)))
+            };
+
+            Ok(DynamicPlugin {
+                id: string(descriptor.id, "id")?,
+                description: string(descriptor.description, "description")?,
+                check: descriptor.check,
+                _library: None,
+            })
+        }
+    }
+
+    unsafe extern "C" fn collect_violation(context: *mut c_void, message: *const u8, len: usize) {
+        let violations = &mut *(context as *mut Vec<String>);
+        if message.is_null() || len == 0 {
+            violations.push(String::new());
+            return;
+        }
+
+        let message = std::slice::from_raw_parts(message, len);
+        violations.push(String::from_utf8_lossy(message).into_owned());
+    }
+
+    impl LintPlugin for DynamicPlugin {
+        fn id(&self) -> &str {
+            &self.id
+        }
+
+        fn description(&self) -> String {
+            self.description.clone()
+        }
+
+        fn check(&self, commit: &ConventionalCommit) -> Vec<String> {
+            let message = commit.to_string();
+            let mut violations: Vec<String> = vec![];
+
+            // SAFETY: the plugin contract was accepted by `load` or `from_descriptor`
+            unsafe {
+                (self.check)(
+                    message.as_ptr(),
+                    message.len(),
+                    collect_violation,
+                    &mut violations as *mut Vec<String> as *mut c_void,
+                );
+            }
+
+            violations
+        }
+    }
+}
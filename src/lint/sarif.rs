@@ -0,0 +1,132 @@
+//! [SARIF](https://sarifweb.azurewebsites.net/) output of a [`LintReport`], requires the `sarif` feature.
+//!
+//! SARIF logs can be uploaded to GitHub code scanning or any other SARIF consumer
+//! to surface commit message violations.
+
+use serde_json::{json, Value};
+
+use crate::lint::{CommitLintReport, LintReport};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+
+/// SARIF output options
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SarifOptions {
+    /// The file every result is reported against, SARIF consumers such as GitHub code
+    /// scanning require a physical location. Defaults to `.git/COMMIT_EDITMSG`.
+    pub artifact_uri: String,
+    /// Pretty print the SARIF log
+    pub pretty: bool,
+}
+
+impl Default for SarifOptions {
+    fn default() -> Self {
+        SarifOptions {
+            artifact_uri: ".git/COMMIT_EDITMSG".to_string(),
+            pretty: false,
+        }
+    }
+}
+
+impl LintReport {
+    /// Build a SARIF 2.1.0 log with a single run, one rule per configured [`crate::lint::LintRule`]
+    /// and one result per violation
+    ///
+    /// # Example :
+    /// ```
+    /// use conventional_commit_parser::lint::{lint_all, LintConfig, LintRule};
+    /// use conventional_commit_parser::lint::sarif::SarifOptions;
+    /// use conventional_commit_parser::parse;
+    ///
+    /// let config = LintConfig {
+    ///     rules: vec![LintRule::ScopeRequired],
+    /// };
+    ///
+    /// let report = lint_all(vec![parse("fix: typo").unwrap()], &config);
+    /// let sarif = report.to_sarif(&SarifOptions::default());
+    ///
+    /// assert_eq!(sarif["version"], "2.1.0");
+    /// assert_eq!(sarif["runs"][0]["tool"]["driver"]["rules"][0]["id"], "scope-empty");
+    /// assert_eq!(sarif["runs"][0]["results"][0]["ruleId"], "scope-empty");
+    /// ```
+    pub fn to_sarif(&self, options: &SarifOptions) -> Value {
+        let rules: Vec<Value> = self
+            .rules
+            .iter()
+            .map(|stats| {
+                json!({
+                    "id": stats.rule.id(),
+                    "shortDescription": { "text": stats.rule.description() },
+                })
+            })
+            .collect();
+
+        let results: Vec<Value> = self
+            .commits
+            .iter()
+            .flat_map(|commit| self.commit_results(commit, options))
+            .collect();
+
+        json!({
+            "$schema": SARIF_SCHEMA,
+            "version": SARIF_VERSION,
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": env!("CARGO_PKG_NAME"),
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "informationUri": env!("CARGO_PKG_REPOSITORY"),
+                        "rules": rules,
+                    }
+                },
+                "results": results,
+            }]
+        })
+    }
+
+    /// Serialize the SARIF log, see [`LintReport::to_sarif`]
+    pub fn to_sarif_string(&self, options: &SarifOptions) -> String {
+        let value = self.to_sarif(options);
+        if options.pretty {
+            serde_json::to_string_pretty(&value).expect("valid json value")
+        } else {
+            value.to_string()
+        }
+    }
+
+    fn commit_results(&self, commit: &CommitLintReport, options: &SarifOptions) -> Vec<Value> {
+        commit
+            .violations
+            .iter()
+            .map(|violation| {
+                let mut result = json!({
+                    "ruleId": violation.rule.id(),
+                    "level": "error",
+                    "message": { "text": format!("{}: {}", commit.header, violation.message) },
+                    "locations": [{
+                        "physicalLocation": {
+                            "artifactLocation": { "uri": options.artifact_uri },
+                            "region": { "startLine": 1 },
+                        },
+                        "logicalLocations": [{
+                            "name": commit.header,
+                            "kind": "commit",
+                        }],
+                    }],
+                    "properties": { "commitIndex": commit.index },
+                });
+
+                if let Some(index) = self
+                    .rules
+                    .iter()
+                    .position(|stats| stats.rule == violation.rule)
+                {
+                    result["ruleIndex"] = json!(index);
+                }
+
+                result
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,144 @@
+//! Release planning for monorepos where commit scopes name packages, computing the
+//! version bump of each package touched by a commit history.
+//!
+//! # Example :
+//! ```
+//! use conventional_commit_parser::monorepo::{affected_packages, BumpLevel, MonorepoMap, Package};
+//! use conventional_commit_parser::parse;
+//!
+//! let mut map = MonorepoMap::default();
+//! map.scopes.insert("parser".to_string(), Package::new("conventional_commit_parser", "crates/parser"));
+//! map.scopes.insert("cli".to_string(), Package::new("cog", "crates/cli"));
+//!
+//! let commits = vec![
+//!     parse("fix(parser): handle tabs").unwrap(),
+//!     parse("feat(parser): add monorepo maps").unwrap(),
+//!     parse("docs(cli): typo").unwrap(),
+//! ];
+//!
+//! let bumps = affected_packages(&commits, &map);
+//!
+//! assert_eq!(bumps.len(), 1);
+//! assert_eq!(bumps[0].package.name, "conventional_commit_parser");
+//! assert_eq!(bumps[0].level, BumpLevel::Minor);
+//! ```
+
+use std::borrow::Borrow;
+use std::collections::{BTreeMap, HashMap};
+
+use crate::commit::{CommitType, ConventionalCommit};
+
+/// A semantic version bump, ordered from the smallest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum BumpLevel {
+    Patch,
+    Minor,
+    Major,
+}
+
+impl BumpLevel {
+    /// The bump a commit calls for: breaking changes are major, features minor, and bug fixes
+    /// and performance improvements patch. Other commits do not call for a release.
+    ///
+    /// # Example :
+    /// ```
+    /// use conventional_commit_parser::monorepo::BumpLevel;
+    /// use conventional_commit_parser::parse;
+    ///
+    /// assert_eq!(BumpLevel::of(&parse("refactor!: drop parse_summary").unwrap()), Some(BumpLevel::Major));
+    /// assert_eq!(BumpLevel::of(&parse("perf: skip allocations").unwrap()), Some(BumpLevel::Patch));
+    /// assert_eq!(BumpLevel::of(&parse("chore: release").unwrap()), None);
+    /// ```
+    pub fn of(commit: &ConventionalCommit) -> Option<BumpLevel> {
+        if commit.is_breaking_change {
+            return Some(BumpLevel::Major);
+        }
+
+        match commit.commit_type {
+            CommitType::Feature => Some(BumpLevel::Minor),
+            CommitType::BugFix | CommitType::Performances => Some(BumpLevel::Patch),
+            _ => None,
+        }
+    }
+}
+
+/// A releasable package of a monorepo
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Package {
+    /// The package name, e.g. the crate name
+    pub name: String,
+    /// The package path relative to the repository root
+    pub path: String,
+}
+
+impl Package {
+    /// Create a package from its name and path
+    pub fn new<N: Into<String>, P: Into<String>>(name: N, path: P) -> Self {
+        Package {
+            name: name.into(),
+            path: path.into(),
+        }
+    }
+}
+
+/// Packages of a monorepo keyed by the commit scopes naming them, several scopes can name
+/// the same package
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MonorepoMap {
+    /// Package of each scope, scopes are compared exactly
+    pub scopes: HashMap<String, Package>,
+    /// The package receiving unscoped commits, usually the workspace root.
+    /// Unscoped commits are ignored when `None`.
+    pub unscoped: Option<Package>,
+}
+
+impl MonorepoMap {
+    /// Returns the package a commit belongs to, `None` for unmapped scopes
+    pub fn package(&self, commit: &ConventionalCommit) -> Option<&Package> {
+        match &commit.scope {
+            Some(scope) => self.scopes.get(scope),
+            None => self.unscoped.as_ref(),
+        }
+    }
+}
+
+/// The release of a package, see [`affected_packages`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackageBump {
+    pub package: Package,
+    /// The highest bump called for by the package commits
+    pub level: BumpLevel,
+    /// Number of package commits calling for a release
+    pub commits: usize,
+}
+
+/// Compute the version bump of every package touched by commits calling for a release,
+/// sorted by package name. Commits with an unmapped scope, and commits that do not call for
+/// a release such as `docs` or `chore`, are ignored.
+pub fn affected_packages<I>(commits: I, map: &MonorepoMap) -> Vec<PackageBump>
+where
+    I: IntoIterator,
+    I::Item: Borrow<ConventionalCommit>,
+{
+    let mut bumps: BTreeMap<&str, PackageBump> = BTreeMap::new();
+    for commit in commits {
+        let commit = commit.borrow();
+        let (Some(package), Some(level)) = (map.package(commit), BumpLevel::of(commit)) else {
+            continue;
+        };
+
+        bumps
+            .entry(package.name.as_str())
+            .and_modify(|bump| {
+                bump.level = bump.level.max(level);
+                bump.commits += 1;
+            })
+            .or_insert_with(|| PackageBump {
+                package: package.clone(),
+                level,
+                commits: 1,
+            });
+    }
+
+    bumps.into_values().collect()
+}
@@ -0,0 +1,796 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::commit::{CommitType, ConventionalCommit, Separator};
+use crate::encoding::MessageEncoding;
+use crate::error::{ParseError, ParseErrorKind, ParseWarning};
+use crate::lint::{LintConfig, LintRule};
+use crate::scope::ScopeIndex;
+use crate::sections::is_footer_start;
+
+/// Parser configuration, every option defaults to the behavior of [`crate::parse`]
+#[derive(Debug, Clone, Default)]
+pub struct ParserOptions {
+    /// How raw message bytes are decoded by [`crate::parse_bytes`]
+    pub encoding: MessageEncoding,
+    /// Maximum summary length in characters, producing [`ParseErrorKind::SummaryTooLong`]
+    pub summary_max_len: Option<usize>,
+    /// Minimum summary length in characters, producing [`ParseErrorKind::SummaryTooShort`]
+    pub summary_min_len: Option<usize>,
+    /// Alternative header layout accepted in addition to the conventional one
+    pub header_style: HeaderStyle,
+    /// Body or footers starting right after the summary, without a blank line,
+    /// see [`ParseErrorKind::MissingBlankLineBeforeBody`]
+    pub missing_blank_line_before_body: Policy,
+    /// A paragraph following footers that directly follow the summary, moved to the body
+    /// by [`FooterContinuation::StopAtBlank`], see [`ParseErrorKind::BodyAfterFooters`].
+    /// Not checked with [`FooterContinuation::Greedy`], which reads such a paragraph as
+    /// part of the last footer value.
+    pub body_after_footers: Policy,
+    /// Footers using the ` #` token separator, e.g. `Refs #12`,
+    /// see [`ParseErrorKind::HashFooterSeparator`]
+    pub hash_footer_separator: Policy,
+    /// Whether a blank line ends a footer value
+    pub footer_continuation: FooterContinuation,
+    /// Maximum message size in bytes, larger messages are rejected before parsing with
+    /// [`ParseErrorKind::MessageTooLarge`]. Services parsing untrusted input should set it.
+    pub max_message_len: Option<usize>,
+    /// Accepted scopes, other scopes are rejected with [`ParseErrorKind::DisallowedScope`]
+    pub scope_validator: Option<Validator>,
+    /// Accepted custom commit types, specification types (`feat`, `fix`, ...) are always
+    /// accepted. Other types are rejected with [`ParseErrorKind::DisallowedType`].
+    /// When set, custom types may also hold digits and `-`, e.g. `exp-ui`.
+    pub custom_type_validator: Option<Validator>,
+    /// Additional footer tokens announcing a breaking change, e.g. a translated
+    /// `CHANGEMENT MAJEUR`, in addition to `BREAKING CHANGE` which is always recognized.
+    /// Matching footers keep their token, and the tokens used are recorded in
+    /// [`ConventionalCommit::breaking_change_tokens`], see
+    /// [`ConventionalCommit::is_breaking_change_footer`].
+    pub breaking_change_tokens: Vec<String>,
+    /// Accept a bracketed tag before the commit type, e.g. the ticket key of
+    /// `[JIRA-1] feat: summary`, captured in [`ConventionalCommit::pre_header_tag`].
+    /// The tag is only rendered with [`crate::commit::FormatOptions::pre_header_tag`].
+    pub accept_pre_header_tag: bool,
+    /// Accept parenthesis escaped with a backslash inside the scope, e.g. the function name
+    /// of `fix(handle\(x\)): summary`, unescaped in [`ConventionalCommit::scope`]
+    pub escaped_scope_parenthesis: bool,
+    /// The commit history used to suggest the nearest accepted value when a scope or a custom
+    /// type is rejected, see [`ParseError::suggestion`]
+    pub suggestion_index: Option<Arc<ScopeIndex>>,
+}
+
+/// Stand-ins for `\(` and `\)` in scopes, two bytes long C1 control characters that are
+/// neither whitespace nor parenthesis
+const OPEN_PLACEHOLDER: char = '\u{91}';
+const CLOSE_PLACEHOLDER: char = '\u{92}';
+
+/// A predicate over a header value, such as the commit scope
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::error::ParseErrorKind;
+/// use conventional_commit_parser::options::{ParserOptions, Validator};
+/// use conventional_commit_parser::parse_with_options;
+///
+/// let options = ParserOptions {
+///     scope_validator: Some(Validator::one_of(&["parser", "changelog"])),
+///     ..Default::default()
+/// };
+///
+/// assert!(parse_with_options("feat(parser): add validators", &options).is_ok());
+///
+/// let error = parse_with_options("feat(cli): add validators", &options).unwrap_err();
+/// assert_eq!(error.kind, ParseErrorKind::DisallowedScope { scope: "cli".to_string() });
+/// ```
+#[derive(Clone)]
+pub struct Validator(Arc<dyn Fn(&str) -> bool + Send + Sync>);
+
+impl Validator {
+    /// Accept values for which `predicate` returns `true`
+    pub fn new<F>(predicate: F) -> Self
+    where
+        F: Fn(&str) -> bool + Send + Sync + 'static,
+    {
+        Validator(Arc::new(predicate))
+    }
+
+    /// Accept the listed values only
+    pub fn one_of<S: AsRef<str>>(values: &[S]) -> Self {
+        let values: Vec<String> = values
+            .iter()
+            .map(|value| value.as_ref().to_string())
+            .collect();
+        Validator::new(move |value| values.iter().any(|accepted| accepted == value))
+    }
+
+    /// Returns `true` if the value is accepted
+    pub fn accepts(&self, value: &str) -> bool {
+        (self.0)(value)
+    }
+}
+
+/// Accept values entirely matching the regular expression, requires the `regex` feature
+#[cfg(feature = "regex")]
+impl From<regex::Regex> for Validator {
+    fn from(regex: regex::Regex) -> Self {
+        // Anchoring the whole expression, rather than checking the span of the leftmost
+        // match, accepts `ab` with `a|ab`
+        let anchored = regex::Regex::new(&format!("^(?:{})$", regex.as_str()))
+            .expect("an anchored valid expression is valid");
+        Validator::new(move |value| anchored.is_match(value))
+    }
+}
+
+impl fmt::Debug for Validator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Validator")
+    }
+}
+
+/// How a recoverable formatting issue is handled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Policy {
+    /// Silently accept the message
+    #[default]
+    Allow,
+    /// Accept the message, reporting a [`ParseWarning`], see [`crate::parse_with_diagnostics`]
+    Warn,
+    /// Reject the message with a [`ParseError`]
+    Deny,
+}
+
+/// How a blank line inside a footer value is interpreted
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::options::{FooterContinuation, ParserOptions};
+/// use conventional_commit_parser::parse_with_options;
+///
+/// let message = "fix: typo\n\nRefs: #12\n\nA trailing paragraph";
+///
+/// let greedy = parse_with_options(message, &ParserOptions::default()).unwrap();
+/// assert_eq!(greedy.footers[0].content, "#12\n\nA trailing paragraph");
+///
+/// let options = ParserOptions {
+///     footer_continuation: FooterContinuation::StopAtBlank,
+///     ..Default::default()
+/// };
+///
+/// let stopped = parse_with_options(message, &options).unwrap();
+/// assert_eq!(stopped.footers[0].content, "#12");
+/// assert_eq!(stopped.body, Some("A trailing paragraph".to_string()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FooterContinuation {
+    /// A footer value runs until the next footer token, blank lines included,
+    /// as described by the specification
+    #[default]
+    Greedy,
+    /// A blank line ends the footer value, as git trailers and Gerrit do. Paragraphs
+    /// following the blank line that are not footers are appended to the body.
+    StopAtBlank,
+}
+
+impl FooterContinuation {
+    /// Move the paragraphs following a blank line in footer values to the body
+    pub(crate) fn apply(&self, commit: &mut ConventionalCommit) {
+        if *self == FooterContinuation::Greedy {
+            return;
+        }
+
+        let mut paragraphs: Vec<String> = commit.body.take().into_iter().collect();
+        for footer in &mut commit.footers {
+            if let Some((value, paragraph)) = split_at_blank_line(&footer.content) {
+                paragraphs.push(paragraph.to_string());
+                footer.content = value.to_string();
+            }
+        }
+
+        commit.body = Some(paragraphs.join("\n\n")).filter(|body| !body.is_empty());
+    }
+}
+
+/// Split a text on its first blank line, returning both trimmed parts
+fn split_at_blank_line(text: &str) -> Option<(&str, &str)> {
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        if offset > 0 && line.trim().is_empty() {
+            return Some((text[..offset].trim_end(), text[offset..].trim()));
+        }
+
+        offset += line.len();
+    }
+
+    None
+}
+
+/// A parsed commit along with the warnings reported by the configured [`Policy`]s
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiagnosedCommit {
+    pub commit: ConventionalCommit,
+    pub warnings: Vec<ParseWarning>,
+}
+
+/// Header layouts understood by [`crate::parse_with_options`], alternative styles are mapped
+/// onto the conventional `<type>[(scope)][!]: <summary>` header before parsing.
+/// Conventional headers are still accepted with an alternative style, easing migrations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HeaderStyle {
+    /// `<type>[(scope)][!]: <summary>`
+    #[default]
+    Conventional,
+    /// `<type>[!]: [scope] <summary>`
+    BracketedScope,
+    /// `<type>[/scope][!]: <summary>`
+    SlashScope,
+}
+
+/// Apply a policy to an issue found at byte offset `offset` of `message`
+fn report(
+    policy: Policy,
+    kind: ParseErrorKind,
+    message: &str,
+    offset: usize,
+    warnings: &mut Vec<ParseWarning>,
+) -> Result<(), ParseError> {
+    #[cfg(feature = "tracing")]
+    tracing::debug!(policy = ?policy, kind = ?kind, offset, "recoverable formatting issue");
+
+    match policy {
+        Policy::Allow => Ok(()),
+        Policy::Warn => {
+            warnings.push(ParseWarning { kind, offset });
+            Ok(())
+        }
+        Policy::Deny => Err(ParseError::custom(kind, message, offset)),
+    }
+}
+
+/// Named bundles of [`ParserOptions`] and [`LintConfig`]
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::lint::lint;
+/// use conventional_commit_parser::options::Preset;
+/// use conventional_commit_parser::parse_with_options;
+///
+/// let preset = Preset::Angular;
+/// let commit = parse_with_options("chore: Update deps.", &preset.parser_options()).unwrap();
+///
+/// assert_eq!(lint(&commit, &preset.lint_config()).len(), 3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Preset {
+    /// The [conventional commit specification](https://www.conventionalcommits.org/en/v1.0.0/)
+    /// and nothing more
+    #[default]
+    ConventionalV1,
+    /// The [angular convention](https://github.com/angular/angular/blob/main/CONTRIBUTING.md#commit):
+    /// angular commit types, lower case summary without trailing period
+    /// and 100 characters lines
+    Angular,
+    /// The specification, decoding invalid UTF-8 lossily and accepting
+    /// bracketed scopes (`type: [scope] summary`)
+    Lenient,
+}
+
+impl Preset {
+    /// The parser options of this preset
+    pub fn parser_options(&self) -> ParserOptions {
+        match self {
+            Preset::ConventionalV1 | Preset::Angular => ParserOptions::default(),
+            Preset::Lenient => ParserOptions {
+                encoding: MessageEncoding::Utf8Lossy,
+                header_style: HeaderStyle::BracketedScope,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// The lint rules of this preset
+    pub fn lint_config(&self) -> LintConfig {
+        match self {
+            Preset::ConventionalV1 | Preset::Lenient => LintConfig::default(),
+            Preset::Angular => LintConfig {
+                rules: vec![
+                    LintRule::TypeEnum(vec![
+                        CommitType::Build,
+                        CommitType::Ci,
+                        CommitType::Documentation,
+                        CommitType::Feature,
+                        CommitType::BugFix,
+                        CommitType::Performances,
+                        CommitType::Refactor,
+                        CommitType::Test,
+                    ]),
+                    LintRule::SummaryLowerCase,
+                    LintRule::SummaryNoTrailingPeriod,
+                    LintRule::HeaderMaxLength(100),
+                    LintRule::BodyMaxLineLength(100),
+                ],
+            },
+        }
+    }
+}
+
+impl ParserOptions {
+    /// Split the leading `[tag] ` of a message when [`ParserOptions::accept_pre_header_tag`]
+    /// is set, returning the tag and the remaining message
+    pub(crate) fn split_pre_header_tag<'a>(&self, message: &'a str) -> (Option<&'a str>, &'a str) {
+        if !self.accept_pre_header_tag {
+            return (None, message);
+        }
+
+        let tagged = message
+            .strip_prefix('[')
+            .and_then(|rest| rest.split_once("] "));
+        match tagged {
+            Some((tag, rest)) if !tag.is_empty() && !tag.contains(['[', '\r', '\n']) => {
+                (Some(tag), rest)
+            }
+            _ => (None, message),
+        }
+    }
+
+    /// Replace the digits and `-` of the commit type by letters accepted by the grammar when
+    /// [`ParserOptions::custom_type_validator`] is set, returning the original type if it was
+    /// masked. The masked type has the same length, so error offsets still match the message.
+    pub(crate) fn mask_custom_type<'a>(
+        &self,
+        message: Cow<'a, str>,
+    ) -> (Cow<'a, str>, Option<String>) {
+        if self.custom_type_validator.is_none() {
+            return (message, None);
+        }
+
+        let type_end = message
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '-'))
+            .unwrap_or(message.len());
+        let commit_type = &message[..type_end];
+        let masked = !commit_type.bytes().all(|b| b.is_ascii_alphabetic())
+            && matches!(message[type_end..].chars().next(), Some('(' | '!' | ':'));
+        if !masked {
+            return (message, None);
+        }
+
+        let commit_type = commit_type.to_string();
+        let masked: String = commit_type
+            .chars()
+            .map(|c| if c.is_ascii_alphabetic() { c } else { 'x' })
+            .chain(message[type_end..].chars())
+            .collect();
+        (Cow::Owned(masked), Some(commit_type))
+    }
+
+    /// Restore the type masked by [`ParserOptions::mask_custom_type`]
+    pub(crate) fn unmask_custom_type(
+        &self,
+        commit_type: Option<String>,
+        commit: &mut ConventionalCommit,
+    ) {
+        if let Some(commit_type) = commit_type {
+            commit.commit_type = CommitType::from(commit_type.as_str());
+        }
+    }
+
+    /// Replace the parenthesis escaped in the scope by placeholders accepted by the grammar
+    /// when [`ParserOptions::escaped_scope_parenthesis`] is set. Placeholders have the
+    /// length of the escape sequences, so error offsets still match the message.
+    pub(crate) fn mask_escaped_scope_parenthesis<'a>(&self, message: Cow<'a, str>) -> Cow<'a, str> {
+        if !self.escaped_scope_parenthesis {
+            return message;
+        }
+
+        let type_end = message
+            .find(|c: char| !c.is_ascii_alphanumeric())
+            .unwrap_or(message.len());
+        if !message[type_end..].starts_with('(') || !message.contains("\\") {
+            return message;
+        }
+
+        let mut masked = String::with_capacity(message.len());
+        masked.push_str(&message[..=type_end]);
+        let mut chars = message[type_end + 1..].char_indices();
+        while let Some((index, c)) = chars.next() {
+            let rest = &message[type_end + 1 + index..];
+            if rest.starts_with("\\(") || rest.starts_with("\\)") {
+                chars.next();
+                masked.push(if rest.as_bytes()[1] == b'(' {
+                    OPEN_PLACEHOLDER
+                } else {
+                    CLOSE_PLACEHOLDER
+                });
+                continue;
+            }
+
+            if matches!(c, ')' | '\r' | '\n') {
+                masked.push_str(rest);
+                return Cow::Owned(masked);
+            }
+            masked.push(c);
+        }
+
+        Cow::Owned(masked)
+    }
+
+    /// Restore the parenthesis masked by [`ParserOptions::mask_escaped_scope_parenthesis`]
+    pub(crate) fn unmask_scope_parenthesis(&self, commit: &mut ConventionalCommit) {
+        if let Some(scope) = commit
+            .scope
+            .as_mut()
+            .filter(|_| self.escaped_scope_parenthesis)
+        {
+            *scope = scope
+                .replace(OPEN_PLACEHOLDER, "(")
+                .replace(CLOSE_PLACEHOLDER, ")");
+        }
+    }
+
+    /// Replace the [`ParserOptions::breaking_change_tokens`] starting a footer line by tokens
+    /// accepted by the grammar, see [`masked_token`]. Masked tokens have the same length,
+    /// so error offsets still match the message.
+    pub(crate) fn mask_breaking_change_tokens<'a>(&self, message: Cow<'a, str>) -> Cow<'a, str> {
+        let tokens: Vec<(&str, String)> = self
+            .breaking_change_tokens
+            .iter()
+            .filter(|token| !token.is_empty())
+            .map(|token| (token.as_str(), masked_token(token)))
+            .filter(|(token, masked)| token != masked)
+            .collect();
+        if tokens.is_empty() {
+            return message;
+        }
+
+        let mut masked = String::with_capacity(message.len());
+        let mut changed = false;
+        for (index, line) in message.split_inclusive('\n').enumerate() {
+            let footer = tokens
+                .iter()
+                .filter(|_| index > 0)
+                .find_map(|(token, masked)| line.strip_prefix(token).map(|rest| (masked, rest)))
+                .filter(|(_, rest)| {
+                    rest.starts_with(": ") || rest.starts_with(":\n") || rest.starts_with(":\r\n")
+                });
+
+            match footer {
+                Some((token, rest)) => {
+                    masked.push_str(token);
+                    masked.push_str(rest);
+                    changed = true;
+                }
+                None => masked.push_str(line),
+            }
+        }
+
+        if changed {
+            Cow::Owned(masked)
+        } else {
+            message
+        }
+    }
+
+    /// Restore the tokens masked by [`ParserOptions::mask_breaking_change_tokens`] and mark
+    /// commits with a footer using one of the [`ParserOptions::breaking_change_tokens`]
+    /// as breaking changes
+    pub(crate) fn apply_breaking_change_tokens(&self, commit: &mut ConventionalCommit) {
+        for footer in &mut commit.footers {
+            let token = self
+                .breaking_change_tokens
+                .iter()
+                .filter(|token| !token.is_empty())
+                .find(|token| **token == footer.token || masked_token(token) == footer.token);
+
+            if let Some(token) = token {
+                footer.token = token.clone();
+                commit.is_breaking_change = true;
+                if !commit.breaking_change_tokens.contains(token) {
+                    commit.breaking_change_tokens.push(token.clone());
+                }
+            }
+        }
+    }
+}
+
+/// A footer token accepted by the grammar with the same length as `token`, every byte of the
+/// characters not allowed in tokens, such as spaces, is replaced by `-`
+fn masked_token(token: &str) -> String {
+    token
+        .chars()
+        .flat_map(|c| {
+            let (c, len) = if c.is_ascii_alphanumeric() || c == '-' {
+                (c, 1)
+            } else {
+                ('-', c.len_utf8())
+            };
+            std::iter::repeat_n(c, len)
+        })
+        .collect()
+}
+
+impl HeaderStyle {
+    /// Rewrite the header of a message in this style to a conventional header,
+    /// messages not matching the style are returned unchanged
+    pub(crate) fn rewrite_header<'a>(&self, message: &'a str) -> Cow<'a, str> {
+        let header_end = message.find(['\r', '\n']).unwrap_or(message.len());
+        let (header, rest) = message.split_at(header_end);
+
+        let rewritten = match self {
+            HeaderStyle::Conventional => None,
+            HeaderStyle::BracketedScope => bracketed_scope_header(header),
+            HeaderStyle::SlashScope => slash_scope_header(header),
+        };
+
+        match rewritten {
+            Some(header) => Cow::Owned(header + rest),
+            None => Cow::Borrowed(message),
+        }
+    }
+}
+
+/// `type!: [scope] summary` -> `type(scope)!: summary`
+fn bracketed_scope_header(header: &str) -> Option<String> {
+    let (prefix, summary) = header.split_once(": ")?;
+    let (scope, summary) = summary.strip_prefix('[')?.split_once("] ")?;
+    let (commit_type, mark) = match prefix.strip_suffix('!') {
+        Some(commit_type) => (commit_type, "!"),
+        None => (prefix, ""),
+    };
+
+    Some(format!("{}({}){}: {}", commit_type, scope, mark, summary))
+}
+
+/// `type/scope!: summary` -> `type(scope)!: summary`
+fn slash_scope_header(header: &str) -> Option<String> {
+    let (prefix, summary) = header.split_once(": ")?;
+    let (commit_type, scope) = prefix.split_once('/')?;
+    let (scope, mark) = match scope.strip_suffix('!') {
+        Some(scope) => (scope, "!"),
+        None => (scope, ""),
+    };
+
+    Some(format!("{}({}){}: {}", commit_type, scope, mark, summary))
+}
+
+impl ParserOptions {
+    /// Reject messages larger than [`ParserOptions::max_message_len`], `len` is the size of
+    /// the raw message in bytes
+    pub(crate) fn check_message_len(&self, message: &str, len: usize) -> Result<(), ParseError> {
+        match self.max_message_len.filter(|limit| len > *limit) {
+            Some(limit) => {
+                #[cfg(feature = "tracing")]
+                tracing::debug!(
+                    limit,
+                    actual = len,
+                    "commit message rejected before parsing"
+                );
+
+                let mut pos = limit.min(message.len());
+                while !message.is_char_boundary(pos) {
+                    pos -= 1;
+                }
+
+                Err(ParseError::custom(
+                    ParseErrorKind::MessageTooLarge { limit, actual: len },
+                    message,
+                    pos,
+                ))
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Validate a parsed commit against the configured policies, returning the warnings
+    pub(crate) fn validate(
+        &self,
+        message: &str,
+        commit: &ConventionalCommit,
+    ) -> Result<Vec<ParseWarning>, ParseError> {
+        let mut warnings = vec![];
+        self.validate_custom_type(message, commit)?;
+        self.validate_scope(message, commit)?;
+        self.validate_summary_len(message, commit)?;
+        self.validate_blank_line_before_body(message, &mut warnings)?;
+        self.validate_body_after_footers(message, commit, &mut warnings)?;
+        self.validate_hash_footer_separator(message, commit, &mut warnings)?;
+        Ok(warnings)
+    }
+
+    fn validate_custom_type(
+        &self,
+        message: &str,
+        commit: &ConventionalCommit,
+    ) -> Result<(), ParseError> {
+        let (Some(validator), CommitType::Custom(commit_type)) =
+            (&self.custom_type_validator, &commit.commit_type)
+        else {
+            return Ok(());
+        };
+
+        if validator.accepts(commit_type) {
+            return Ok(());
+        }
+
+        let replacement = self.suggestion_index.as_ref().and_then(|index| {
+            index.nearest_type(commit_type, |candidate| {
+                !matches!(CommitType::from(candidate), CommitType::Custom(_))
+                    || validator.accepts(candidate)
+            })
+        });
+
+        let error = ParseError::custom(
+            ParseErrorKind::DisallowedType {
+                commit_type: commit_type.clone(),
+            },
+            message,
+            0,
+        );
+        Err(error.with_replacement(replacement))
+    }
+
+    fn validate_scope(&self, message: &str, commit: &ConventionalCommit) -> Result<(), ParseError> {
+        let (Some(validator), Some(scope)) = (&self.scope_validator, &commit.scope) else {
+            return Ok(());
+        };
+
+        if validator.accepts(scope) {
+            return Ok(());
+        }
+
+        // The scope directly follows the type and its opening parenthesis
+        let offset = commit.commit_type.as_ref().len() + 1;
+        let replacement = self.suggestion_index.as_ref().and_then(|index| {
+            index.nearest_scope(scope, commit.commit_type.as_ref(), |candidate| {
+                validator.accepts(candidate)
+            })
+        });
+
+        let error = ParseError::custom(
+            ParseErrorKind::DisallowedScope {
+                scope: scope.clone(),
+            },
+            message,
+            offset,
+        );
+        Err(error.with_replacement(replacement))
+    }
+
+    fn validate_blank_line_before_body(
+        &self,
+        message: &str,
+        warnings: &mut Vec<ParseWarning>,
+    ) -> Result<(), ParseError> {
+        let header_end = message.find('\n').map(|idx| idx + 1);
+        let second_line = header_end.map(|start| (start, message[start..].lines().next()));
+
+        if let Some((offset, Some(line))) = second_line {
+            if !line.trim().is_empty() {
+                let kind = ParseErrorKind::MissingBlankLineBeforeBody;
+                report(
+                    self.missing_blank_line_before_body,
+                    kind,
+                    message,
+                    offset,
+                    warnings,
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn validate_body_after_footers(
+        &self,
+        message: &str,
+        commit: &ConventionalCommit,
+        warnings: &mut Vec<ParseWarning>,
+    ) -> Result<(), ParseError> {
+        // A greedy footer value holds the following paragraphs on purpose
+        if self.footer_continuation == FooterContinuation::Greedy || commit.footers.is_empty() {
+            return Ok(());
+        }
+
+        // Only a message whose first paragraph holds the footers can have its body after them
+        let first_paragraph = message.lines().skip(1).find(|line| !line.trim().is_empty());
+        if !first_paragraph.is_some_and(is_footer_start) {
+            return Ok(());
+        }
+
+        let cherry_pick = commit
+            .cherry_picked_from
+            .as_ref()
+            .map(|sha| format!("(cherry picked from commit {})", sha));
+
+        let mut offset = 0;
+        let mut after_blank_line = false;
+        for (index, line) in message.split_inclusive('\n').enumerate() {
+            let start = offset;
+            offset += line.len();
+
+            if index == 0 {
+                continue;
+            }
+
+            if line.trim().is_empty() {
+                after_blank_line = true;
+                continue;
+            }
+
+            if cherry_pick.as_deref() == Some(line.trim()) {
+                continue;
+            }
+
+            if after_blank_line && !is_footer_start(line) {
+                let kind = ParseErrorKind::BodyAfterFooters;
+                return report(self.body_after_footers, kind, message, start, warnings);
+            }
+
+            after_blank_line = false;
+        }
+
+        Ok(())
+    }
+
+    fn validate_hash_footer_separator(
+        &self,
+        message: &str,
+        commit: &ConventionalCommit,
+        warnings: &mut Vec<ParseWarning>,
+    ) -> Result<(), ParseError> {
+        let Some(footer) = commit
+            .footers
+            .iter()
+            .find(|footer| footer.token_separator == Separator::Hash)
+        else {
+            return Ok(());
+        };
+
+        let line = format!(
+            "{} #{}",
+            footer.token,
+            footer.content.lines().next().unwrap_or_default()
+        );
+        let offset = message
+            .match_indices(&line)
+            .map(|(offset, _)| offset)
+            .find(|offset| message[..*offset].ends_with('\n'))
+            .unwrap_or_default();
+
+        let kind = ParseErrorKind::HashFooterSeparator;
+        report(self.hash_footer_separator, kind, message, offset, warnings)
+    }
+
+    fn validate_summary_len(
+        &self,
+        message: &str,
+        commit: &ConventionalCommit,
+    ) -> Result<(), ParseError> {
+        let actual = commit.summary.chars().count();
+        let header = message.lines().next().unwrap_or_default();
+        let summary_start = header.len().saturating_sub(commit.summary.len());
+
+        if let Some(limit) = self.summary_max_len.filter(|limit| actual > *limit) {
+            let overflow = commit
+                .summary
+                .char_indices()
+                .nth(limit)
+                .map_or(0, |(idx, _)| idx);
+
+            return Err(ParseError::custom(
+                ParseErrorKind::SummaryTooLong { limit, actual },
+                message,
+                summary_start + overflow,
+            ));
+        }
+
+        if let Some(limit) = self.summary_min_len.filter(|limit| actual < *limit) {
+            return Err(ParseError::custom(
+                ParseErrorKind::SummaryTooShort { limit, actual },
+                message,
+                summary_start,
+            ));
+        }
+
+        Ok(())
+    }
+}
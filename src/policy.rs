@@ -0,0 +1,447 @@
+//! Policies evaluated across a range of commits, typically to gate a release.
+//!
+//! Unlike [`crate::lint`] rules that check each commit on its own, a [`PolicyRule`]
+//! looks at the whole range, for instance to require at least one releasable commit.
+//! Rules may also depend on the branch the commits are pushed to, see [`PolicyContext`].
+
+use std::borrow::Borrow;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::commit::{Annotation, CommitType, ConventionalCommit};
+
+/// A rule evaluated against a range of commits
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum PolicyRule {
+    /// At least one commit must have one of the given types, e.g. a `feat` or a `fix` per release
+    RequireAnyType(Vec<CommitType>),
+    /// No commit may be a breaking change, e.g. on a patch branch
+    NoBreakingChanges,
+    /// At most the given number of distinct custom commit types may be used
+    MaxCustomTypes(usize),
+    /// Commits of the given types are forbidden on branches matching the pattern,
+    /// e.g. no `feat` on `hotfix/*`. Patterns support the `*` wildcard.
+    ForbidTypesOnBranch {
+        branch: String,
+        types: Vec<CommitType>,
+    },
+    /// When the branch name contains a ticket key such as `ABC-123`, every commit must
+    /// reference it
+    RequireBranchTicket,
+    /// No two commits may share the same normalized header, see [`find_duplicates`]
+    NoDuplicateHeaders,
+    /// Commits of the given types, any type when empty, may not have an integer annotation
+    /// above the maximum, e.g. at most 20 `files_changed` per `fix`.
+    /// Commits without the annotation are not checked, see [`ConventionalCommit::annotations`].
+    MaxAnnotation {
+        annotation: String,
+        max: i64,
+        types: Vec<CommitType>,
+    },
+}
+
+/// The context a range of commits is evaluated in, rules depending on a missing
+/// context value are skipped
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PolicyContext<'a> {
+    /// The branch the commits are pushed to, without the `refs/heads/` prefix
+    pub branch: Option<&'a str>,
+}
+
+/// A set of policy rules
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PolicyConfig {
+    pub rules: Vec<PolicyRule>,
+}
+
+/// A policy rule violation
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct PolicyViolation {
+    /// The violated rule
+    pub rule: PolicyRule,
+    /// Human readable description of the violation
+    pub message: String,
+    /// Position of the offending commits in the evaluated range, empty when the
+    /// violation is caused by a missing commit
+    pub commits: Vec<usize>,
+}
+
+impl fmt::Display for PolicyViolation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+/// Evaluate every rule of a [`PolicyConfig`] against a range of commits, returning
+/// the violations in rule order. Branch rules are skipped, see [`evaluate_with_context`].
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::commit::CommitType;
+/// use conventional_commit_parser::policy::{evaluate, PolicyConfig, PolicyRule};
+/// use conventional_commit_parser::parse;
+///
+/// let config = PolicyConfig {
+///     rules: vec![
+///         PolicyRule::RequireAnyType(vec![CommitType::Feature, CommitType::BugFix]),
+///         PolicyRule::NoBreakingChanges,
+///     ],
+/// };
+///
+/// let commits = vec![
+///     parse("docs: update readme").unwrap(),
+///     parse("chore!: drop msrv").unwrap(),
+/// ];
+///
+/// let violations = evaluate(&commits, &config);
+///
+/// assert_eq!(violations.len(), 2);
+/// assert_eq!(violations[1].rule, PolicyRule::NoBreakingChanges);
+/// assert_eq!(violations[1].commits, vec![1]);
+/// ```
+pub fn evaluate<I>(commits: I, config: &PolicyConfig) -> Vec<PolicyViolation>
+where
+    I: IntoIterator,
+    I::Item: Borrow<ConventionalCommit>,
+{
+    evaluate_with_context(commits, config, &PolicyContext::default())
+}
+
+/// Evaluate every rule of a [`PolicyConfig`] against a range of commits pushed in the given
+/// context, a single integration point for server side hooks
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::commit::CommitType;
+/// use conventional_commit_parser::policy::{evaluate_with_context, PolicyConfig, PolicyContext, PolicyRule};
+/// use conventional_commit_parser::parse;
+///
+/// let config = PolicyConfig {
+///     rules: vec![
+///         PolicyRule::ForbidTypesOnBranch {
+///             branch: "hotfix/*".to_string(),
+///             types: vec![CommitType::Feature],
+///         },
+///         PolicyRule::RequireBranchTicket,
+///     ],
+/// };
+///
+/// let context = PolicyContext {
+///     branch: Some("hotfix/ABC-42-login"),
+/// };
+///
+/// let commits = vec![
+///     parse("fix: reject expired tokens\n\nRefs: ABC-42").unwrap(),
+///     parse("feat: add login page").unwrap(),
+/// ];
+///
+/// let violations = evaluate_with_context(&commits, &config, &context);
+///
+/// assert_eq!(violations.len(), 2);
+/// assert_eq!(violations[0].commits, vec![1]);
+/// assert_eq!(violations[1].message, "1 commit(s) do not reference branch ticket `ABC-42`");
+/// ```
+pub fn evaluate_with_context<I>(
+    commits: I,
+    config: &PolicyConfig,
+    context: &PolicyContext<'_>,
+) -> Vec<PolicyViolation>
+where
+    I: IntoIterator,
+    I::Item: Borrow<ConventionalCommit>,
+{
+    let commits: Vec<I::Item> = commits.into_iter().collect();
+    let commits: Vec<&ConventionalCommit> = commits.iter().map(Borrow::borrow).collect();
+
+    config
+        .rules
+        .iter()
+        .filter_map(|rule| rule.evaluate(&commits, context))
+        .collect()
+}
+
+/// Find the commits sharing the same normalized header, typically left over by squash
+/// and fixup workflows. Headers are compared ignoring case, surrounding and repeated
+/// whitespace and a trailing period.
+///
+/// Returns the position of the duplicated commits, grouped by header in order of first
+/// occurrence.
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::policy::find_duplicates;
+/// use conventional_commit_parser::parse;
+///
+/// let commits = vec![
+///     parse("fix(parser): handle tabs").unwrap(),
+///     parse("feat: add policies").unwrap(),
+///     parse("fix(Parser): Handle  tabs.").unwrap(),
+/// ];
+///
+/// assert_eq!(find_duplicates(&commits), vec![vec![0, 2]]);
+/// ```
+pub fn find_duplicates<I>(commits: I) -> Vec<Vec<usize>>
+where
+    I: IntoIterator,
+    I::Item: Borrow<ConventionalCommit>,
+{
+    let commits: Vec<I::Item> = commits.into_iter().collect();
+    let commits: Vec<&ConventionalCommit> = commits.iter().map(Borrow::borrow).collect();
+    duplicate_groups(&commits)
+}
+
+fn duplicate_groups(commits: &[&ConventionalCommit]) -> Vec<Vec<usize>> {
+    let mut groups: Vec<Vec<usize>> = vec![];
+    let mut positions: HashMap<String, usize> = HashMap::new();
+
+    for (index, commit) in commits.iter().enumerate() {
+        let key = normalized_header(commit);
+        match positions.get(&key) {
+            Some(&group) => groups[group].push(index),
+            None => {
+                positions.insert(key, groups.len());
+                groups.push(vec![index]);
+            }
+        }
+    }
+
+    groups.retain(|group| group.len() > 1);
+    groups
+}
+
+fn normalized_header(commit: &ConventionalCommit) -> String {
+    let header = commit.header().to_lowercase();
+    let header = header.split_whitespace().collect::<Vec<&str>>().join(" ");
+    header.trim_end_matches('.').to_string()
+}
+
+impl PolicyRule {
+    /// Evaluate a single rule, returning the violation if any
+    fn evaluate(
+        &self,
+        commits: &[&ConventionalCommit],
+        context: &PolicyContext<'_>,
+    ) -> Option<PolicyViolation> {
+        let (message, offenders) = match self {
+            PolicyRule::RequireAnyType(types) => {
+                let found = commits
+                    .iter()
+                    .any(|commit| types.contains(&commit.commit_type));
+                if found {
+                    return None;
+                }
+
+                let message = format!(
+                    "No commit of type {}",
+                    types
+                        .iter()
+                        .map(|commit_type| format!("`{}`", commit_type))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                );
+                (message, vec![])
+            }
+            PolicyRule::NoBreakingChanges => {
+                let offenders = offending_commits(commits, |commit| commit.is_breaking_change);
+                if offenders.is_empty() {
+                    return None;
+                }
+
+                let message = format!("{} breaking change(s) found", offenders.len());
+                (message, offenders)
+            }
+            PolicyRule::MaxCustomTypes(limit) => {
+                // Keep the commits using each custom type, sorted by type for a stable message
+                let mut custom_types: BTreeMap<&str, Vec<usize>> = BTreeMap::new();
+                for (index, commit) in commits.iter().enumerate() {
+                    if let CommitType::Custom(commit_type) = &commit.commit_type {
+                        custom_types.entry(commit_type).or_default().push(index);
+                    }
+                }
+
+                if custom_types.len() <= *limit {
+                    return None;
+                }
+
+                let message = format!(
+                    "{} custom commit types used ({}), maximum is {}",
+                    custom_types.len(),
+                    custom_types
+                        .keys()
+                        .map(|commit_type| format!("`{}`", commit_type))
+                        .collect::<Vec<String>>()
+                        .join(", "),
+                    limit
+                );
+                let mut offenders: Vec<usize> = custom_types.into_values().flatten().collect();
+                offenders.sort_unstable();
+                (message, offenders)
+            }
+            PolicyRule::ForbidTypesOnBranch { branch, types } => {
+                let current = context.branch?;
+                if !branch_matches(branch, current) {
+                    return None;
+                }
+
+                let offenders =
+                    offending_commits(commits, |commit| types.contains(&commit.commit_type));
+                if offenders.is_empty() {
+                    return None;
+                }
+
+                let message = format!(
+                    "{} commit(s) of type {} on branch `{}`",
+                    offenders.len(),
+                    types
+                        .iter()
+                        .map(|commit_type| format!("`{}`", commit_type))
+                        .collect::<Vec<String>>()
+                        .join(", "),
+                    current
+                );
+                (message, offenders)
+            }
+            PolicyRule::RequireBranchTicket => {
+                let ticket = context.branch.and_then(branch_ticket)?;
+                let offenders = offending_commits(commits, |commit| {
+                    !references_ticket(&commit.to_string(), ticket)
+                        && !commit
+                            .pre_header_tag
+                            .as_deref()
+                            .is_some_and(|tag| references_ticket(tag, ticket))
+                });
+                if offenders.is_empty() {
+                    return None;
+                }
+
+                let message = format!(
+                    "{} commit(s) do not reference branch ticket `{}`",
+                    offenders.len(),
+                    ticket
+                );
+                (message, offenders)
+            }
+            PolicyRule::NoDuplicateHeaders => {
+                let duplicates = duplicate_groups(commits);
+                if duplicates.is_empty() {
+                    return None;
+                }
+
+                let message = format!(
+                    "{} header(s) used by more than one commit: {}",
+                    duplicates.len(),
+                    duplicates
+                        .iter()
+                        .map(|group| format!("`{}`", commits[group[0]].header()))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                );
+                let mut offenders: Vec<usize> = duplicates.into_iter().flatten().collect();
+                offenders.sort_unstable();
+                (message, offenders)
+            }
+            PolicyRule::MaxAnnotation {
+                annotation,
+                max,
+                types,
+            } => {
+                let offenders = offending_commits(commits, |commit| {
+                    let checked = types.is_empty() || types.contains(&commit.commit_type);
+                    match commit.annotations.get(annotation) {
+                        Some(Annotation::Integer(value)) => checked && value > max,
+                        _ => false,
+                    }
+                });
+                if offenders.is_empty() {
+                    return None;
+                }
+
+                let message = format!(
+                    "{} commit(s) with `{}` above {}",
+                    offenders.len(),
+                    annotation,
+                    max
+                );
+                (message, offenders)
+            }
+        };
+
+        Some(PolicyViolation {
+            rule: self.clone(),
+            message,
+            commits: offenders,
+        })
+    }
+}
+
+fn offending_commits<F>(commits: &[&ConventionalCommit], offends: F) -> Vec<usize>
+where
+    F: Fn(&ConventionalCommit) -> bool,
+{
+    commits
+        .iter()
+        .enumerate()
+        .filter(|(_, commit)| offends(commit))
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// Match a branch name against a pattern where `*` matches any sequence of characters
+fn branch_matches(pattern: &str, branch: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = branch.strip_prefix(first) else {
+        return false;
+    };
+
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        // No wildcard, the whole branch must match
+        return rest.is_empty();
+    };
+
+    for part in middle {
+        match rest.find(part) {
+            Some(position) => rest = &rest[position + part.len()..],
+            None => return false,
+        }
+    }
+
+    rest.ends_with(last)
+}
+
+/// Find the first `ABC-123` ticket key in a branch name
+fn branch_ticket(branch: &str) -> Option<&str> {
+    let is_boundary = |c: char| !c.is_ascii_alphanumeric();
+
+    branch.char_indices().find_map(|(start, c)| {
+        let preceded = branch[..start].chars().next_back().is_none_or(is_boundary);
+        if !preceded || !c.is_ascii_uppercase() {
+            return None;
+        }
+
+        let project_len = branch[start..]
+            .find(|c: char| !(c.is_ascii_uppercase() || c.is_ascii_digit()))
+            .unwrap_or(branch.len() - start);
+        let number = branch[start + project_len..].strip_prefix('-')?;
+        let number_len = number
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(number.len());
+        let followed = number[number_len..].chars().next().is_none_or(is_boundary);
+
+        (number_len > 0 && followed).then(|| &branch[start..start + project_len + 1 + number_len])
+    })
+}
+
+/// Returns `true` if the message contains the ticket key as a whole word
+fn references_ticket(message: &str, ticket: &str) -> bool {
+    message.match_indices(ticket).any(|(start, _)| {
+        let before = message[..start].chars().next_back();
+        let after = message[start + ticket.len()..].chars().next();
+        !before.is_some_and(|c| c.is_ascii_alphanumeric())
+            && !after.is_some_and(|c| c.is_ascii_alphanumeric())
+    })
+}
@@ -0,0 +1,142 @@
+//! Filtering of commit collections.
+//!
+//! # Example :
+//! ```
+//! use conventional_commit_parser::commit::CommitType;
+//! use conventional_commit_parser::parse;
+//! use conventional_commit_parser::query::{CommitQuery, QueryExt};
+//!
+//! let commits = vec![
+//!     parse("feat(api)!: remove v1 endpoints").unwrap(),
+//!     parse("fix(api): handle empty body").unwrap(),
+//!     parse("feat(cli): add verbose flag").unwrap(),
+//! ];
+//!
+//! let query = CommitQuery::new()
+//!     .types([CommitType::Feature, CommitType::BugFix])
+//!     .scope("api")
+//!     .breaking(true);
+//!
+//! let matching: Vec<_> = commits.iter().matching(&query).collect();
+//!
+//! assert_eq!(matching, vec![&commits[0]]);
+//! ```
+
+use std::borrow::Borrow;
+
+use crate::commit::{CommitType, ConventionalCommit};
+
+/// A set of criteria a commit must all satisfy, criteria left unset match any commit
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommitQuery {
+    types: Vec<CommitType>,
+    scopes: Vec<String>,
+    unscoped: bool,
+    breaking: Option<bool>,
+    footer_tokens: Vec<String>,
+}
+
+impl CommitQuery {
+    /// A query matching every commit
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Match commits of any of the given types
+    pub fn types<I>(mut self, types: I) -> Self
+    where
+        I: IntoIterator<Item = CommitType>,
+    {
+        self.types.extend(types);
+        self
+    }
+
+    /// Match commits with the given scope, can be called multiple times to match any of them
+    pub fn scope<S: Into<String>>(mut self, scope: S) -> Self {
+        self.scopes.push(scope.into());
+        self
+    }
+
+    /// Match commits without scope, in addition to the configured scopes
+    pub fn unscoped(mut self) -> Self {
+        self.unscoped = true;
+        self
+    }
+
+    /// Match breaking changes, or commits that are not breaking changes
+    pub fn breaking(mut self, breaking: bool) -> Self {
+        self.breaking = Some(breaking);
+        self
+    }
+
+    /// Match commits with a footer of the given token (case-insensitive),
+    /// every given token must be present
+    pub fn footer<S: Into<String>>(mut self, token: S) -> Self {
+        self.footer_tokens.push(token.into());
+        self
+    }
+
+    /// Returns true if the commit satisfies every criterion
+    pub fn matches(&self, commit: &ConventionalCommit) -> bool {
+        let type_ok = self.types.is_empty() || self.types.contains(&commit.commit_type);
+
+        let scope_ok = match &commit.scope {
+            _ if self.scopes.is_empty() && !self.unscoped => true,
+            Some(scope) => self.scopes.contains(scope),
+            None => self.unscoped,
+        };
+
+        let breaking_ok = self
+            .breaking
+            .is_none_or(|breaking| breaking == commit.is_breaking_change);
+
+        let footers_ok = self.footer_tokens.iter().all(|token| {
+            commit
+                .footers
+                .iter()
+                .any(|footer| footer.token.eq_ignore_ascii_case(token))
+        });
+
+        type_ok && scope_ok && breaking_ok && footers_ok
+    }
+}
+
+/// Iterator adapter over commits, see [`QueryExt::matching`]
+#[derive(Debug, Clone)]
+pub struct Matching<'q, I> {
+    iter: I,
+    query: &'q CommitQuery,
+}
+
+impl<I> Iterator for Matching<'_, I>
+where
+    I: Iterator,
+    I::Item: Borrow<ConventionalCommit>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let query = self.query;
+        self.iter
+            .by_ref()
+            .find(|commit| query.matches(commit.borrow()))
+    }
+}
+
+/// Query adapters for iterators over owned or borrowed commits
+pub trait QueryExt: Iterator + Sized
+where
+    Self::Item: Borrow<ConventionalCommit>,
+{
+    /// Keep the commits matching the query
+    fn matching(self, query: &CommitQuery) -> Matching<'_, Self> {
+        Matching { iter: self, query }
+    }
+}
+
+impl<I> QueryExt for I
+where
+    I: Iterator,
+    I::Item: Borrow<ConventionalCommit>,
+{
+}
@@ -0,0 +1,153 @@
+//! Release channel hinting, deciding from the commits since the last release whether to
+//! skip the release, publish a stable release or a pre-release.
+//!
+//! # Example :
+//! ```
+//! use conventional_commit_parser::commit::CommitType;
+//! use conventional_commit_parser::monorepo::BumpLevel;
+//! use conventional_commit_parser::parse;
+//! use conventional_commit_parser::release::{
+//!     release_decision, Channel, Condition, ReleaseDecision, ReleaseRule, ReleaseRules,
+//! };
+//!
+//! let rules = ReleaseRules {
+//!     branch: Some("next".to_string()),
+//!     rules: vec![
+//!         ReleaseRule {
+//!             branch: None,
+//!             condition: Condition::OnlyTypes(vec![CommitType::Documentation, CommitType::Chore]),
+//!             channel: Channel::Skip,
+//!         },
+//!         ReleaseRule {
+//!             branch: Some("next".to_string()),
+//!             condition: Condition::AnyType(vec![CommitType::Feature]),
+//!             channel: Channel::Prerelease("beta".to_string()),
+//!         },
+//!     ],
+//!     ..Default::default()
+//! };
+//!
+//! let commits = vec![
+//!     parse("feat(parser): add release hints").unwrap(),
+//!     parse("docs: typo").unwrap(),
+//! ];
+//!
+//! assert_eq!(
+//!     release_decision(&commits, &rules),
+//!     ReleaseDecision::Prerelease {
+//!         channel: "beta".to_string(),
+//!         level: BumpLevel::Minor,
+//!     }
+//! );
+//! ```
+
+use std::borrow::Borrow;
+
+use crate::commit::{CommitType, ConventionalCommit};
+use crate::monorepo::BumpLevel;
+
+/// The commits a [`ReleaseRule`] applies to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Condition {
+    /// Every commit has one of these types
+    OnlyTypes(Vec<CommitType>),
+    /// At least one commit has one of these types
+    AnyType(Vec<CommitType>),
+    /// At least one commit is a breaking change
+    AnyBreakingChange,
+}
+
+impl Condition {
+    fn matches(&self, commits: &[&ConventionalCommit]) -> bool {
+        match self {
+            Condition::OnlyTypes(types) => commits
+                .iter()
+                .all(|commit| types.contains(&commit.commit_type)),
+            Condition::AnyType(types) => commits
+                .iter()
+                .any(|commit| types.contains(&commit.commit_type)),
+            Condition::AnyBreakingChange => commits.iter().any(|commit| commit.is_breaking_change),
+        }
+    }
+}
+
+/// Where a release is published
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Channel {
+    /// Do not release
+    Skip,
+    /// A stable release
+    #[default]
+    Stable,
+    /// A pre-release on the given channel, e.g. `alpha` or `beta`
+    Prerelease(String),
+}
+
+/// A rule mapping a set of commits to a release channel
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReleaseRule {
+    /// The branch the rule applies to, any branch when `None`
+    pub branch: Option<String>,
+    pub condition: Condition,
+    pub channel: Channel,
+}
+
+/// Rules used by [`release_decision`], the first matching rule wins
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReleaseRules {
+    /// The branch being released, only rules without a branch apply when `None`
+    pub branch: Option<String>,
+    pub rules: Vec<ReleaseRule>,
+    /// The channel used when no rule matches
+    pub default: Channel,
+}
+
+/// The outcome of [`release_decision`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReleaseDecision {
+    /// Nothing to release
+    Skip,
+    /// A stable release with the given bump
+    Release(BumpLevel),
+    /// A pre-release on the given channel
+    Prerelease { channel: String, level: BumpLevel },
+}
+
+/// Decide how to release a set of commits: the channel comes from the first matching rule,
+/// or [`ReleaseRules::default`], and the bump from the highest [`BumpLevel`] of the commits.
+/// Commits calling for no bump at all, such as an empty set, are never released.
+pub fn release_decision<I>(commits: I, rules: &ReleaseRules) -> ReleaseDecision
+where
+    I: IntoIterator,
+    I::Item: Borrow<ConventionalCommit>,
+{
+    let commits: Vec<I::Item> = commits.into_iter().collect();
+    let commits: Vec<&ConventionalCommit> = commits.iter().map(Borrow::borrow).collect();
+
+    let Some(level) = commits
+        .iter()
+        .filter_map(|commit| BumpLevel::of(commit))
+        .max()
+    else {
+        return ReleaseDecision::Skip;
+    };
+
+    let channel = rules
+        .rules
+        .iter()
+        .filter(|rule| match &rule.branch {
+            Some(branch) => rules.branch.as_ref() == Some(branch),
+            None => true,
+        })
+        .find(|rule| rule.condition.matches(&commits))
+        .map_or(&rules.default, |rule| &rule.channel);
+
+    match channel {
+        Channel::Skip => ReleaseDecision::Skip,
+        Channel::Stable => ReleaseDecision::Release(level),
+        Channel::Prerelease(channel) => ReleaseDecision::Prerelease {
+            channel: channel.clone(),
+            level,
+        },
+    }
+}
@@ -0,0 +1,224 @@
+//! Index of the scopes used in a commit history, to drive scope auto-completion and
+//! scope whitelist suggestions.
+//!
+//! # Example :
+//! ```
+//! use conventional_commit_parser::options::{ParserOptions, Validator};
+//! use conventional_commit_parser::parse;
+//! use conventional_commit_parser::scope::ScopeIndex;
+//!
+//! let history = vec![
+//!     parse("feat(parser): add scope index").unwrap(),
+//!     parse("fix(changelog): typo").unwrap(),
+//!     parse("fix(parser): handle empty scopes").unwrap(),
+//!     parse("chore(tmp): experiment").unwrap(),
+//! ];
+//!
+//! let index = ScopeIndex::build(&history);
+//!
+//! assert_eq!(index.complete("p"), vec!["parser"]);
+//!
+//! let options = ParserOptions {
+//!     scope_validator: Some(Validator::one_of(&index.whitelist(2))),
+//!     ..Default::default()
+//! };
+//! ```
+
+use std::borrow::Borrow;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+use crate::commit::ConventionalCommit;
+
+/// Usage statistics of a single scope
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ScopeUsage {
+    pub scope: String,
+    /// Number of commits using the scope
+    pub count: usize,
+    /// Position of the first commit using the scope in the scanned history
+    pub first_seen: usize,
+}
+
+/// Scopes used in a commit history, ranked by frequency, see [`ScopeIndex::build`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScopeIndex {
+    scopes: Vec<ScopeUsage>,
+    /// Commit type usage counts, in order of appearance
+    commit_types: Vec<(String, usize)>,
+    /// Number of commits using each `(commit type, scope)` pair
+    pairs: HashMap<(String, String), usize>,
+}
+
+impl ScopeIndex {
+    /// Scan a commit history, most used scopes are ranked first and scopes used as many
+    /// times keep their order of appearance. Scopes are compared exactly.
+    pub fn build<I>(commits: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Borrow<ConventionalCommit>,
+    {
+        let mut positions: HashMap<String, usize> = HashMap::new();
+        let mut scopes: Vec<ScopeUsage> = vec![];
+        let mut commit_types: Vec<(String, usize)> = vec![];
+        let mut pairs: HashMap<(String, String), usize> = HashMap::new();
+
+        for (index, commit) in commits.into_iter().enumerate() {
+            let commit = commit.borrow();
+            let commit_type = commit.commit_type.as_ref();
+            match commit_types
+                .iter_mut()
+                .find(|(used, _)| used == commit_type)
+            {
+                Some((_, count)) => *count += 1,
+                None => commit_types.push((commit_type.to_string(), 1)),
+            }
+
+            let Some(scope) = &commit.scope else {
+                continue;
+            };
+
+            *pairs
+                .entry((commit_type.to_string(), scope.clone()))
+                .or_default() += 1;
+
+            match positions.get(scope) {
+                Some(position) => scopes[*position].count += 1,
+                None => {
+                    positions.insert(scope.clone(), scopes.len());
+                    scopes.push(ScopeUsage {
+                        scope: scope.clone(),
+                        count: 1,
+                        first_seen: index,
+                    });
+                }
+            }
+        }
+
+        scopes.sort_by_key(|usage| Reverse(usage.count));
+        ScopeIndex {
+            scopes,
+            commit_types,
+            pairs,
+        }
+    }
+
+    /// Every scope, most used first
+    pub fn ranked(&self) -> &[ScopeUsage] {
+        &self.scopes
+    }
+
+    /// Returns the usage statistics of a scope
+    pub fn get(&self, scope: &str) -> Option<&ScopeUsage> {
+        self.scopes.iter().find(|usage| usage.scope == scope)
+    }
+
+    /// Scopes starting with `prefix`, compared case-insensitively, most used first
+    pub fn complete(&self, prefix: &str) -> Vec<&str> {
+        let prefix = prefix.to_lowercase();
+        self.scopes
+            .iter()
+            .filter(|usage| usage.scope.to_lowercase().starts_with(&prefix))
+            .map(|usage| usage.scope.as_str())
+            .collect()
+    }
+
+    /// Scopes used at least `min_count` times, most used first, a candidate list for
+    /// [`crate::options::Validator::one_of`]
+    pub fn whitelist(&self, min_count: usize) -> Vec<&str> {
+        self.scopes
+            .iter()
+            .filter(|usage| usage.count >= min_count)
+            .map(|usage| usage.scope.as_str())
+            .collect()
+    }
+
+    /// The historical scope closest to a rejected `scope` among the scopes `accepts` returns
+    /// `true` for, see [`crate::options::ParserOptions::suggestion_index`]. Candidates are
+    /// ranked by edit distance, then by how often they were used with `commit_type`, then by
+    /// frequency. Scopes too different from `scope` are never suggested.
+    ///
+    /// # Example :
+    /// ```
+    /// use conventional_commit_parser::parse;
+    /// use conventional_commit_parser::scope::ScopeIndex;
+    ///
+    /// let index = ScopeIndex::build(vec![
+    ///     parse("feat(api): add pagination").unwrap(),
+    ///     parse("fix(app): typo").unwrap(),
+    /// ]);
+    ///
+    /// assert_eq!(index.nearest_scope("apx", "fix", |_| true), Some("app"));
+    /// assert_eq!(index.nearest_scope("apx", "feat", |_| true), Some("api"));
+    /// assert_eq!(index.nearest_scope("cli", "feat", |_| true), None);
+    /// ```
+    pub fn nearest_scope<F>(&self, scope: &str, commit_type: &str, accepts: F) -> Option<&str>
+    where
+        F: Fn(&str) -> bool,
+    {
+        let candidates = self.scopes.iter().map(|usage| {
+            let pair = (commit_type.to_string(), usage.scope.clone());
+            let together = self.pairs.get(&pair).copied().unwrap_or_default();
+            (usage.scope.as_str(), together, usage.count)
+        });
+
+        nearest(scope, candidates, accepts)
+    }
+
+    /// The historical commit type closest to a rejected `commit_type` among the types
+    /// `accepts` returns `true` for, ranked by edit distance then by frequency
+    pub fn nearest_type<F>(&self, commit_type: &str, accepts: F) -> Option<&str>
+    where
+        F: Fn(&str) -> bool,
+    {
+        let candidates = self
+            .commit_types
+            .iter()
+            .map(|(used, count)| (used.as_str(), 0, *count));
+
+        nearest(commit_type, candidates, accepts)
+    }
+}
+
+/// The accepted candidate closest to `value`, candidates being `(value, co-occurrence, count)`
+fn nearest<'a, I, F>(value: &str, candidates: I, accepts: F) -> Option<&'a str>
+where
+    I: Iterator<Item = (&'a str, usize, usize)>,
+    F: Fn(&str) -> bool,
+{
+    let max_distance = (value.chars().count() / 3).max(1);
+    let value = value.to_lowercase();
+
+    candidates
+        .filter(|(candidate, _, _)| accepts(candidate))
+        .map(|(candidate, together, count)| {
+            let distance = edit_distance(&value, &candidate.to_lowercase());
+            (distance, Reverse(together), Reverse(count), candidate)
+        })
+        .filter(|(distance, _, _, _)| *distance <= max_distance)
+        .min_by_key(|(distance, together, count, _)| (*distance, *together, *count))
+        .map(|(_, _, _, candidate)| candidate)
+}
+
+/// Edit distance between two strings in characters, an adjacent transposition counting
+/// as a single edit (optimal string alignment distance)
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut rows: Vec<Vec<usize>> = vec![(0..=b.len()).collect()];
+
+    for i in 1..=a.len() {
+        let mut row = vec![i];
+        for j in 1..=b.len() {
+            let substitution = rows[i - 1][j - 1] + usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = substitution.min(rows[i - 1][j] + 1).min(row[j - 1] + 1);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(rows[i - 2][j - 2] + 1);
+            }
+            row.push(distance);
+        }
+        rows.push(row);
+    }
+
+    rows[a.len()][b.len()]
+}
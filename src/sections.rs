@@ -0,0 +1,131 @@
+//! Cheap splitting of a commit message into its raw sections, each section can then be
+//! parsed on demand. Tools only interested in the header can skip parsing the rest of
+//! the message.
+
+use std::ops::Range;
+
+use crate::commit::{ConventionalCommit, Footer};
+use crate::error::ParseError;
+use crate::token::footer_token_len;
+
+/// The raw sections of a commit message, see [`split_message`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RawSections<'a> {
+    /// The first line of the message
+    pub header: &'a str,
+    /// The trimmed body, `None` if empty
+    pub body: Option<&'a str>,
+    /// Every line from the first footer to the end of the message
+    pub footer_block: Option<&'a str>,
+}
+
+/// Split a commit message into its header, body and footer block with a single
+/// line scan, using the same footer detection as [`crate::parse`]
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::sections::split_message;
+///
+/// let sections = split_message("fix(parser): handle tabs\n\nA body\n\nRefs #133\nReviewed-by: Z");
+///
+/// assert_eq!(sections.header, "fix(parser): handle tabs");
+/// assert_eq!(sections.body, Some("A body"));
+/// assert_eq!(sections.footer_block, Some("Refs #133\nReviewed-by: Z"));
+///
+/// let commit = sections.parse_header().unwrap();
+/// assert_eq!(commit.scope, Some("parser".to_string()));
+/// ```
+pub fn split_message(message: &str) -> RawSections<'_> {
+    let header_end = message.find('\n').unwrap_or(message.len());
+    let header = message[..header_end].trim_end_matches('\r');
+    let rest_start = (header_end + 1).min(message.len());
+
+    let footer_block = footer_block_range(message, rest_start);
+    let body_end = footer_block
+        .as_ref()
+        .map_or(message.len(), |range| range.start);
+
+    let body = Some(message[rest_start..body_end].trim()).filter(|body| !body.is_empty());
+    let footer_block = footer_block.map(|range| &message[range]);
+
+    RawSections {
+        header,
+        body,
+        footer_block,
+    }
+}
+
+impl RawSections<'_> {
+    /// Parse the header, see [`crate::parse_summary`]
+    pub fn parse_header(&self) -> Result<ConventionalCommit, ParseError> {
+        crate::parse_summary(self.header)
+    }
+
+    /// Parse the footer block, see [`crate::parse_footers`]
+    pub fn parse_footers(&self) -> Result<Vec<Footer>, ParseError> {
+        match self.footer_block {
+            Some(footers) => crate::parse_footers(footers),
+            None => Ok(vec![]),
+        }
+    }
+
+    /// Parse every section into a complete commit
+    pub fn parse(&self) -> Result<ConventionalCommit, ParseError> {
+        let mut commit = self.parse_header()?;
+        commit.body = self.body.map(str::to_string);
+        commit.footers = self.parse_footers()?;
+        commit.is_breaking_change |= commit.footers.iter().any(Footer::is_breaking_change);
+        commit.extract_cherry_pick();
+        Ok(commit)
+    }
+}
+
+/// Locate the footer block of a commit message without parsing it, the returned byte range
+/// spans from the first footer token to the end of the message, trailing whitespace excluded.
+/// Footers are detected the same way as [`crate::parse`] does.
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::sections::find_footer_block;
+///
+/// let message = "fix: typo\n\nA body\n\nReviewed-by: Z\n";
+/// let range = find_footer_block(message).unwrap();
+///
+/// assert_eq!(&message[range.clone()], "Reviewed-by: Z");
+/// assert_eq!(message[..range.start].trim_end(), "fix: typo\n\nA body");
+/// ```
+pub fn find_footer_block(message: &str) -> Option<Range<usize>> {
+    let header_end = message.find('\n')?;
+    footer_block_range(message, header_end + 1)
+}
+
+/// Byte range of the footer block, searched from offset `from`
+fn footer_block_range(message: &str, from: usize) -> Option<Range<usize>> {
+    let end = message.trim_end().len();
+    let mut offset = from;
+    for line in message[from..].split_inclusive('\n') {
+        let indent = line.len() - line.trim_start_matches(' ').len();
+        if is_footer_start(&line[indent..]) {
+            return Some(offset + indent..end.max(offset + indent));
+        }
+
+        offset += line.len();
+    }
+
+    None
+}
+
+/// Returns true if a line starts with a footer token followed by a token separator
+pub(crate) fn is_footer_start(line: &str) -> bool {
+    let token_end = footer_token_len(line);
+
+    if token_end == 0 {
+        return false;
+    }
+
+    let separator = &line[token_end..];
+    separator.starts_with(": ")
+        || separator.starts_with(" #")
+        || separator.starts_with(":\n")
+        || separator.starts_with(":\r")
+}
@@ -0,0 +1,122 @@
+//! Streaming statistics over a commit history, aggregating counts without storing the
+//! commits, for histories too large to be kept in memory.
+//!
+//! # Example :
+//! ```
+//! use conventional_commit_parser::parse;
+//! use conventional_commit_parser::stats::StatsCollector;
+//!
+//! let messages = ["feat(parser): add stats", "fix: typo", "feat(parser)!: drop summaries"];
+//!
+//! let mut stats = StatsCollector::default();
+//! stats.extend(messages.iter().filter_map(|message| parse(message).ok()));
+//!
+//! assert_eq!(stats.commits, 3);
+//! assert_eq!(stats.commit_types["feat"], 2);
+//! assert_eq!(stats.scopes["parser"], 2);
+//! assert_eq!(stats.breaking_changes, 1);
+//! ```
+
+use std::collections::BTreeMap;
+use std::iter::FromIterator;
+
+use crate::commit::ConventionalCommit;
+use crate::value::Identity;
+
+/// Incremental commit counts, fed with [`Extend`] or [`StatsCollector::push`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StatsCollector {
+    /// Number of aggregated commits
+    pub commits: usize,
+    /// Number of breaking changes
+    pub breaking_changes: usize,
+    /// Number of commits without a scope
+    pub unscoped: usize,
+    /// Number of commits of each commit type
+    pub commit_types: BTreeMap<String, usize>,
+    /// Number of commits using each scope, compared exactly
+    pub scopes: BTreeMap<String, usize>,
+    /// Number of commits each author signed off or co-authored, read from the
+    /// `Signed-off-by` and `Co-authored-by` footers (case-insensitive). Identities are keyed
+    /// as `Name <email>` with a lower case email, other values as written.
+    pub authors: BTreeMap<String, usize>,
+}
+
+impl StatsCollector {
+    /// Aggregate a single commit
+    pub fn push(&mut self, commit: &ConventionalCommit) {
+        self.commits += 1;
+        if commit.is_breaking_change {
+            self.breaking_changes += 1;
+        }
+
+        *self
+            .commit_types
+            .entry(commit.commit_type.as_ref().to_string())
+            .or_default() += 1;
+
+        match &commit.scope {
+            Some(scope) => *self.scopes.entry(scope.clone()).or_default() += 1,
+            None => self.unscoped += 1,
+        }
+
+        let mut authors: Vec<String> = commit
+            .footers
+            .iter()
+            .filter(|footer| {
+                footer.token.eq_ignore_ascii_case("Signed-off-by")
+                    || footer.token.eq_ignore_ascii_case("Co-authored-by")
+            })
+            .map(|footer| match footer.value_as::<Identity>() {
+                Ok(identity) => format!("{} <{}>", identity.name, identity.email.to_lowercase()),
+                Err(_) => footer.content.trim().to_string(),
+            })
+            .collect();
+
+        // An author both signing off and co-authoring a commit is counted once
+        authors.sort();
+        authors.dedup();
+        for author in authors {
+            *self.authors.entry(author).or_default() += 1;
+        }
+    }
+
+    /// Add the counts of another collector, e.g. one aggregating another part of the history
+    /// on another thread
+    pub fn merge(&mut self, other: &StatsCollector) {
+        self.commits += other.commits;
+        self.breaking_changes += other.breaking_changes;
+        self.unscoped += other.unscoped;
+
+        let counts = [
+            (&mut self.commit_types, &other.commit_types),
+            (&mut self.scopes, &other.scopes),
+            (&mut self.authors, &other.authors),
+        ];
+        for (counts, other) in counts {
+            for (key, count) in other {
+                *counts.entry(key.clone()).or_default() += count;
+            }
+        }
+    }
+}
+
+impl Extend<ConventionalCommit> for StatsCollector {
+    fn extend<I: IntoIterator<Item = ConventionalCommit>>(&mut self, commits: I) {
+        commits.into_iter().for_each(|commit| self.push(&commit));
+    }
+}
+
+impl<'a> Extend<&'a ConventionalCommit> for StatsCollector {
+    fn extend<I: IntoIterator<Item = &'a ConventionalCommit>>(&mut self, commits: I) {
+        commits.into_iter().for_each(|commit| self.push(commit));
+    }
+}
+
+impl FromIterator<ConventionalCommit> for StatsCollector {
+    fn from_iter<I: IntoIterator<Item = ConventionalCommit>>(commits: I) -> Self {
+        let mut stats = StatsCollector::default();
+        stats.extend(commits);
+        stats
+    }
+}
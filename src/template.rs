@@ -0,0 +1,184 @@
+//! Validation of partially filled commit message templates, for `prepare-commit-msg` hooks
+//! and editor integrations guiding the user while the message is written.
+
+use crate::commit::ConventionalCommit;
+use crate::parse;
+use crate::token::{tokenize, Token};
+
+/// The line below which git ignores the commit message, see `git commit --cleanup=scissors`
+const SCISSORS: &str = "------------------------ >8 ------------------------";
+
+/// Parts a commit template must fill, see [`check_template`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemplateOptions {
+    /// Lines starting with this character are comment scaffolding, defaults to `#`
+    /// as git's `core.commentChar`
+    pub comment_char: char,
+    /// Report a missing scope
+    pub require_scope: bool,
+    /// Report a missing body
+    pub require_body: bool,
+    /// Footer tokens that must be present, matched case-insensitively
+    pub required_footers: Vec<String>,
+}
+
+impl Default for TemplateOptions {
+    fn default() -> Self {
+        TemplateOptions {
+            comment_char: '#',
+            require_scope: false,
+            require_body: false,
+            required_footers: vec![],
+        }
+    }
+}
+
+/// A part of the commit message the template does not fill yet
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TemplatePart {
+    /// The header does not start with a `<type>[(scope)][!]:` prefix
+    Type,
+    /// The header has no scope
+    Scope,
+    /// The header has no description after the type
+    Summary,
+    /// The message has no body
+    Body,
+    /// A required footer is missing, holding its token
+    Footer(String),
+}
+
+/// The state of a commit template, see [`check_template`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemplateStatus {
+    /// The message without comment lines and without anything below the scissors line
+    pub message: String,
+    /// Parts still to be filled, in message order
+    pub missing: Vec<TemplatePart>,
+    /// The parsed commit, `None` while the message is not a valid conventional commit
+    pub commit: Option<ConventionalCommit>,
+}
+
+impl TemplateStatus {
+    /// Return true if every required part is filled and the message parses
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty() && self.commit.is_some()
+    }
+}
+
+/// Remove comment lines from a commit template, and everything below git's scissors line
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::template::strip_comments;
+///
+/// let template = "fix: \n# Please enter the commit message\n\n# Refs: <issue>";
+///
+/// assert_eq!(strip_comments(template, '#'), "fix: \n\n");
+/// ```
+pub fn strip_comments(template: &str, comment_char: char) -> String {
+    let mut message = String::with_capacity(template.len());
+    for line in template.lines() {
+        if let Some(comment) = line.strip_prefix(comment_char) {
+            if comment.trim() == SCISSORS {
+                break;
+            }
+            continue;
+        }
+
+        message.push_str(line);
+        message.push('\n');
+    }
+
+    message
+}
+
+/// Check a partially filled commit template and report the parts still missing.
+/// Comment lines are ignored, so the scaffolding of a `prepare-commit-msg` template does not
+/// need to be removed first.
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::template::{check_template, TemplateOptions, TemplatePart};
+///
+/// let options = TemplateOptions {
+///     require_scope: true,
+///     required_footers: vec!["Refs".to_string()],
+///     ..Default::default()
+/// };
+///
+/// let template = "feat: \n# <type>(<scope>): <summary>\n\n# Refs: <issue>";
+/// let status = check_template(template, &options);
+///
+/// assert_eq!(status.missing, vec![
+///     TemplatePart::Scope,
+///     TemplatePart::Summary,
+///     TemplatePart::Footer("Refs".to_string()),
+/// ]);
+/// assert!(!status.is_complete());
+///
+/// let status = check_template("feat(parser): add templates\n\nRefs: #12", &options);
+/// assert!(status.is_complete());
+/// ```
+pub fn check_template(template: &str, options: &TemplateOptions) -> TemplateStatus {
+    let message = strip_comments(template, options.comment_char);
+    let trimmed = message.trim_start_matches(['\n', '\r']);
+    let mut missing = vec![];
+
+    let header = trimmed.lines().next().unwrap_or_default();
+    let (has_type, has_scope, has_summary) = header_parts(header);
+    if !has_type {
+        missing.push(TemplatePart::Type);
+    }
+    if options.require_scope && !has_scope {
+        missing.push(TemplatePart::Scope);
+    }
+    if !has_summary {
+        missing.push(TemplatePart::Summary);
+    }
+
+    let tokens: Vec<Token> = tokenize(trimmed).collect();
+    if options.require_body && !tokens.iter().any(|t| matches!(t, Token::BodyLine(_))) {
+        missing.push(TemplatePart::Body);
+    }
+
+    for required in &options.required_footers {
+        let present = tokens.iter().any(|t| {
+            matches!(t, Token::FooterLine { token, value, .. }
+                if token.eq_ignore_ascii_case(required) && !value.trim().is_empty())
+        });
+        if !present {
+            missing.push(TemplatePart::Footer(required.clone()));
+        }
+    }
+
+    let commit = parse(trimmed).ok();
+
+    TemplateStatus {
+        message,
+        missing,
+        commit,
+    }
+}
+
+/// Whether a partially typed header has a type, a scope and a summary
+fn header_parts(header: &str) -> (bool, bool, bool) {
+    let type_end = header
+        .find(|c: char| !c.is_ascii_alphabetic())
+        .unwrap_or(header.len());
+    let mut rest = &header[type_end..];
+
+    let mut has_scope = false;
+    if let Some((scope, after)) = rest.strip_prefix('(').and_then(|s| s.split_once(')')) {
+        has_scope = !scope.trim().is_empty();
+        rest = after;
+    }
+    rest = rest.strip_prefix('!').unwrap_or(rest);
+
+    match rest.strip_prefix(':') {
+        Some(summary) => (type_end > 0, has_scope, !summary.trim().is_empty()),
+        // A header without `:` is either a bare type or a summary without a type
+        None if rest.is_empty() => (type_end > 0, has_scope, false),
+        None => (false, false, !header.trim().is_empty()),
+    }
+}
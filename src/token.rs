@@ -0,0 +1,184 @@
+//! A line based lexer splitting a commit message into [`Token`]s, for downstream parsers
+//! needing slightly different semantics than [`crate::parse`].
+//!
+//! The lexer never fails and never allocates: tokens borrow the message and malformed
+//! input is reported as [`Token::Header`] or [`Token::BodyLine`].
+
+use crate::commit::Separator;
+
+/// A lexical element of a commit message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Token<'a> {
+    /// The commit type of a conventional header
+    Type(&'a str),
+    /// The scope of a conventional header, without its parenthesis
+    Scope(&'a str),
+    /// The `!` breaking change mark of a conventional header
+    BreakingMark,
+    /// The description of a conventional header, following `: `
+    Summary(&'a str),
+    /// A header not following the `<type>[(scope)][!]: <summary>` layout
+    Header(&'a str),
+    /// An empty or whitespace only line
+    BlankLine,
+    /// A line that is neither a footer nor the continuation of a footer
+    BodyLine(&'a str),
+    /// A line starting with a footer token and a token separator
+    FooterLine {
+        token: &'a str,
+        separator: Separator,
+        value: &'a str,
+    },
+    /// A line directly following a footer line or another continuation line, without
+    /// a blank line in between
+    ContinuationLine(&'a str),
+}
+
+/// Split a commit message into tokens, one per line after the header. Whether a
+/// paragraph following a blank line still belongs to a footer value is left to the caller.
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::commit::Separator;
+/// use conventional_commit_parser::token::{tokenize, Token};
+///
+/// let tokens: Vec<Token> = tokenize("fix(lexer)!: split lines\n\nA body\n\nRefs #12\nclosed in review").collect();
+///
+/// assert_eq!(tokens, vec![
+///     Token::Type("fix"),
+///     Token::Scope("lexer"),
+///     Token::BreakingMark,
+///     Token::Summary("split lines"),
+///     Token::BlankLine,
+///     Token::BodyLine("A body"),
+///     Token::BlankLine,
+///     Token::FooterLine { token: "Refs", separator: Separator::Hash, value: "12" },
+///     Token::ContinuationLine("closed in review"),
+/// ]);
+/// ```
+pub fn tokenize(message: &str) -> Tokens<'_> {
+    let mut lines = message.lines();
+    let header = lines.next().map(header_tokens).unwrap_or_default();
+
+    Tokens {
+        header,
+        header_index: 0,
+        lines,
+        in_footer: false,
+    }
+}
+
+/// Iterator over the [`Token`]s of a commit message, see [`tokenize`]
+#[derive(Debug, Clone)]
+pub struct Tokens<'a> {
+    header: [Option<Token<'a>>; 4],
+    header_index: usize,
+    lines: std::str::Lines<'a>,
+    in_footer: bool,
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.header_index < self.header.len() {
+            let token = self.header[self.header_index].take();
+            self.header_index += 1;
+            if token.is_some() {
+                return token;
+            }
+        }
+
+        let line = self.lines.next()?;
+        let token = if line.trim().is_empty() {
+            self.in_footer = false;
+            Token::BlankLine
+        } else if let Some(footer) = footer_line(line) {
+            self.in_footer = true;
+            footer
+        } else if self.in_footer {
+            Token::ContinuationLine(line)
+        } else {
+            Token::BodyLine(line)
+        };
+
+        Some(token)
+    }
+}
+
+/// Split a `<type>[(scope)][!]: <summary>` header, falling back to [`Token::Header`]
+fn header_tokens(header: &str) -> [Option<Token<'_>>; 4] {
+    let invalid = [Some(Token::Header(header)), None, None, None];
+
+    let type_end = header
+        .find(|c: char| !c.is_ascii_alphabetic())
+        .unwrap_or(header.len());
+    if type_end == 0 {
+        return invalid;
+    }
+
+    let mut rest = &header[type_end..];
+    let mut scope = None;
+    if let Some(scoped) = rest.strip_prefix('(') {
+        match scoped.split_once(')') {
+            Some((content, after))
+                if !content.is_empty() && !content.contains(['(', ' ', '\t']) =>
+            {
+                scope = Some(Token::Scope(content));
+                rest = after;
+            }
+            _ => return invalid,
+        }
+    }
+
+    let mark = rest.strip_prefix('!').map(|after| {
+        rest = after;
+        Token::BreakingMark
+    });
+
+    match rest.strip_prefix(": ").map(str::trim_start) {
+        Some(summary) if !summary.is_empty() => [
+            Some(Token::Type(&header[..type_end])),
+            scope,
+            mark,
+            Some(Token::Summary(summary)),
+        ],
+        _ => invalid,
+    }
+}
+
+/// Length of the footer token a line starts with, `0` if it does not start with a token
+pub(crate) fn footer_token_len(line: &str) -> usize {
+    ["BREAKING CHANGE", "BREAKING-CHANGE"]
+        .iter()
+        .find(|token| line.starts_with(*token))
+        .map(|token| token.len())
+        .unwrap_or_else(|| {
+            line.find(|c: char| !(c.is_ascii_alphanumeric() || c == '-'))
+                .unwrap_or(line.len())
+        })
+}
+
+fn footer_line(line: &str) -> Option<Token<'_>> {
+    let token_end = footer_token_len(line);
+    if token_end == 0 {
+        return None;
+    }
+
+    let (token, rest) = line.split_at(token_end);
+    let (separator, value) = if let Some(value) = rest.strip_prefix(": ") {
+        (Separator::Colon, value)
+    } else if let Some(value) = rest.strip_prefix(" #") {
+        (Separator::Hash, value)
+    } else if rest == ":" {
+        (Separator::ColonWithNewLine, "")
+    } else {
+        return None;
+    };
+
+    Some(Token::FooterLine {
+        token,
+        separator,
+        value,
+    })
+}
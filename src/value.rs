@@ -0,0 +1,455 @@
+//! Typed footer values, see [`crate::commit::Footer::value_as`].
+//!
+//! Every type in this module implements [`FromStr`], any other type implementing it can be
+//! used as a footer value as well.
+
+use std::fmt;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+use crate::error::ValueError;
+
+/// A [semantic version](https://semver.org), an optional leading `v` is accepted.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Version {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+    /// Pre-release identifiers, `alpha.1` in `1.0.0-alpha.1`
+    pub pre: Option<String>,
+    /// Build metadata, `build.5` in `1.0.0+build.5`
+    pub build: Option<String>,
+}
+
+/// A RFC 3339 `full-date`, `2025-01-01`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Date {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+}
+
+/// A RFC 3339 `date-time`, `2025-01-01T10:00:00+02:00`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DateTime {
+    pub date: Date,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+    /// Fractional seconds in nanoseconds
+    pub nanosecond: u32,
+    /// Offset from UTC in minutes
+    pub offset_minutes: i16,
+}
+
+/// An absolute URL of the form `scheme://host[:port][/path][?query][#fragment]`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Url {
+    pub scheme: String,
+    pub host: String,
+    pub port: Option<u16>,
+    /// Everything after the authority, including the query and fragment
+    pub path: String,
+}
+
+/// A commit hash, abbreviated or full, normalized to lower case. Both SHA-1 and SHA-256
+/// object names are accepted.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Sha(String);
+
+/// The shortest abbreviated commit hash git prints, see `core.abbrev`
+pub const SHA_MIN_LEN: usize = 4;
+
+const SHA1_LEN: usize = 40;
+const SHA256_LEN: usize = 64;
+
+/// Return true if `sha` is a plausible abbreviated or full commit hash: 4 to 64 hex digits
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::value::is_valid_sha;
+///
+/// assert!(is_valid_sha("ea3237f"));
+/// assert!(!is_valid_sha("HEAD~1"));
+/// assert!(!is_valid_sha("abc"));
+/// ```
+pub fn is_valid_sha(sha: &str) -> bool {
+    (SHA_MIN_LEN..=SHA256_LEN).contains(&sha.len()) && sha.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Return true if `sha` is a full SHA-1 (40 hex digits) or SHA-256 (64 hex digits) commit hash
+pub fn is_full_sha(sha: &str) -> bool {
+    (sha.len() == SHA1_LEN || sha.len() == SHA256_LEN) && is_valid_sha(sha)
+}
+
+/// Trim and lower case a commit hash, returning `None` if it is not a valid hash
+///
+/// # Example :
+/// ```
+/// use conventional_commit_parser::value::normalize_sha;
+///
+/// assert_eq!(normalize_sha(" EA3237F\n"), Some("ea3237f".to_string()));
+/// assert_eq!(normalize_sha("main"), None);
+/// ```
+pub fn normalize_sha(sha: &str) -> Option<String> {
+    let sha = sha.trim();
+    is_valid_sha(sha).then(|| sha.to_ascii_lowercase())
+}
+
+/// A `Name <email>` pair as found in `Signed-off-by` or `Co-authored-by` trailers
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Identity {
+    pub name: String,
+    pub email: String,
+}
+
+impl FromStr for Version {
+    type Err = ValueError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let err = || ValueError::InvalidVersion(value.to_string());
+        let version = value.trim();
+        let version = version.strip_prefix('v').unwrap_or(version);
+
+        let (version, build) = match version.split_once('+') {
+            Some((version, build)) => (version, Some(build)),
+            None => (version, None),
+        };
+
+        let (version, pre) = match version.split_once('-') {
+            Some((version, pre)) => (version, Some(pre)),
+            None => (version, None),
+        };
+
+        let identifiers_ok = |identifiers: &str| {
+            !identifiers.is_empty()
+                && identifiers.split('.').all(|identifier| {
+                    !identifier.is_empty()
+                        && identifier
+                            .chars()
+                            .all(|c| c.is_ascii_alphanumeric() || c == '-')
+                })
+        };
+
+        if pre.is_some_and(|pre| !identifiers_ok(pre))
+            || build.is_some_and(|build| !identifiers_ok(build))
+        {
+            return Err(err());
+        }
+
+        let mut numbers = version.split('.').map(|number| {
+            if number.is_empty() || (number.len() > 1 && number.starts_with('0')) {
+                None
+            } else {
+                number.parse::<u64>().ok()
+            }
+        });
+
+        match (
+            numbers.next(),
+            numbers.next(),
+            numbers.next(),
+            numbers.next(),
+        ) {
+            (Some(Some(major)), Some(Some(minor)), Some(Some(patch)), None) => Ok(Version {
+                major,
+                minor,
+                patch,
+                pre: pre.map(str::to_string),
+                build: build.map(str::to_string),
+            }),
+            _ => Err(err()),
+        }
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+        if let Some(pre) = &self.pre {
+            write!(f, "-{}", pre)?;
+        }
+        if let Some(build) = &self.build {
+            write!(f, "+{}", build)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_digits<T: FromStr>(digits: &str, len: usize) -> Option<T> {
+    if digits.len() == len && digits.bytes().all(|b| b.is_ascii_digit()) {
+        digits.parse().ok()
+    } else {
+        None
+    }
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400)) => {
+            29
+        }
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl FromStr for Date {
+    type Err = ValueError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let err = || ValueError::InvalidDate(value.to_string());
+        let mut parts = value.trim().split('-');
+
+        let year = parts.next().and_then(|year| parse_digits(year, 4));
+        let month = parts.next().and_then(|month| parse_digits(month, 2));
+        let day = parts.next().and_then(|day| parse_digits(day, 2));
+
+        match (year, month, day, parts.next()) {
+            (Some(year), Some(month), Some(day), None)
+                if (1..=12).contains(&month) && day >= 1 && day <= days_in_month(year, month) =>
+            {
+                Ok(Date { year, month, day })
+            }
+            _ => Err(err()),
+        }
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl FromStr for DateTime {
+    type Err = ValueError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let err = || ValueError::InvalidDate(value.to_string());
+        let trimmed = value.trim();
+
+        let separator = trimmed.find(['T', 't', ' ']).ok_or_else(err)?;
+        let date = trimmed[..separator].parse::<Date>().map_err(|_| err())?;
+        let time = &trimmed[separator + 1..];
+
+        let (time, offset_minutes) = if let Some(time) = time.strip_suffix(['Z', 'z']) {
+            (time, 0)
+        } else {
+            let offset_start = time.rfind(['+', '-']).ok_or_else(err)?;
+            let (time, offset) = time.split_at(offset_start);
+            let sign = if offset.starts_with('-') { -1 } else { 1 };
+            let (hours, minutes) = offset[1..].split_once(':').ok_or_else(err)?;
+            let hours: i16 = parse_digits(hours, 2).ok_or_else(err)?;
+            let minutes: i16 = parse_digits(minutes, 2).ok_or_else(err)?;
+            if hours > 23 || minutes > 59 {
+                return Err(err());
+            }
+            (time, sign * (hours * 60 + minutes))
+        };
+
+        let (time, fraction) = match time.split_once('.') {
+            Some((time, fraction)) => (time, Some(fraction)),
+            None => (time, None),
+        };
+
+        let mut parts = time.split(':');
+        let hour: u8 = parts
+            .next()
+            .and_then(|h| parse_digits(h, 2))
+            .ok_or_else(err)?;
+        let minute: u8 = parts
+            .next()
+            .and_then(|m| parse_digits(m, 2))
+            .ok_or_else(err)?;
+        let second: u8 = parts
+            .next()
+            .and_then(|s| parse_digits(s, 2))
+            .ok_or_else(err)?;
+
+        // Leap seconds are allowed by RFC 3339
+        if parts.next().is_some() || hour > 23 || minute > 59 || second > 60 {
+            return Err(err());
+        }
+
+        let nanosecond = match fraction {
+            Some(fraction)
+                if !fraction.is_empty() && fraction.bytes().all(|b| b.is_ascii_digit()) =>
+            {
+                let digits: String = fraction
+                    .chars()
+                    .chain("000000000".chars())
+                    .take(9)
+                    .collect();
+                digits.parse().map_err(|_| err())?
+            }
+            Some(_) => return Err(err()),
+            None => 0,
+        };
+
+        Ok(DateTime {
+            date,
+            hour,
+            minute,
+            second,
+            nanosecond,
+            offset_minutes,
+        })
+    }
+}
+
+impl fmt::Display for DateTime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}T{:02}:{:02}:{:02}",
+            self.date, self.hour, self.minute, self.second
+        )?;
+
+        if self.nanosecond > 0 {
+            let fraction = format!("{:09}", self.nanosecond);
+            write!(f, ".{}", fraction.trim_end_matches('0'))?;
+        }
+
+        if self.offset_minutes == 0 {
+            write!(f, "Z")
+        } else {
+            let sign = if self.offset_minutes < 0 { '-' } else { '+' };
+            let offset = self.offset_minutes.abs();
+            write!(f, "{}{:02}:{:02}", sign, offset / 60, offset % 60)
+        }
+    }
+}
+
+impl FromStr for Url {
+    type Err = ValueError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let err = || ValueError::InvalidUrl(value.to_string());
+        let url = value.trim();
+
+        let (scheme, rest) = url.split_once("://").ok_or_else(err)?;
+        let scheme_ok = scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+            && scheme
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-' || c == '.');
+
+        if !scheme_ok || rest.contains(char::is_whitespace) {
+            return Err(err());
+        }
+
+        let authority_end = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+        let (authority, path) = rest.split_at(authority_end);
+        let host_and_port = authority.rsplit('@').next().unwrap_or(authority);
+
+        // A colon inside brackets belongs to an IPv6 host, not to the port
+        let (host, port) = match host_and_port.rsplit_once(':') {
+            Some((host, port)) if !port.contains(']') => {
+                (host, Some(port.parse::<u16>().map_err(|_| err())?))
+            }
+            _ => (host_and_port, None),
+        };
+
+        if host.is_empty() {
+            return Err(err());
+        }
+
+        Ok(Url {
+            scheme: scheme.to_ascii_lowercase(),
+            host: host.to_string(),
+            port,
+            path: path.to_string(),
+        })
+    }
+}
+
+impl fmt::Display for Url {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}://{}", self.scheme, self.host)?;
+        if let Some(port) = self.port {
+            write!(f, ":{}", port)?;
+        }
+        write!(f, "{}", self.path)
+    }
+}
+
+impl Sha {
+    /// Returns the normalized hash
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Return true if the hash is not abbreviated, see [`is_full_sha`]
+    pub fn is_full(&self) -> bool {
+        is_full_sha(&self.0)
+    }
+
+    /// Return true if `prefix` is an abbreviation of this hash, compared case-insensitively
+    ///
+    /// # Example :
+    /// ```
+    /// use conventional_commit_parser::value::Sha;
+    ///
+    /// let sha: Sha = "ea3237fd0e9a1c2b3d4e5f60718293a4b5c6d7e8".parse().unwrap();
+    ///
+    /// assert!(sha.is_full());
+    /// assert!(sha.matches("EA3237F"));
+    /// assert!(!sha.matches("bdca127"));
+    /// ```
+    pub fn matches(&self, prefix: &str) -> bool {
+        normalize_sha(prefix).is_some_and(|prefix| self.0.starts_with(&prefix))
+    }
+}
+
+impl FromStr for Sha {
+    type Err = ValueError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        normalize_sha(value)
+            .map(Sha)
+            .ok_or_else(|| ValueError::InvalidSha(value.to_string()))
+    }
+}
+
+impl fmt::Display for Sha {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for Identity {
+    type Err = ValueError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let err = || ValueError::InvalidIdentity(value.to_string());
+        let identity = value.trim();
+
+        let (name, email) = identity
+            .strip_suffix('>')
+            .and_then(|identity| identity.rsplit_once('<'))
+            .ok_or_else(err)?;
+
+        let name = name.trim();
+        let email = email.trim();
+        let email_ok = email
+            .split_once('@')
+            .is_some_and(|(local, domain)| !local.is_empty() && !domain.is_empty())
+            && !email.contains(char::is_whitespace);
+
+        if name.is_empty() || !email_ok {
+            return Err(err());
+        }
+
+        Ok(Identity {
+            name: name.to_string(),
+            email: email.to_string(),
+        })
+    }
+}
+
+impl fmt::Display for Identity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} <{}>", self.name, self.email)
+    }
+}
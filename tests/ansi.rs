@@ -0,0 +1,43 @@
+#![cfg(feature = "ansi")]
+
+use conventional_commit_parser::parse;
+use indoc::indoc;
+use speculoos::prelude::*;
+
+#[test]
+fn should_render_colors_unless_no_color_is_set() {
+    // Arrange
+    let commit = parse(indoc!(
+        "feat(api): add pagination
+
+        Pages are 50 items long.
+
+        BREAKING CHANGE: lists are paginated
+        Refs: #12"
+    ))
+    .unwrap();
+
+    // Act
+    std::env::remove_var("NO_COLOR");
+    let colored = commit.render_ansi();
+    std::env::set_var("NO_COLOR", "1");
+    let plain = commit.render_ansi();
+    std::env::set_var("NO_COLOR", "");
+    let empty_no_color = commit.render_ansi();
+    std::env::remove_var("NO_COLOR");
+
+    // Assert
+    assert_that(&colored).is_equal_to(
+        indoc!(
+            "\x1b[1;33mfeat\x1b[0m(\x1b[36mapi\x1b[0m): add pagination
+
+            Pages are 50 items long.
+
+            \x1b[1;31mBREAKING CHANGE\x1b[0m: lists are paginated
+            \x1b[1;34mRefs\x1b[0m: #12"
+        )
+        .to_string(),
+    );
+    assert_that(&plain).is_equal_to(commit.to_string());
+    assert_that(&empty_no_color).is_equal_to(&colored);
+}
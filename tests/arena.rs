@@ -0,0 +1,117 @@
+#![cfg(feature = "bumpalo")]
+
+use bumpalo::Bump;
+use std::borrow::Cow;
+
+use conventional_commit_parser::arena::{parse_all_in, CowCommit, OwnedSections};
+use conventional_commit_parser::commit::ConventionalCommit;
+use conventional_commit_parser::parse;
+use indoc::indoc;
+use speculoos::prelude::*;
+
+#[test]
+fn should_parse_batch_like_parse() {
+    // Arrange
+    let history = vec![
+        "feat(parser)!: add arenas".to_string(),
+        "Fix: typo\n\nin the readme".to_string(),
+        indoc!(
+            "feat(api): paginate lists
+
+            Lists hold at most 50 items.
+
+            BREAKING CHANGE: clients must follow next links
+            Refs #12
+            (cherry picked from commit 1c6c84a8f2ef4e2e0a1e7b2e8f1c9b0a1c2d3e4f)"
+        )
+        .to_string(),
+        indoc!(
+            "fix: handle tabs
+
+            A body line
+            (cherry picked from commit 1c6c84a)"
+        )
+        .to_string(),
+        "".to_string(),
+        "not conventional".to_string(),
+    ];
+    let arena = Bump::new();
+
+    // Act
+    let commits = parse_all_in(&arena, &history);
+
+    // Assert
+    assert_that(&commits.len()).is_equal_to(history.len());
+    for (message, commit) in history.iter().zip(commits.iter()) {
+        match (commit, parse(message)) {
+            (Ok(commit), Ok(expected)) => {
+                assert!(
+                    ConventionalCommit::from(*commit).raw_eq(&expected),
+                    "{}",
+                    message
+                )
+            }
+            (Err(error), Err(expected)) => assert_that(&error.kind).is_equal_to(expected.kind),
+            (commit, expected) => panic!("{:?} != {:?}", commit, expected),
+        }
+    }
+}
+
+#[test]
+fn commits_should_outlive_parsed_messages() {
+    // Arrange
+    let arena = Bump::new();
+    let messages = vec!["fix(parser): handle tabs\n\nRefs: #12".to_string()];
+
+    // Act
+    let commits = parse_all_in(&arena, messages);
+
+    // Assert
+    let commit = commits[0].as_ref().unwrap();
+    assert_that(&commit.summary).is_equal_to("handle tabs");
+    assert_that(&commit.footers[0].content).is_equal_to("#12");
+}
+
+#[test]
+fn should_only_copy_requested_sections() {
+    // Arrange
+    let arena: &'static Bump = Box::leak(Box::new(Bump::new()));
+    let commits = parse_all_in(
+        arena,
+        ["feat: add arenas\n\nA long body\n\nBREAKING CHANGE: drop parse\nRefs: #12"],
+    );
+    let sections = OwnedSections {
+        body: false,
+        footers: true,
+    };
+
+    // Act
+    let commit: CowCommit<'static> = commits[0].as_ref().unwrap().to_cow(&sections);
+
+    // Assert
+    assert_that(&matches!(commit.body, Some(Cow::Borrowed("A long body")))).is_true();
+    assert_that(&matches!(commit.footers[1].content, Cow::Owned(_))).is_true();
+    assert_that(&commit.is_breaking_change).is_true();
+    assert_that(&commit.summary).is_equal_to("add arenas".to_string());
+}
+
+#[test]
+fn should_copy_borrowed_sections_on_demand() {
+    // Arrange
+    let message = "feat: add arenas\n\nA long body\n\nBREAKING CHANGE: drop parse\nRefs: #12";
+    let arena = Bump::new();
+    let commits = parse_all_in(&arena, [message]);
+    let sections = OwnedSections {
+        body: false,
+        footers: false,
+    };
+
+    // Act
+    let commit = commits[0].as_ref().unwrap().to_cow(&sections).into_owned();
+    drop(commits);
+    drop(arena);
+
+    // Assert
+    assert_that(&matches!(commit.body, Some(Cow::Owned(_)))).is_true();
+    assert_that(&ConventionalCommit::from(commit)).is_equal_to(parse(message).unwrap());
+}
@@ -18,3 +18,43 @@ fn parse_body_only() {
     // Assert
     assert_that(&parsed).is_ok().is_some().is_equal_to(body);
 }
+
+#[test]
+fn should_extract_cherry_pick_line_from_body() {
+    // Arrange
+    let commit_message = indoc!(
+        "fix(parser): handle tabs
+
+        A body
+
+        (cherry picked from commit 8d1f0c2b6e1a0c9f3e5d7b4a2c1e0f9d8b7a6c5e)"
+    );
+
+    // Act
+    let parsed = conventional_commit_parser::parse(commit_message).unwrap();
+
+    // Assert
+    assert_that(&parsed.body).is_equal_to(Some("A body".to_string()));
+    assert_that(&parsed.cherry_picked_from)
+        .is_equal_to(Some("8d1f0c2b6e1a0c9f3e5d7b4a2c1e0f9d8b7a6c5e".to_string()));
+    assert_that(&parsed.to_string()).is_equal_to(commit_message.to_string());
+}
+
+#[test]
+fn should_extract_cherry_pick_line_after_footers() {
+    // Arrange
+    let commit_message = indoc!(
+        "fix(parser): handle tabs
+
+        Reviewed-by: Z
+        (cherry picked from commit 8d1f0c2)"
+    );
+
+    // Act
+    let parsed = conventional_commit_parser::parse(commit_message).unwrap();
+
+    // Assert
+    assert_that(&parsed.footers[0].content).is_equal_to("Z".to_string());
+    assert_that(&parsed.cherry_picked_from).is_equal_to(Some("8d1f0c2".to_string()));
+    assert_that(&parsed.to_string()).is_equal_to(commit_message.to_string());
+}
@@ -0,0 +1,67 @@
+#![cfg(feature = "cache")]
+
+use std::num::NonZeroUsize;
+
+use conventional_commit_parser::cache::CachedParser;
+use conventional_commit_parser::error::ParseErrorKind;
+use conventional_commit_parser::options::ParserOptions;
+use speculoos::prelude::*;
+
+fn capacity(capacity: usize) -> NonZeroUsize {
+    NonZeroUsize::new(capacity).unwrap()
+}
+
+#[test]
+fn should_cache_parse_errors() {
+    // Arrange
+    let mut parser = CachedParser::new(capacity(8));
+
+    // Act
+    let first = parser.parse("feat add a cache").map_err(|err| err.kind);
+    let second = parser.parse("feat add a cache").map_err(|err| err.kind);
+
+    // Assert
+    assert_that(&first).is_err_containing(ParseErrorKind::MissingSeparator);
+    assert_that(&second).is_err_containing(ParseErrorKind::MissingSeparator);
+    assert_that(&parser.hits()).is_equal_to(1);
+    assert_that(&parser.misses()).is_equal_to(1);
+}
+
+#[test]
+fn should_evict_least_recently_used_message() {
+    // Arrange
+    let mut parser = CachedParser::new(capacity(2));
+
+    // Act
+    let _ = parser.parse("feat: first");
+    let _ = parser.parse("feat: second");
+    let _ = parser.parse("feat: first");
+    let _ = parser.parse("feat: third");
+    let _ = parser.parse("feat: second");
+
+    // Assert
+    assert_that(&parser.hits()).is_equal_to(1);
+    assert_that(&parser.misses()).is_equal_to(4);
+    assert_that(&parser.len()).is_equal_to(2);
+}
+
+#[test]
+fn should_parse_with_options() {
+    // Arrange
+    let options = ParserOptions {
+        summary_max_len: Some(10),
+        ..Default::default()
+    };
+    let mut parser = CachedParser::with_options(capacity(8), options);
+
+    // Act
+    let parsed = parser.parse("feat: a summary that is way too long");
+
+    // Assert
+    assert_that(&parsed.map_err(|err| err.kind)).is_err_containing(
+        ParseErrorKind::SummaryTooLong {
+            limit: 10,
+            actual: 30,
+        },
+    );
+}
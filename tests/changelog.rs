@@ -0,0 +1,734 @@
+use conventional_commit_parser::changelog::html::HtmlRenderer;
+use conventional_commit_parser::changelog::markdown::{
+    render_markdown, render_markdown_with, LineContext, MarkdownStyle, RenderOptions,
+};
+use conventional_commit_parser::changelog::template::{
+    Provider, ReleaseCommitTemplate, Remote, TemplateContext, Token, UrlTemplate, UrlTemplates,
+};
+use conventional_commit_parser::changelog::{
+    is_release_commit, Changelog, ChangelogEntry, ChangelogRenderer, ChangelogSections, CommitLink,
+    CommitMeta, ExclusionMarkers, ScopeGrouping, TypeNames,
+};
+use conventional_commit_parser::commit::CommitType;
+use conventional_commit_parser::parse;
+use indoc::indoc;
+use speculoos::prelude::*;
+
+fn github() -> Remote {
+    Remote {
+        host: "github.com".to_string(),
+        owner: "oknozor".to_string(),
+        repository: "conventional_commits_parser_rs".to_string(),
+        templates: UrlTemplates {
+            commit: UrlTemplate::parse("https://{{host}}/{{owner}}/{{repository}}/commit/{{sha}}").ok(),
+            compare: UrlTemplate::parse(
+                "https://{{host}}/{{owner}}/{{repository}}/compare/{{previous_tag}}..{{current_tag}}",
+            )
+            .ok(),
+            issue: UrlTemplate::parse("https://{{host}}/{{owner}}/{{repository}}/issues/{{issue}}").ok(),
+            user: UrlTemplate::parse("https://{{host}}/{{username}}").ok(),
+        },
+    }
+}
+
+fn sections() -> ChangelogSections {
+    let entries = vec![
+        ChangelogEntry {
+            commit: parse("fix: handle windows new lines escape sequence in footer separators")
+                .unwrap(),
+            sha: Some("1df053fb3133ca36ca4127cf48231bf7c77b3d33".to_string()),
+            author: Some("oknozor".to_string()),
+            date: None,
+            links: vec![],
+        },
+        ChangelogEntry {
+            commit: parse("feat(parser)!: drop legacy separators (#12)").unwrap(),
+            sha: Some("22632366d71819ad8cfa7bff82a2ac290686c043".to_string()),
+            author: Some("oknozor".to_string()),
+            date: None,
+            links: vec![],
+        },
+        ChangelogEntry {
+            commit: parse(
+                "fix: reject oversized messages\n\nDeprecates: parse_unchecked\nCVE: CVE-2022-0001",
+            )
+            .unwrap(),
+            sha: None,
+            author: None,
+            date: None,
+            links: vec![],
+        },
+    ];
+
+    let mut sections = ChangelogSections::from_entries(entries);
+    sections.version = Some("0.9.4".to_string());
+    sections.previous_version = Some("0.9.3".to_string());
+    sections.date = Some("2022-01-17".to_string());
+    sections
+}
+
+#[test]
+fn should_parse_url_template_tokens() {
+    // Act
+    let template = UrlTemplate::parse("{{ host }}/{{owner}}");
+    let unknown = UrlTemplate::parse("{{host}}/{{unknown}}");
+
+    // Assert
+    assert_that(&template).is_ok();
+    assert_that(&unknown).is_err();
+}
+
+#[test]
+fn should_render_cocogitto_markdown_with_links() {
+    // Arrange
+    let options = RenderOptions {
+        style: MarkdownStyle::Cocogitto,
+        remote: Some(github()),
+        ..Default::default()
+    };
+
+    // Act
+    let markdown = render_markdown(&sections(), &options);
+
+    // Assert
+    assert_that(&markdown).is_equal_to(indoc!(
+        "## [0.9.4](https://github.com/oknozor/conventional_commits_parser_rs/compare/0.9.3..0.9.4) - 2022-01-17
+        #### Security
+        - reject oversized messages
+        #### Features
+        - **BREAKING** **(parser)** drop legacy separators ([#12](https://github.com/oknozor/conventional_commits_parser_rs/issues/12)) - ([2263236](https://github.com/oknozor/conventional_commits_parser_rs/commit/22632366d71819ad8cfa7bff82a2ac290686c043)) - [@oknozor](https://github.com/oknozor)
+        #### Bug Fixes
+        - handle windows new lines escape sequence in footer separators - ([1df053f](https://github.com/oknozor/conventional_commits_parser_rs/commit/1df053fb3133ca36ca4127cf48231bf7c77b3d33)) - [@oknozor](https://github.com/oknozor)
+        - - -
+        "
+    ).to_string());
+}
+
+#[test]
+fn should_render_keep_a_changelog_markdown() {
+    // Arrange
+    let options = RenderOptions {
+        style: MarkdownStyle::KeepAChangelog,
+        remote: Some(github()),
+        ..Default::default()
+    };
+
+    // Act
+    let markdown = render_markdown(&sections(), &options);
+
+    // Assert
+    assert_that(&markdown).is_equal_to(indoc!(
+        "## [0.9.4] - 2022-01-17
+        ### Added
+        - **BREAKING** **parser:** drop legacy separators ([#12](https://github.com/oknozor/conventional_commits_parser_rs/issues/12)) ([2263236](https://github.com/oknozor/conventional_commits_parser_rs/commit/22632366d71819ad8cfa7bff82a2ac290686c043))
+        ### Deprecated
+        - parse_unchecked
+        ### Fixed
+        - handle windows new lines escape sequence in footer separators ([1df053f](https://github.com/oknozor/conventional_commits_parser_rs/commit/1df053fb3133ca36ca4127cf48231bf7c77b3d33))
+        ### Security
+        - reject oversized messages
+
+        [0.9.4]: https://github.com/oknozor/conventional_commits_parser_rs/compare/0.9.3..0.9.4
+        "
+    ).to_string());
+}
+
+#[test]
+fn should_render_html_changelog() {
+    // Arrange
+    let renderer = HtmlRenderer {
+        remote: Some(github()),
+        ..Default::default()
+    };
+
+    // Act
+    let html = renderer.render(&sections());
+
+    // Assert
+    assert_that(&html).is_equal_to(indoc!(
+        "<section class=\"release\">
+        <h2><a href=\"https://github.com/oknozor/conventional_commits_parser_rs/compare/0.9.3..0.9.4\">0.9.4</a> - 2022-01-17</h2>
+        <h3>Features</h3>
+        <ul>
+        <li><span class=\"breaking\">BREAKING</span> <strong>(parser)</strong> drop legacy separators (<a href=\"https://github.com/oknozor/conventional_commits_parser_rs/issues/12\">#12</a>) - <a href=\"https://github.com/oknozor/conventional_commits_parser_rs/commit/22632366d71819ad8cfa7bff82a2ac290686c043\"><code>2263236</code></a> - <a href=\"https://github.com/oknozor\">@oknozor</a></li>
+        </ul>
+        <h3>Bug Fixes</h3>
+        <ul>
+        <li>handle windows new lines escape sequence in footer separators - <a href=\"https://github.com/oknozor/conventional_commits_parser_rs/commit/1df053fb3133ca36ca4127cf48231bf7c77b3d33\"><code>1df053f</code></a> - <a href=\"https://github.com/oknozor\">@oknozor</a></li>
+        <li>reject oversized messages</li>
+        </ul>
+        </section>
+        "
+    ).to_string());
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn should_render_json_changelog() {
+    use conventional_commit_parser::changelog::json::JsonRenderer;
+
+    // Arrange
+    let renderer = JsonRenderer {
+        remote: Some(github()),
+        ..Default::default()
+    };
+
+    // Act
+    let json = renderer.render(&sections());
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+    // Assert
+    assert_that(&value["compare_url"].as_str()).is_equal_to(Some(
+        "https://github.com/oknozor/conventional_commits_parser_rs/compare/0.9.3..0.9.4",
+    ));
+    assert_that(&value["sections"][0]["title"].as_str()).is_equal_to(Some("Features"));
+    assert_that(&value["sections"][0]["entries"][0]["breaking_change"].as_bool())
+        .is_equal_to(Some(true));
+    assert_that(&value["sections"][1]["entries"][1]["footers"][1]["token"].as_str())
+        .is_equal_to(Some("CVE"));
+    assert_that(&value["sections"][1]["entries"][1]["footers"][1]["separator"].as_str())
+        .is_equal_to(Some(": "));
+}
+
+#[test]
+fn should_nest_markdown_entries_by_scope() {
+    // Arrange
+    let sections = ChangelogSections::from_entries(vec![
+        parse("feat(cli): add verbose flag").unwrap(),
+        parse("feat(core): add config file").unwrap(),
+        parse("feat: add man page").unwrap(),
+        parse("feat(cli): add quiet flag").unwrap(),
+    ]);
+
+    let mut grouping = ScopeGrouping::default();
+    grouping
+        .scope_names
+        .insert("cli".to_string(), "Command line".to_string());
+
+    let options = RenderOptions {
+        scope_grouping: Some(grouping),
+        ..Default::default()
+    };
+
+    // Act
+    let markdown = render_markdown(&sections, &options);
+
+    // Assert
+    assert_that(&markdown).is_equal_to(
+        [
+            "## Unreleased",
+            "#### Features",
+            "- add man page",
+            "##### Command line",
+            "- add verbose flag",
+            "- add quiet flag",
+            "##### core",
+            "- add config file",
+            "- - -\n",
+        ]
+        .join("\n"),
+    );
+}
+
+#[test]
+fn should_render_breaking_change_migration_notes() {
+    // Arrange
+    let commit = parse(indoc!(
+        "feat(parser)!: drop parse_summary
+
+        BREAKING CHANGE: parse_summary was removed
+          Migration: use parse instead,
+          the summary is available on the parsed commit"
+    ))
+    .unwrap();
+    let sections = ChangelogSections::from_entries(vec![commit]);
+
+    // Act
+    let markdown = render_markdown(&sections, &RenderOptions::default());
+    let html = HtmlRenderer::default().render(&sections);
+
+    // Assert
+    assert_that(&markdown).is_equal_to(
+        indoc!(
+            "## Unreleased
+            #### Features
+            - **BREAKING** **(parser)** drop parse_summary
+              - **Migration:** use parse instead,
+                the summary is available on the parsed commit
+            - - -
+            "
+        )
+        .to_string(),
+    );
+    assert_that(&html).contains(
+        "<p class=\"migration\">Migration: use parse instead,\n  the summary is available on the parsed commit</p>",
+    );
+}
+
+#[test]
+fn should_render_sections_in_configured_type_order() {
+    // Arrange
+    let sections = ChangelogSections::from_entries(vec![
+        parse("feat: add verbose flag").unwrap(),
+        parse("docs: document flags").unwrap(),
+        parse("fix: handle empty flags").unwrap(),
+    ]);
+    let options = RenderOptions {
+        type_order: vec![CommitType::BugFix, CommitType::Documentation],
+        ..Default::default()
+    };
+
+    // Act
+    let markdown = render_markdown(&sections, &options);
+
+    // Assert
+    assert_that(&markdown).is_equal_to(
+        [
+            "## Unreleased",
+            "#### Bug Fixes",
+            "- handle empty flags",
+            "#### Documentation",
+            "- document flags",
+            "#### Features",
+            "- add verbose flag",
+            "- - -\n",
+        ]
+        .join("\n"),
+    );
+}
+
+#[test]
+fn should_render_custom_type_display_names() {
+    // Arrange
+    let sections = ChangelogSections::from_entries(vec![
+        parse("feat: ajoute un drapeau").unwrap(),
+        parse("fix: corrige une coquille").unwrap(),
+    ]);
+    let mut type_names = TypeNames::default();
+    type_names
+        .names
+        .insert(CommitType::Feature, "Fonctionnalités".to_string());
+    type_names
+        .names
+        .insert(CommitType::BugFix, "Corrections".to_string());
+
+    let options = RenderOptions {
+        type_names: type_names.clone(),
+        ..Default::default()
+    };
+
+    // Act
+    let markdown = render_markdown(&sections, &options);
+    let html = HtmlRenderer {
+        type_names,
+        ..Default::default()
+    }
+    .render(&sections);
+
+    // Assert
+    assert_that(&markdown).contains("#### Fonctionnalités\n- ajoute un drapeau");
+    assert_that(&markdown).contains("#### Corrections\n- corrige une coquille");
+    assert_that(&html).contains("<h3>Fonctionnalités</h3>");
+}
+
+#[test]
+fn should_keep_code_spans_when_linking_issues() {
+    // Arrange
+    let sections =
+        ChangelogSections::from_entries(vec![
+            parse("fix: escape `#12` in `Footer` tokens (#13)").unwrap()
+        ]);
+    let options = RenderOptions {
+        remote: Some(github()),
+        ..Default::default()
+    };
+
+    // Act
+    let markdown = render_markdown(&sections, &options);
+    let html = HtmlRenderer::default().render(&sections);
+
+    // Assert
+    assert_that(&markdown).contains(
+        "- escape `#12` in `Footer` tokens ([#13](https://github.com/oknozor/conventional_commits_parser_rs/issues/13))",
+    );
+    assert_that(&html)
+        .contains("<li>escape <code>#12</code> in <code>Footer</code> tokens (#13)</li>");
+}
+
+#[test]
+fn should_render_markdown_lines_with_custom_formatter() {
+    // Arrange
+    let options = RenderOptions {
+        remote: Some(github()),
+        ..Default::default()
+    };
+    let formatter = |line: &LineContext<'_>| match (&line.commit_url, line.short_sha) {
+        (Some(url), Some(sha)) => format!("* {} [`{}`]({})", line.summary, sha, url),
+        _ => format!("* {}", line.summary),
+    };
+
+    // Act
+    let markdown = render_markdown_with(&sections(), &options, &formatter);
+
+    // Assert
+    assert_that(&markdown).is_equal_to(indoc!(
+        "## [0.9.4](https://github.com/oknozor/conventional_commits_parser_rs/compare/0.9.3..0.9.4) - 2022-01-17
+        #### Security
+        * reject oversized messages
+        #### Features
+        * drop legacy separators ([#12](https://github.com/oknozor/conventional_commits_parser_rs/issues/12)) [`2263236`](https://github.com/oknozor/conventional_commits_parser_rs/commit/22632366d71819ad8cfa7bff82a2ac290686c043)
+        #### Bug Fixes
+        * handle windows new lines escape sequence in footer separators [`1df053f`](https://github.com/oknozor/conventional_commits_parser_rs/commit/1df053fb3133ca36ca4127cf48231bf7c77b3d33)
+        - - -
+        "
+    ).to_string());
+}
+
+#[test]
+fn should_strip_ci_skip_markers_from_changelog_summaries() {
+    // Arrange
+    let commit = parse("docs: [ci skip] fix `README` typo [SKIP CI]").unwrap();
+    let sections = ChangelogSections::from_entries(vec![commit.clone()]);
+
+    // Act
+    let markdown = render_markdown(&sections, &RenderOptions::default());
+    let html = HtmlRenderer::default().render(&sections);
+
+    // Assert
+    assert_that(&commit.ci_skip()).is_true();
+    assert_that(&markdown).contains("- fix `README` typo\n");
+    assert_that(&html).contains("<li>fix <code>README</code> typo</li>");
+}
+
+#[test]
+fn should_assemble_unreleased_changes() {
+    // Arrange
+    let changelog = Changelog {
+        type_order: vec![CommitType::Documentation, CommitType::Feature],
+        ..Default::default()
+    };
+    let commits = vec![
+        parse("feat(parser)!: drop parse_summary\n\nBREAKING CHANGE: parse_summary was removed\nMigration: use parse instead").unwrap(),
+        parse("docs: document unreleased").unwrap(),
+        parse("fix!: reject empty messages").unwrap(),
+    ];
+
+    // Act
+    let unreleased = changelog.unreleased(commits);
+    let notes = unreleased.breaking_change_notes();
+
+    // Assert
+    let types: Vec<&CommitType> = unreleased.sections.iter().map(|s| &s.commit_type).collect();
+    assert_that(&types).is_equal_to(vec![
+        &CommitType::Documentation,
+        &CommitType::Feature,
+        &CommitType::BugFix,
+    ]);
+    assert_that(&notes).has_length(2);
+    assert_that(&notes[0].description).is_equal_to("parse_summary was removed");
+    assert_that(&notes[0].migration).is_equal_to(Some("use parse instead"));
+    assert_that(&notes[1].description).is_equal_to("reject empty messages");
+    assert_that(&notes[1].migration).is_none();
+}
+
+#[test]
+fn should_render_compare_links_between_release_tags() {
+    // Arrange
+    let log = vec![
+        (None, parse("fix: handle empty messages").unwrap()),
+        (
+            Some("0.9.4".to_string()),
+            parse("feat: add releases").unwrap(),
+        ),
+        (Some("0.9.3".to_string()), parse("fix: typo").unwrap()),
+    ];
+    let options = RenderOptions {
+        remote: Some(github()),
+        ..Default::default()
+    };
+
+    // Act
+    let releases = Changelog::default().releases(log);
+    let headings: Vec<String> = releases
+        .iter()
+        .map(|release| {
+            render_markdown(release, &options)
+                .lines()
+                .next()
+                .unwrap()
+                .to_string()
+        })
+        .collect();
+
+    // Assert
+    assert_that(&headings).is_equal_to(vec![
+        "## [Unreleased](https://github.com/oknozor/conventional_commits_parser_rs/compare/0.9.4..HEAD)".to_string(),
+        "## [0.9.4](https://github.com/oknozor/conventional_commits_parser_rs/compare/0.9.3..0.9.4)".to_string(),
+        "## 0.9.3".to_string(),
+    ]);
+}
+
+#[test]
+fn should_render_links_with_provider_presets() {
+    // Arrange
+    let mut gitea = Remote::from_provider(Provider::Gitea, "oknozor", "cocogitto");
+    gitea.host = "git.example.org".to_string();
+    let sourcehut = Remote::from_provider(Provider::SourceHut, "oknozor", "cocogitto");
+    let bitbucket = Remote::from_provider(Provider::Bitbucket, "oknozor", "cocogitto");
+
+    // Act
+    let gitea_issue = gitea.issue_url("12");
+    let sourcehut_compare = sourcehut.compare_url("1.0.0", "1.1.0");
+    let sourcehut_issue = sourcehut.issue_url("12");
+    let bitbucket_user = bitbucket.user_url("oknozor");
+
+    // Assert
+    assert_that(&gitea_issue).is_equal_to(Some(
+        "https://git.example.org/oknozor/cocogitto/issues/12".to_string(),
+    ));
+    assert_that(&sourcehut_compare).is_none();
+    assert_that(&sourcehut_issue)
+        .is_equal_to(Some("https://todo.sr.ht/~oknozor/cocogitto/12".to_string()));
+    assert_that(&bitbucket_user).is_none();
+}
+
+#[test]
+fn should_render_version_date_and_custom_placeholders() {
+    // Arrange
+    let template = UrlTemplate::parse_with_placeholders(
+        r"chore(version): {{version}} ({{ date }}) for {{package.name}} \{{version}}",
+        &["package.name"],
+    )
+    .unwrap();
+    let context = TemplateContext {
+        version: Some("0.9.4"),
+        date: Some("2022-01-17"),
+        custom: &[("package.name", "parser")],
+        ..Default::default()
+    };
+
+    // Act
+    let rendered = template.render(&context);
+    let missing = template.render(&TemplateContext::default());
+
+    // Assert
+    assert_that(&template.tokens().to_vec()).contains(Token::Custom("package.name".to_string()));
+    assert_that(&rendered).is_equal_to(Some(
+        "chore(version): 0.9.4 (2022-01-17) for parser {{version}}".to_string(),
+    ));
+    assert_that(&missing).is_none();
+}
+
+#[test]
+fn should_not_mistake_placeholder_prefixed_by_builtin_name() {
+    // Act
+    let hostname = UrlTemplate::parse_with_placeholders("{{hostname}}", &["hostname"]);
+    let undeclared = UrlTemplate::parse("{{hostname}}");
+
+    // Assert
+    assert_that(&hostname.unwrap().tokens().to_vec())
+        .is_equal_to(vec![Token::Custom("hostname".to_string())]);
+    assert_that(&undeclared).is_err();
+}
+
+#[test]
+fn should_find_release_commits_in_history() {
+    // Arrange
+    let template = ReleaseCommitTemplate::parse("chore(version): {{version}} - {{date}}").unwrap();
+    let history = [
+        parse("feat: add releases").unwrap(),
+        parse("chore(version): 0.9.4 - 2022-01-17").unwrap(),
+        parse("chore(version): 0.9.3").unwrap(),
+        parse("chore(version): 0.9.2-rc - 1 - 2021-12-01").unwrap(),
+    ];
+
+    // Act
+    let releases: Vec<bool> = history
+        .iter()
+        .map(|commit| is_release_commit(commit, &template))
+        .collect();
+    let versions: Vec<Option<String>> = history.iter().map(|c| template.version(c)).collect();
+
+    // Assert
+    assert_that(&releases).is_equal_to(vec![false, true, false, true]);
+    assert_that(&versions).is_equal_to(vec![
+        None,
+        Some("0.9.4".to_string()),
+        None,
+        Some("0.9.2-rc".to_string()),
+    ]);
+}
+
+struct GitCommit {
+    sha: &'static str,
+    author: &'static str,
+    date: &'static str,
+    pull_request: Option<u32>,
+}
+
+impl CommitMeta for GitCommit {
+    fn sha(&self) -> Option<&str> {
+        Some(self.sha)
+    }
+
+    fn author(&self) -> Option<&str> {
+        Some(self.author)
+    }
+
+    fn date(&self) -> Option<&str> {
+        Some(self.date)
+    }
+
+    fn links(&self) -> Vec<CommitLink> {
+        self.pull_request
+            .map(|number| CommitLink {
+                title: format!("PR #{}", number),
+                url: format!("https://github.com/oknozor/cocogitto/pull/{}", number),
+            })
+            .into_iter()
+            .collect()
+    }
+}
+
+#[test]
+fn should_render_commit_metadata_paired_with_commits() {
+    // Arrange
+    let log = vec![
+        (
+            parse("feat: add commit metadata").unwrap(),
+            GitCommit {
+                sha: "22632366d71819ad8cfa7bff82a2ac290686c043",
+                author: "oknozor",
+                date: "2022-01-17",
+                pull_request: Some(12),
+            },
+        ),
+        (
+            parse("fix: typo").unwrap(),
+            GitCommit {
+                sha: "1df053fb3133ca36ca4127cf48231bf7c77b3d33",
+                author: "guillaumer63",
+                date: "2022-01-16",
+                pull_request: None,
+            },
+        ),
+    ];
+
+    // Act
+    let unreleased = Changelog::default().unreleased(log);
+    let markdown = render_markdown(&unreleased, &RenderOptions::default());
+
+    // Assert
+    assert_that(&unreleased.sections[0].entries[0].date.as_deref()).is_equal_to(Some("2022-01-17"));
+    assert_that(&markdown).contains(
+        "- add commit metadata - (2263236) - oknozor - [PR #12](https://github.com/oknozor/cocogitto/pull/12)\n",
+    );
+    assert_that(&markdown).contains("- typo - (1df053f) - guillaumer63\n");
+}
+
+#[test]
+fn should_omit_commits_with_exclusion_markers() {
+    // Arrange
+    let changelog = Changelog {
+        exclusion_markers: ExclusionMarkers {
+            markers: vec!["[no release notes]".to_string()],
+            footers: vec![("Release-Note".to_string(), "skip".to_string())],
+        },
+        ..Default::default()
+    };
+    let commits = vec![
+        parse("fix: typo [No Release Notes]").unwrap(),
+        parse("fix: bump lockfile\n\nrelease-note: SKIP").unwrap(),
+        parse("fix: handle tabs [skip changelog]").unwrap(),
+    ];
+
+    // Act
+    let unreleased = changelog.unreleased(commits.clone());
+    let with_defaults = Changelog::default().unreleased(commits);
+
+    // Assert
+    let summaries: Vec<&str> = unreleased
+        .entries()
+        .map(|entry| entry.commit.summary.as_str())
+        .collect();
+    assert_that(&summaries).is_equal_to(vec!["handle tabs [skip changelog]"]);
+    assert_that(&with_defaults.entries().count()).is_equal_to(2);
+}
+
+#[test]
+fn should_only_omit_excluded_commits_when_asked_to() {
+    // Arrange
+    let commits = vec![
+        parse("feat(cli): add verbose flag").unwrap(),
+        parse("feat(cli): add debug flag [skip changelog]").unwrap(),
+        parse("fix: bump lockfile\n\nChangelog: none").unwrap(),
+    ];
+    let markers = ExclusionMarkers {
+        markers: vec!["[internal]".to_string()],
+        footers: vec![],
+    };
+
+    // Act
+    let sections = ChangelogSections::from_entries(commits.clone());
+    let excluded = ChangelogSections::from_entries_excluding(commits.clone(), &Default::default());
+    let custom = ChangelogSections::from_entries_excluding(commits, &markers);
+
+    // Assert
+    assert_that(&sections.entries().count()).is_equal_to(3);
+    assert_that(&excluded.entries().count()).is_equal_to(1);
+    assert_that(&custom.entries().count()).is_equal_to(3);
+}
+
+#[test]
+fn should_keep_commits_allowed_by_custom_markers_when_grouping_by_scope() {
+    // Arrange
+    let changelog = Changelog {
+        exclusion_markers: ExclusionMarkers {
+            markers: vec!["[internal]".to_string()],
+            footers: vec![],
+        },
+        ..Default::default()
+    };
+    let unreleased = changelog.unreleased(vec![
+        parse("feat(cli): add debug flag [skip changelog]").unwrap(),
+        parse("feat(cli): add trace flag [internal]").unwrap(),
+    ]);
+    let renderer = HtmlRenderer {
+        scope_grouping: Some(ScopeGrouping::default()),
+        ..Default::default()
+    };
+
+    // Act
+    let html = renderer.render(&unreleased);
+
+    // Assert
+    assert_that(&html).contains("<h3>Features</h3>");
+    assert_that(&html).contains("add debug flag [skip changelog]");
+    assert_that(&html).does_not_contain("add trace flag");
+}
+
+#[test]
+fn should_render_changelog_footer_instead_of_summary() {
+    // Arrange
+    let commit = parse(indoc!(
+        "fix(parser): handle tabs
+
+        Changelog: Tabs are now accepted between the type and the summary"
+    ))
+    .unwrap();
+    let sections = ChangelogSections::from_entries(vec![commit]);
+    let options = RenderOptions {
+        style: MarkdownStyle::KeepAChangelog,
+        ..Default::default()
+    };
+
+    // Act
+    let markdown = render_markdown(&sections, &options);
+    let html = HtmlRenderer::default().render(&sections);
+
+    // Assert
+    assert_that(&markdown).contains("Tabs are now accepted between the type and the summary");
+    assert_that(&markdown).does_not_contain("handle tabs");
+    assert_that(&html)
+        .contains("</strong> Tabs are now accepted between the type and the summary</li>");
+}
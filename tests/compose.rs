@@ -0,0 +1,325 @@
+use conventional_commit_parser::commit::{CommitType, Footer};
+use conventional_commit_parser::compose::{
+    append_trailer, apply_changes, footers_enumerated, remove_trailer, replace_trailer,
+    CommitMessage,
+};
+use conventional_commit_parser::error::ComposeErrorKind;
+use conventional_commit_parser::options::ParserOptions;
+use conventional_commit_parser::parse_with_options;
+use indoc::indoc;
+use speculoos::prelude::*;
+
+fn footer(token: &str, content: &str) -> Footer {
+    Footer {
+        token: token.to_string(),
+        content: content.to_string(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn should_compose_full_commit_message() {
+    // Act
+    let message = CommitMessage::compose(
+        CommitType::Feature,
+        Some("api"),
+        "add pagination",
+        Some("Pages are 50 items long."),
+        &[footer("BREAKING CHANGE", "list endpoints are paginated")],
+    );
+
+    // Assert
+    assert_that(&message.map(String::from)).is_ok().is_equal_to(
+        indoc!(
+            "feat(api): add pagination
+
+            Pages are 50 items long.
+
+            BREAKING CHANGE: list endpoints are paginated"
+        )
+        .to_string(),
+    );
+}
+
+#[test]
+fn should_compose_breaking_change_with_mark() {
+    // Act
+    let message = CommitMessage::compose_breaking(
+        CommitType::Feature,
+        None,
+        "drop node 12",
+        None,
+        &[footer("BREAKING CHANGE", "node 12 is no longer supported")],
+    );
+
+    // Assert
+    assert_that(&message.map(String::from)).is_ok().is_equal_to(
+        "feat!: drop node 12\n\nBREAKING CHANGE: node 12 is no longer supported".to_string(),
+    );
+}
+
+#[test]
+fn should_report_every_composition_error() {
+    // Act
+    let message = CommitMessage::compose(
+        CommitType::Custom("work in progress".to_string()),
+        Some(""),
+        "a\nb",
+        None,
+        &[footer("Reviewed by", " ")],
+    );
+
+    // Assert
+    assert_that(&message.map_err(|err| err.errors))
+        .is_err()
+        .is_equal_to(vec![
+            ComposeErrorKind::InvalidType("work in progress".to_string()),
+            ComposeErrorKind::InvalidScope("".to_string()),
+            ComposeErrorKind::MultilineSummary,
+            ComposeErrorKind::InvalidFooterToken {
+                index: 0,
+                token: "Reviewed by".to_string(),
+            },
+            ComposeErrorKind::EmptyFooterContent { index: 0 },
+        ]);
+}
+
+#[test]
+fn should_reject_header_too_long() {
+    // Act
+    let message = CommitMessage::compose(CommitType::Chore, None, &"a".repeat(100), None, &[]);
+
+    // Assert
+    assert_that(&message.map_err(|err| err.errors))
+        .is_err()
+        .is_equal_to(vec![ComposeErrorKind::HeaderTooLong {
+            limit: 100,
+            actual: 107,
+        }]);
+}
+
+#[test]
+fn should_reject_body_that_would_be_parsed_as_footer() {
+    // Act
+    let message = CommitMessage::compose(
+        CommitType::Chore,
+        None,
+        "release",
+        Some("Release-notes: see below"),
+        &[],
+    );
+
+    // Assert
+    assert_that(&message.map_err(|err| err.errors))
+        .is_err()
+        .is_equal_to(vec![ComposeErrorKind::Ambiguous]);
+}
+
+#[test]
+fn apply_changes_should_only_rewrite_edited_body() {
+    // Arrange
+    let original = "feat(api): add endpoint\n\n\nFirst  draft\n\nreviewed-by:   Z\nRefs #133\n";
+    let mut edited = conventional_commit_parser::parse(original).unwrap();
+    edited.body = Some("Final version".to_string());
+
+    // Act
+    let message = apply_changes(original, &edited);
+
+    // Assert
+    assert_that(&message).is_equal_to(
+        "feat(api): add endpoint\n\n\nFinal version\n\nreviewed-by:   Z\nRefs #133\n".to_string(),
+    );
+}
+
+#[test]
+fn apply_changes_should_add_and_remove_sections() {
+    // Arrange
+    let original = "fix: typo\n\nA body\n";
+    let mut edited = conventional_commit_parser::parse(original).unwrap();
+    edited.body = None;
+    edited.footers = vec![footer("Reviewed-by", "Z")];
+
+    // Act
+    let message = apply_changes(original, &edited);
+    let restored = apply_changes(
+        &message,
+        &conventional_commit_parser::parse(original).unwrap(),
+    );
+
+    // Assert
+    assert_that(&message).is_equal_to("fix: typo\n\nReviewed-by: Z\n".to_string());
+    assert_that(&restored).is_equal_to(original.to_string());
+}
+
+#[test]
+fn apply_changes_should_render_edited_commit_when_original_is_invalid() {
+    // Arrange
+    let edited = conventional_commit_parser::parse("fix: typo\n\nA body").unwrap();
+
+    // Act
+    let message = apply_changes("not a conventional commit", &edited);
+
+    // Assert
+    assert_that(&message).is_equal_to("fix: typo\n\nA body".to_string());
+}
+
+#[test]
+fn apply_changes_should_keep_pre_header_tag_of_edited_commit() {
+    // Arrange
+    let original = "[ABC-42] fix: typo\n\nA body";
+    let options = ParserOptions {
+        accept_pre_header_tag: true,
+        ..ParserOptions::default()
+    };
+    let mut edited = parse_with_options(original, &options).unwrap();
+    edited.summary = "correct typo".to_string();
+
+    // Act
+    let message = apply_changes(original, &edited);
+
+    // Assert
+    assert_that(&message).is_equal_to("[ABC-42] fix: correct typo\n\nA body".to_string());
+}
+
+#[test]
+fn append_trailer_should_preserve_message_formatting() {
+    // Arrange
+    let with_footers = "feat(api):  add endpoint\n\nSome   body\n\nRefs #12\nCo-authored-by: Y\n\n";
+    let header_only = "feat: add endpoint";
+
+    // Act
+    let appended = append_trailer(with_footers, "Reviewed-by", "Z");
+    let appended_to_header = append_trailer(header_only, "Signed-off-by", "Z <z@example.org>");
+
+    // Assert
+    assert_that(&appended).is_equal_to(
+        "feat(api):  add endpoint\n\nSome   body\n\nRefs #12\nCo-authored-by: Y\nReviewed-by: Z\n\n"
+            .to_string(),
+    );
+    assert_that(&appended_to_header)
+        .is_equal_to("feat: add endpoint\n\nSigned-off-by: Z <z@example.org>".to_string());
+    assert_that(
+        &conventional_commit_parser::parse(&appended)
+            .unwrap()
+            .footers,
+    )
+    .has_length(3);
+}
+
+#[test]
+fn append_trailer_should_extend_trailers_of_non_conventional_message() {
+    // Arrange
+    let plain = "Update README\n\nSigned-off-by: A <a@b.c>\n";
+    let tagged = "[JIRA-1] feat: add endpoint\n\nA body\n\nRefs: #1\n";
+
+    // Act
+    let appended_to_plain = append_trailer(plain, "Reviewed-by", "Z");
+    let appended_to_tagged = append_trailer(tagged, "Reviewed-by", "Z");
+
+    // Assert
+    assert_that(&appended_to_plain)
+        .is_equal_to("Update README\n\nSigned-off-by: A <a@b.c>\nReviewed-by: Z\n".to_string());
+    assert_that(&appended_to_tagged).is_equal_to(
+        "[JIRA-1] feat: add endpoint\n\nA body\n\nRefs: #1\nReviewed-by: Z\n".to_string(),
+    );
+}
+
+#[test]
+fn remove_trailer_should_keep_other_footers_untouched() {
+    // Arrange
+    let message = "fix: typo\n\nA  body\n\nSigned-off-by: Y\nBREAKING CHANGE:\nno more typos\n\nsigned-off-by: Z\n";
+
+    // Act
+    let (removed_signoffs, signoffs) = remove_trailer(message, "Signed-off-by");
+    let (removed_all, _) = remove_trailer(&removed_signoffs, "BREAKING CHANGE");
+    let (unchanged, none) = remove_trailer(message, "Refs");
+
+    // Assert
+    assert_that(&removed_signoffs)
+        .is_equal_to("fix: typo\n\nA  body\n\nBREAKING CHANGE:\nno more typos\n".to_string());
+    assert_that(
+        &signoffs
+            .iter()
+            .map(|footer| footer.content.as_str())
+            .collect::<Vec<_>>(),
+    )
+    .is_equal_to(vec!["Y", "Z"]);
+    assert_that(&removed_all).is_equal_to("fix: typo\n\nA  body\n".to_string());
+    assert_that(&unchanged).is_equal_to(message.to_string());
+    assert_that(&none).is_empty();
+}
+
+#[test]
+fn replace_trailer_should_keep_separator_or_append_missing_trailer() {
+    // Arrange
+    let message = "fix: typo\n\nRefs #12\nReviewed-by: Y\n";
+
+    // Act
+    let (replaced, old) = replace_trailer(message, "reviewed-by", "Z");
+    let (appended, missing) = replace_trailer(message, "Signed-off-by", "Z");
+
+    // Assert
+    assert_that(&replaced).is_equal_to("fix: typo\n\nRefs #12\nReviewed-by: Z\n".to_string());
+    assert_that(&old[0].content).is_equal_to("Y".to_string());
+    assert_that(&appended)
+        .is_equal_to("fix: typo\n\nRefs #12\nReviewed-by: Y\nSigned-off-by: Z\n".to_string());
+    assert_that(&missing).is_empty();
+}
+
+#[test]
+fn should_edit_trailers_of_non_conventional_message() {
+    // Arrange
+    let message = "Update README\n\nSigned-off-by: A <a@b.c>\n";
+
+    // Act
+    let (removed, signoffs) = remove_trailer(message, "Signed-off-by");
+    let (replaced, old) = replace_trailer(message, "Signed-off-by", "B <b@c.d>");
+
+    // Assert
+    assert_that(&removed).is_equal_to("Update README\n".to_string());
+    assert_that(&signoffs[0].content).is_equal_to("A <a@b.c>".to_string());
+    assert_that(&replaced).is_equal_to("Update README\n\nSigned-off-by: B <b@c.d>\n".to_string());
+    assert_that(&old[0].content).is_equal_to("A <a@b.c>".to_string());
+}
+
+#[test]
+fn should_enumerate_footers_with_their_byte_range() {
+    // Arrange
+    let message = indoc!(
+        "fix: typo
+
+        Refs #12
+        BREAKING CHANGE: the lexer
+        splits lines
+        Reviewed-by: Z
+        "
+    );
+
+    // Act
+    let footers: Vec<_> = footers_enumerated(message).collect();
+
+    // Assert
+    let spliced: Vec<(usize, &str, &str)> = footers
+        .iter()
+        .map(|(index, range, footer)| (*index, &message[range.clone()], footer.token.as_str()))
+        .collect();
+
+    assert_that(&spliced).is_equal_to(vec![
+        (0, "Refs #12", "Refs"),
+        (
+            1,
+            "BREAKING CHANGE: the lexer\nsplits lines",
+            "BREAKING CHANGE",
+        ),
+        (2, "Reviewed-by: Z", "Reviewed-by"),
+    ]);
+}
+
+#[test]
+fn should_not_enumerate_footers_of_invalid_message() {
+    // Act
+    let footers = footers_enumerated("not a conventional commit\n\nRefs #12");
+
+    // Assert
+    assert_that(&footers.count()).is_equal_to(0);
+}
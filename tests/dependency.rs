@@ -0,0 +1,171 @@
+use conventional_commit_parser::dependency::{DependabotGroup, DependencyUpdate};
+use conventional_commit_parser::parse;
+use indoc::indoc;
+use speculoos::prelude::*;
+
+#[test]
+fn should_parse_dependabot_group_update() {
+    // Arrange
+    let commit_message = indoc!(
+        "chore(deps): bump the npm group with 12 updates (#456)
+
+        Bumps the npm group with 12 updates:
+
+        Signed-off-by: dependabot[bot] <support@github.com>"
+    );
+
+    // Act
+    let commit = parse(commit_message).unwrap();
+
+    // Assert
+    assert_that(&commit.pull_request_number()).is_equal_to(Some(456));
+    assert_that(&commit.dependabot_group()).is_equal_to(Some(DependabotGroup {
+        name: "npm",
+        directory: None,
+        directory_count: None,
+        update_count: 12,
+        pull_request: Some(456),
+    }));
+}
+
+#[test]
+fn should_parse_dependabot_group_update_with_directories() {
+    // Act
+    let in_directory = DependabotGroup::from_summary("bump the pip group in /docs with 1 update");
+    let across =
+        DependabotGroup::from_summary("Bump the cargo group across 2 directories with 5 updates");
+
+    // Assert
+    let in_directory = in_directory.unwrap();
+    assert_that(&in_directory.directory).is_equal_to(Some("/docs"));
+    assert_that(&in_directory.update_count).is_equal_to(1);
+    assert_that(&in_directory.pull_request).is_none();
+
+    let across = across.unwrap();
+    assert_that(&across.name).is_equal_to("cargo");
+    assert_that(&across.directory_count).is_equal_to(Some(2));
+    assert_that(&across.update_count).is_equal_to(5);
+}
+
+#[test]
+fn single_dependency_update_is_not_a_group() {
+    // Act
+    let commit =
+        parse("chore(deps): bump archunit-junit5-engine from 0.21.0 to 0.22.0 (#11)").unwrap();
+
+    // Assert
+    assert_that(&commit.pull_request_number()).is_equal_to(Some(11));
+    assert_that(&commit.dependabot_group()).is_none();
+}
+
+#[test]
+fn should_extract_renovate_table_updates() {
+    // Arrange
+    let commit_message = indoc!(
+        "chore(deps): update rust crates
+
+        This PR contains the following updates:
+
+        | Package | Type | Update | Change |
+        |---|---|---|---|
+        | [pest](https://pest.rs) ([source](https://github.com/pest-parser/pest)) | dependencies | minor | `2.1.0` -> `2.5.0` |
+        | [indoc](https://github.com/dtolnay/indoc) | dev-dependencies | major | `1.0.3` -> `2.0.0` |
+
+        ---
+
+        ### Configuration"
+    );
+
+    // Act
+    let updates = parse(commit_message).unwrap().dependency_updates();
+
+    // Assert
+    assert_that(&updates).is_equal_to(vec![
+        DependencyUpdate {
+            name: "pest".to_string(),
+            from: Some("2.1.0".to_string()),
+            to: "2.5.0".to_string(),
+        },
+        DependencyUpdate {
+            name: "indoc".to_string(),
+            from: Some("1.0.3".to_string()),
+            to: "2.0.0".to_string(),
+        },
+    ]);
+}
+
+#[test]
+fn should_extract_update_from_summary() {
+    // Act
+    let renovate = parse("chore(deps): update rust crate pest to 2.5").unwrap();
+    let dependabot = parse("chore(deps): bump lodash from 4.17.20 to 4.17.21 in /web").unwrap();
+
+    // Assert
+    assert_that(&renovate.dependency_updates()).is_equal_to(vec![DependencyUpdate {
+        name: "pest".to_string(),
+        from: None,
+        to: "2.5".to_string(),
+    }]);
+    assert_that(&dependabot.dependency_updates()).is_equal_to(vec![DependencyUpdate {
+        name: "lodash".to_string(),
+        from: Some("4.17.20".to_string()),
+        to: "4.17.21".to_string(),
+    }]);
+}
+
+#[cfg(feature = "dependabot")]
+#[test]
+fn should_decode_updated_dependencies_footer() {
+    use conventional_commit_parser::dependency::dependabot::{
+        DependencyType, UpdateType, UpdatedDependency,
+    };
+
+    // Arrange
+    let commit_message = indoc!(
+        "chore(deps): bump the maven group with 2 updates (#11)
+
+        ---
+        updated-dependencies:\r- dependency-name: com.tngtech.archunit:archunit-junit5-engine
+          dependency-type: direct:production
+          update-type: version-update:semver-minor
+          dependency-group: maven
+        - dependency-name: org.junit:junit-bom
+          dependency-type: direct:development
+          update-type: version-update:semver-major
+          dependency-group: maven
+        ...
+
+        Signed-off-by: dependabot[bot] <support@github.com>"
+    );
+
+    // Act
+    let dependencies = parse(commit_message).unwrap().updated_dependencies();
+
+    // Assert
+    assert_that(&dependencies).is_ok().is_equal_to(vec![
+        UpdatedDependency {
+            dependency_name: "com.tngtech.archunit:archunit-junit5-engine".to_string(),
+            dependency_type: Some(DependencyType::DirectProduction),
+            update_type: Some(UpdateType::SemverMinor),
+            dependency_group: Some("maven".to_string()),
+        },
+        UpdatedDependency {
+            dependency_name: "org.junit:junit-bom".to_string(),
+            dependency_type: Some(DependencyType::DirectDevelopment),
+            update_type: Some(UpdateType::SemverMajor),
+            dependency_group: Some("maven".to_string()),
+        },
+    ]);
+}
+
+#[cfg(feature = "dependabot")]
+#[test]
+fn malformed_updated_dependencies_footer_should_fail() {
+    // Act
+    let dependencies = parse("chore(deps): bump pest\n\nupdated-dependencies: [oops")
+        .unwrap()
+        .updated_dependencies();
+
+    // Assert
+    assert_that(&dependencies).is_err();
+}
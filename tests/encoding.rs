@@ -0,0 +1,175 @@
+use conventional_commit_parser::encoding::MessageEncoding;
+use conventional_commit_parser::error::ParseErrorKind;
+use conventional_commit_parser::options::ParserOptions;
+use conventional_commit_parser::{parse_bytes, parse_git_raw};
+use speculoos::prelude::*;
+
+mod assertions;
+use assertions::*;
+
+#[test]
+fn strict_utf8_should_reject_latin1_bytes() {
+    // Arrange
+    let message = b"fix: r\xe9sum\xe9 typo";
+
+    // Act
+    let parsed = parse_bytes(message, &ParserOptions::default());
+
+    // Assert
+    assert_error(&parsed, ParseErrorKind::InvalidEncoding);
+}
+
+#[test]
+fn strict_utf8_should_not_reencode_valid_message() {
+    // Arrange
+    let message = "fix: résumé typo".as_bytes();
+
+    // Act
+    let parsed = parse_bytes(message, &ParserOptions::default());
+
+    // Assert
+    assert_that(&parsed)
+        .is_ok()
+        .map(|decoded| &decoded.reencoded)
+        .is_false();
+}
+
+#[test]
+fn lossy_utf8_should_replace_latin1_bytes() {
+    // Arrange
+    let message = b"fix: r\xe9sum\xe9 typo";
+    let options = ParserOptions {
+        encoding: MessageEncoding::Utf8Lossy,
+        ..Default::default()
+    };
+
+    // Act
+    let parsed = parse_bytes(message, &options).unwrap();
+
+    // Assert
+    assert_that(&parsed.commit.summary).is_equal_to("r\u{FFFD}sum\u{FFFD} typo".to_string());
+    assert_that(&parsed.reencoded).is_true();
+}
+
+#[cfg(feature = "encoding_rs")]
+#[test]
+fn explicit_encoding_should_decode_latin1_bytes() {
+    // Arrange
+    let message = b"fix: r\xe9sum\xe9 typo";
+    let options = ParserOptions {
+        encoding: MessageEncoding::Explicit(encoding_rs::WINDOWS_1252),
+        ..Default::default()
+    };
+
+    // Act
+    let parsed = parse_bytes(message, &options).unwrap();
+
+    // Assert
+    assert_that(&parsed.commit.summary).is_equal_to("résumé typo".to_string());
+    assert_that(&parsed.reencoded).is_true();
+}
+
+#[test]
+fn raw_message_larger_than_max_len_should_fail_before_decoding() {
+    // Arrange
+    let options = ParserOptions {
+        max_message_len: Some(8),
+        ..Default::default()
+    };
+
+    // Act
+    let result = parse_bytes(b"feat: \xFF invalid utf-8", &options);
+
+    // Assert
+    assert_that(&result.unwrap_err().kind).is_equal_to(ParseErrorKind::MessageTooLarge {
+        limit: 8,
+        actual: 21,
+    });
+}
+
+#[test]
+fn should_skip_signature_block_after_raw_message() {
+    // Arrange
+    let object = indoc::indoc!(
+        "tree 9bedf67800b2923982bdf60c89c57ce6b1c3ac1b
+        parent 4dd3c95a1b2c3d4e5f60718293a4b5c6d7e8f901
+        parent bdca127c2e3f4a5b6c7d8e9f0a1b2c3d4e5f6a7b
+        author Jane Doe <jane@example.org> 1658150000 +0200
+        committer Jane Doe <jane@example.org> 1658150000 +0200
+        mergetag object 4dd3c95a1b2c3d4e5f60718293a4b5c6d7e8f901
+         type commit
+         tag v1.0.0
+
+        fix(parser): skip signatures
+
+        Refs: #12
+        -----BEGIN SSH SIGNATURE-----
+        U1NIU0lHAAAAAQ==
+        -----END SSH SIGNATURE-----
+        "
+    );
+
+    // Act
+    let raw = parse_git_raw(object.as_bytes(), &ParserOptions::default()).unwrap();
+
+    // Assert
+    assert_that(&raw.is_signed).is_true();
+    assert_that(&raw.parents).has_length(2);
+    assert_that(&raw.committer).is_equal_to(Some(
+        "Jane Doe <jane@example.org> 1658150000 +0200".to_string(),
+    ));
+    assert_that(&raw.commit.footers[0].content).is_equal_to("#12".to_string());
+}
+
+#[test]
+fn should_parse_unsigned_raw_object_and_plain_message() {
+    // Arrange
+    let object = b"tree 9bedf67800b2923982bdf60c89c57ce6b1c3ac1b\n\nfeat: unsigned\n";
+
+    // Act
+    let raw = parse_git_raw(object, &ParserOptions::default()).unwrap();
+    let plain = parse_git_raw(b"docs: plain message", &ParserOptions::default()).unwrap();
+    let empty = parse_git_raw(
+        b"tree 9bedf67800b2923982bdf60c89c57ce6b1c3ac1b\n",
+        &Default::default(),
+    );
+
+    // Assert
+    assert_that(&raw.is_signed).is_false();
+    assert_that(&raw.parents).is_empty();
+    assert_that(&plain.commit.summary).is_equal_to("plain message".to_string());
+    assert_that(&empty.map_err(|error| error.kind))
+        .is_err_containing(ParseErrorKind::EmptyCommitMessage);
+}
+
+#[cfg(feature = "encoding_rs")]
+#[test]
+fn raw_commit_should_honor_encoding_header() {
+    // Arrange
+    let object = b"tree 9bedf67800b2923982bdf60c89c57ce6b1c3ac1b\n\
+        author Ren\xe9 <rene@example.org> 1658150000 +0200\n\
+        encoding ISO-8859-1\n\
+        \n\
+        fix: r\xe9sum\xe9 typo\n";
+
+    // Act
+    let raw = parse_git_raw(object, &ParserOptions::default()).unwrap();
+
+    // Assert
+    assert_that(&raw.encoding).is_equal_to(Some("ISO-8859-1".to_string()));
+    assert_that(&raw.commit.summary).is_equal_to("résumé typo".to_string());
+    assert_that(&raw.reencoded).is_true();
+}
+
+#[test]
+fn raw_commit_without_encoding_header_should_use_options_encoding() {
+    // Arrange
+    let object = b"tree 9bedf67800b2923982bdf60c89c57ce6b1c3ac1b\n\nfix: r\xe9sum\xe9 typo\n";
+
+    // Act
+    let raw = parse_git_raw(object, &ParserOptions::default());
+
+    // Assert
+    assert_that(&raw.map_err(|error| error.kind))
+        .is_err_containing(ParseErrorKind::InvalidEncoding);
+}
@@ -0,0 +1,59 @@
+use conventional_commit_parser::{parse, parse_summary};
+use speculoos::prelude::*;
+
+/// One-line messages, both plain headers taking the fast path and unusual ones
+/// falling back to the grammar
+const HEADERS: [&str; 20] = [
+    "feat: add a fast path",
+    "fix(parser): handle tabs",
+    "feat(parser)!: drop parse_summary",
+    "feat!: drop parse_summary",
+    "Feat(Parser): upper case type",
+    "wip: custom type",
+    "fix(ui/button): nested scope",
+    "fix(a:b): colon in scope",
+    "fix: \ttab before the description",
+    "fix: trailing whitespace  ",
+    "fix: summary with (parenthesis): and colons",
+    "fix(é): unicode scope",
+    "feat!(parser): mark before scope",
+    "feat!!: double mark",
+    "feat(): empty scope",
+    "feat(a b): whitespace in scope",
+    "feat(parser: unclosed scope",
+    "feat:missing space",
+    "feat:  ",
+    "fe4t: digit in type",
+];
+
+#[test]
+fn one_line_messages_should_parse_like_the_grammar() {
+    for header in HEADERS {
+        // Act
+        let one_line = parse(header);
+        // A trailing line break always goes through the grammar
+        let grammar = parse_summary(&format!("{}\n", header));
+
+        // Assert
+        match (one_line, grammar) {
+            (Ok(one_line), Ok(grammar)) => assert!(one_line.raw_eq(&grammar), "{}", header),
+            (Err(one_line), Err(grammar)) => assert_that(&one_line.kind).is_equal_to(grammar.kind),
+            (one_line, grammar) => panic!("{}: {:?} != {:?}", header, one_line, grammar),
+        }
+    }
+}
+
+#[test]
+fn one_line_summaries_should_parse_like_messages() {
+    for header in HEADERS {
+        // Act
+        let summary = parse_summary(header);
+        let message = parse(header);
+
+        // Assert
+        match (summary, message) {
+            (Ok(summary), Ok(message)) => assert!(summary.raw_eq(&message), "{}", header),
+            (summary, message) => assert_that(&summary.is_err()).is_equal_to(message.is_err()),
+        }
+    }
+}
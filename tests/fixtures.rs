@@ -0,0 +1,80 @@
+#![cfg(feature = "fixtures")]
+#![allow(clippy::result_large_err)]
+
+use std::collections::HashSet;
+
+use conventional_commit_parser::fixtures::{corpus, run, Category};
+use conventional_commit_parser::options::ParserOptions;
+use conventional_commit_parser::{parse, parse_with_options};
+use speculoos::prelude::*;
+
+#[test]
+fn parser_should_conform_to_fixture_corpus() {
+    // Act
+    let mismatches = run(parse);
+
+    // Assert
+    assert_that(&mismatches).is_empty();
+}
+
+#[test]
+fn corpus_should_cover_every_category_with_unique_names() {
+    // Arrange
+    let corpus = corpus();
+
+    // Act
+    let names: HashSet<&str> = corpus.iter().map(|fixture| fixture.name).collect();
+    let categories: HashSet<Category> = corpus.iter().map(|fixture| fixture.category).collect();
+
+    // Assert
+    assert_that(&names.len()).is_equal_to(corpus.len());
+    assert_that(&categories).contains_all_of(&[
+        &Category::Dependabot,
+        &Category::Renovate,
+        &Category::Merge,
+        &Category::Gitmoji,
+        &Category::Unicode,
+    ]);
+}
+
+#[test]
+fn should_report_mismatching_fixtures() {
+    // Arrange
+    let options = ParserOptions {
+        max_message_len: Some(40),
+        ..Default::default()
+    };
+
+    // Act
+    let mismatches = run(|message| parse_with_options(message, &options));
+
+    // Assert
+    let too_large = corpus()
+        .iter()
+        .filter(|fixture| fixture.message.len() > 40)
+        .count();
+    assert_that(&mismatches).has_length(too_large);
+    assert_that(&mismatches[0].to_string()).starts_with("fixture `dependabot-maven`: expected Ok(");
+}
+
+#[test]
+#[cfg(feature = "json")]
+fn checked_in_conformance_vectors_should_be_up_to_date() {
+    // Arrange
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/conformance/vectors.json");
+    let exported = format!(
+        "{}\n",
+        serde_json::to_string_pretty(&conventional_commit_parser::fixtures::to_json()).unwrap()
+    );
+
+    // Regenerate with `UPDATE_CONFORMANCE_VECTORS=1 cargo test --all-features`
+    if std::env::var_os("UPDATE_CONFORMANCE_VECTORS").is_some() {
+        std::fs::write(path, &exported).unwrap();
+    }
+
+    // Act
+    let checked_in = std::fs::read_to_string(path).unwrap();
+
+    // Assert
+    assert_that(&checked_in).is_equal_to(&exported);
+}
@@ -0,0 +1,109 @@
+use conventional_commit_parser::commit::Footer;
+use conventional_commit_parser::error::ValueError;
+use conventional_commit_parser::value::{Date, DateTime, Identity, Sha, Url, Version};
+use speculoos::prelude::*;
+
+fn footer(content: &str) -> Footer {
+    Footer {
+        token: "a-token".to_string(),
+        content: content.to_string(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn should_parse_semver_footer_value() {
+    // Act
+    let version = footer("v1.2.3-alpha.1+build.5").value_as::<Version>();
+
+    // Assert
+    assert_that(&version).is_ok().is_equal_to(Version {
+        major: 1,
+        minor: 2,
+        patch: 3,
+        pre: Some("alpha.1".to_string()),
+        build: Some("build.5".to_string()),
+    });
+}
+
+#[test]
+fn should_reject_invalid_semver_footer_value() {
+    // Act
+    let version = footer("1.02").value_as::<Version>();
+
+    // Assert
+    assert_that(&version)
+        .is_err()
+        .is_equal_to(ValueError::InvalidVersion("1.02".to_string()));
+}
+
+#[test]
+fn should_parse_date_footer_value() {
+    // Act
+    let date = footer("2024-02-29").value_as::<Date>();
+    let invalid = footer("2025-02-29").value_as::<Date>();
+
+    // Assert
+    assert_that(&date).is_ok().is_equal_to(Date {
+        year: 2024,
+        month: 2,
+        day: 29,
+    });
+    assert_that(&invalid).is_err();
+}
+
+#[test]
+fn should_parse_date_time_footer_value() {
+    // Act
+    let date_time = footer("2025-01-01T10:30:00.5+02:00").value_as::<DateTime>();
+
+    // Assert
+    let date_time = date_time.unwrap();
+    assert_that(&date_time.hour).is_equal_to(10);
+    assert_that(&date_time.nanosecond).is_equal_to(500_000_000);
+    assert_that(&date_time.offset_minutes).is_equal_to(120);
+    assert_that(&date_time.to_string()).is_equal_to("2025-01-01T10:30:00.5+02:00".to_string());
+}
+
+#[test]
+fn should_parse_url_footer_value() {
+    // Act
+    let url = footer("https://github.com:443/oknozor/cocogitto/issues/1").value_as::<Url>();
+
+    // Assert
+    assert_that(&url).is_ok().is_equal_to(Url {
+        scheme: "https".to_string(),
+        host: "github.com".to_string(),
+        port: Some(443),
+        path: "/oknozor/cocogitto/issues/1".to_string(),
+    });
+}
+
+#[test]
+fn should_parse_identity_footer_value() {
+    // Act
+    let identity = footer("dependabot[bot] <support@github.com>").value_as::<Identity>();
+    let invalid = footer("dependabot[bot]").value_as::<Identity>();
+
+    // Assert
+    assert_that(&identity).is_ok().is_equal_to(Identity {
+        name: "dependabot[bot]".to_string(),
+        email: "support@github.com".to_string(),
+    });
+    assert_that(&invalid).is_err();
+}
+
+#[test]
+fn should_parse_and_normalize_sha_footer_value() {
+    // Act
+    let full = footer("EA3237FD0E9A1C2B3D4E5F60718293A4B5C6D7E8").value_as::<Sha>();
+    let short = footer("ea3237f").value_as::<Sha>();
+    let invalid = footer("ea3237g").value_as::<Sha>();
+
+    // Assert
+    let full = full.unwrap();
+    assert_that(&full.as_str()).is_equal_to("ea3237fd0e9a1c2b3d4e5f60718293a4b5c6d7e8");
+    assert_that(&full.is_full()).is_true();
+    assert_that(&short.unwrap().is_full()).is_false();
+    assert_that(&invalid).is_err_containing(ValueError::InvalidSha("ea3237g".to_string()));
+}
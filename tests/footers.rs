@@ -1,4 +1,8 @@
-use conventional_commit_parser::commit::{Footer, Separator};
+use conventional_commit_parser::commit::{
+    BotSignature, BreakingOrigin, Footer, FormatOptions, SecurityAdvisory, Separator, SignOffCheck,
+};
+use conventional_commit_parser::error::ValueError;
+use conventional_commit_parser::value::Identity;
 use indoc::indoc;
 use speculoos::prelude::*;
 
@@ -56,3 +60,278 @@ pub fn parse_footer_with_new_lines() {
         token_separator: Separator::ColonWithNewLine,
     }]);
 }
+
+#[test]
+pub fn parse_deprecation_and_security_footers() {
+    // Arrange
+    let message = indoc!(
+        "fix(auth): reject expired tokens
+
+        Deprecates: legacy token endpoint
+        Security: GHSA-xxxx-yyyy-zzzz
+        cve: CVE-2022-0001
+        Refs #42"
+    );
+
+    // Act
+    let parsed = conventional_commit_parser::parse(message).unwrap();
+
+    // Assert
+    assert_that(&parsed.deprecations()).is_equal_to(vec!["legacy token endpoint"]);
+    assert_that(&parsed.security_advisories()).is_equal_to(vec![
+        SecurityAdvisory::Security("GHSA-xxxx-yyyy-zzzz"),
+        SecurityAdvisory::Cve("CVE-2022-0001"),
+    ]);
+    assert_that(&parsed.is_security_fix()).is_true();
+}
+
+#[test]
+fn should_detect_bot_signatures() {
+    // Arrange
+    let commit_message = indoc!(
+        "chore(deps): update rust crate pest to 2.5
+
+        Signed-off-by: Jane Doe <jane@example.com>
+        Co-authored-by: renovate[bot] <29139614+renovate[bot]@users.noreply.github.com>"
+    );
+
+    // Act
+    let parsed = conventional_commit_parser::parse(commit_message).unwrap();
+    let human = conventional_commit_parser::parse(
+        "fix: typo\n\nSigned-off-by: Jane Doe <jane@example.com>",
+    )
+    .unwrap();
+    let other =
+        conventional_commit_parser::parse("ci: release\n\nCo-authored-by: release-please[bot]")
+            .unwrap();
+
+    // Assert
+    assert_that(&parsed.bot_signature()).is_equal_to(Some(BotSignature::Renovate));
+    assert_that(&human.bot_signature()).is_none();
+    assert_that(&other.bot_signature())
+        .is_equal_to(Some(BotSignature::Other("release-please[bot]")));
+}
+
+#[test]
+fn footers_should_round_trip_with_their_separator() {
+    // Arrange
+    let footers = indoc!(
+        "Reviewed-by: Z
+        Refs #133
+        BREAKING CHANGE:
+        parse_summary now returns a Result"
+    );
+
+    // Act
+    let parsed = conventional_commit_parser::parse_footers(footers).unwrap();
+    let rendered: Vec<String> = parsed.iter().map(Footer::to_string).collect();
+
+    // Assert
+    assert_that(&rendered.join("\n")).is_equal_to(footers.to_string());
+}
+
+#[test]
+fn commit_should_round_trip_with_hash_separator() {
+    // Arrange
+    let message = indoc!(
+        "fix(parser): correct minor typos in code
+
+        Closes #133
+        Reviewed-by: Z"
+    );
+
+    // Act
+    let parsed = conventional_commit_parser::parse(message).unwrap();
+
+    // Assert
+    assert_that(&parsed.to_string()).is_equal_to(message.to_string());
+}
+
+#[test]
+fn format_should_force_footer_separator() {
+    // Arrange
+    let parsed =
+        conventional_commit_parser::parse("fix: typo\n\nCloses #133\nReviewed-by: Z").unwrap();
+    let options = FormatOptions {
+        footer_separator: Some(Separator::Hash),
+        ..Default::default()
+    };
+
+    // Act
+    let formatted = parsed.format(&options);
+
+    // Assert
+    assert_that(&formatted).is_equal_to("fix: typo\n\nCloses #133\nReviewed-by #Z".to_string());
+    assert_that(
+        &conventional_commit_parser::parse(&formatted)
+            .unwrap()
+            .footers,
+    )
+    .has_length(2);
+}
+
+#[test]
+fn should_extract_breaking_change_migration_footer() {
+    // Arrange
+    let commit_message = indoc!(
+        "feat!: drop parse_summary
+
+        BREAKING CHANGE: parse_summary was removed
+        Migration: use parse instead
+        Refs #133"
+    );
+
+    // Act
+    let parsed = conventional_commit_parser::parse(commit_message).unwrap();
+    let mark_only = conventional_commit_parser::parse("feat!: drop parse_summary").unwrap();
+
+    // Assert
+    assert_that(&parsed.breaking_change_description())
+        .is_equal_to(Some("parse_summary was removed"));
+    assert_that(&parsed.breaking_change_migration()).is_equal_to(Some("use parse instead"));
+    assert_that(&mark_only.breaking_change_description()).is_equal_to(Some("drop parse_summary"));
+    assert_that(&mark_only.breaking_change_migration()).is_none();
+}
+
+#[test]
+fn should_convert_separator_from_and_to_string() {
+    // Act
+    let separators: Vec<Result<Separator, _>> = vec![": ", " #", ":\n", "hash", "colon", "-"]
+        .into_iter()
+        .map(str::parse)
+        .collect();
+
+    // Assert
+    assert_that(&separators).is_equal_to(vec![
+        Ok(Separator::Colon),
+        Ok(Separator::Hash),
+        Ok(Separator::ColonWithNewLine),
+        Ok(Separator::Hash),
+        Ok(Separator::Colon),
+        Err(ValueError::InvalidSeparator("-".to_string())),
+    ]);
+    assert_that(&Separator::Hash.to_string()).is_equal_to(" #".to_string());
+}
+
+#[cfg(all(feature = "serde", feature = "json"))]
+#[test]
+fn should_serialize_separator_symbolically() {
+    // Act
+    let serialized = serde_json::to_string(&Separator::ColonWithNewLine).unwrap();
+    let deserialized: Vec<Separator> = serde_json::from_str(r#"["colon", " #"]"#).unwrap();
+
+    // Assert
+    assert_that(&serialized).is_equal_to(r#"":\n""#.to_string());
+    assert_that(&deserialized).is_equal_to(vec![Separator::Colon, Separator::Hash]);
+}
+
+#[test]
+fn should_find_footer_ignoring_case_and_title_case_tokens_on_render() {
+    // Arrange
+    let parsed = conventional_commit_parser::parse(indoc!(
+        "fix: typo
+
+        signed-off-by: Z <z@example.org>
+        CO-AUTHORED-BY: Y <y@example.org>
+        BREAKING CHANGE: removed"
+    ))
+    .unwrap();
+    let options = FormatOptions {
+        title_case_tokens: true,
+        ..Default::default()
+    };
+
+    // Act
+    let footer = parsed.footer_ci("Co-authored-by");
+    let formatted = parsed.format(&options);
+
+    // Assert
+    assert_that(&footer.map(|footer| footer.token.as_str())).is_equal_to(Some("CO-AUTHORED-BY"));
+    assert_that(&parsed.footer_ci("Refs")).is_none();
+    assert_that(&formatted).is_equal_to(
+        indoc!(
+            "fix: typo
+
+            Signed-off-by: Z <z@example.org>
+            Co-authored-by: Y <y@example.org>
+            BREAKING CHANGE: removed"
+        )
+        .to_string(),
+    );
+}
+
+#[test]
+fn should_report_breaking_change_origin() {
+    // Arrange
+    let both = indoc!(
+        "feat!: drop parse_summary
+
+        BREAKING CHANGE: use parse instead"
+    );
+
+    // Act
+    let mark = conventional_commit_parser::parse("feat!: drop parse_summary").unwrap();
+    let footer =
+        conventional_commit_parser::parse("feat: drop parse_summary\n\nBREAKING-CHANGE: use parse")
+            .unwrap();
+    let both = conventional_commit_parser::parse(both).unwrap();
+    let none = conventional_commit_parser::parse("feat: add parse").unwrap();
+
+    // Assert
+    assert_that(&mark.breaking_change_origin()).is_equal_to(Some(BreakingOrigin::Mark));
+    assert_that(&footer.breaking_change_origin()).is_equal_to(Some(BreakingOrigin::Footer));
+    assert_that(&both.breaking_change_origin()).is_equal_to(Some(BreakingOrigin::Both));
+    assert_that(&none.breaking_change_origin()).is_none();
+    assert_that(&both.to_string())
+        .is_equal_to("feat!: drop parse_summary\n\nBREAKING CHANGE: use parse instead".to_string());
+}
+
+#[test]
+fn should_check_author_sign_off() {
+    // Arrange
+    let signed = conventional_commit_parser::parse(indoc!(
+        "fix: typo
+
+        signed-off-by: Bot
+        Signed-off-by: Jane Doe <jane@example.org>"
+    ))
+    .unwrap();
+    let unsigned = conventional_commit_parser::parse("fix: typo\n\nReviewed-by: Z").unwrap();
+
+    // Act
+    let matching = signed.check_sign_off("Jane Doe", "jane@example.org");
+    let mismatch = signed.check_sign_off("Jane", "jane@example.org");
+    let missing = unsigned.check_sign_off("Jane Doe", "jane@example.org");
+
+    // Assert
+    assert_that(&matching).is_equal_to(SignOffCheck::Signed);
+    assert_that(&mismatch).is_equal_to(SignOffCheck::Mismatch(vec![Identity {
+        name: "Jane Doe".to_string(),
+        email: "jane@example.org".to_string(),
+    }]));
+    assert_that(&missing).is_equal_to(SignOffCheck::Missing);
+}
+
+#[test]
+fn should_split_trailers_from_breaking_change_footers() {
+    // Arrange
+    let commit = conventional_commit_parser::parse(indoc!(
+        "feat!: drop v1
+
+        Reviewed-by: Z
+        BREAKING-CHANGE: v1 is gone
+        Refs #12"
+    ))
+    .unwrap();
+
+    // Act
+    let trailers: Vec<&str> = commit.trailers().map(|f| f.token.as_str()).collect();
+    let metadata: Vec<&str> = commit
+        .metadata_footers()
+        .map(|f| f.token.as_str())
+        .collect();
+
+    // Assert
+    assert_that(&trailers).is_equal_to(vec!["Reviewed-by", "Refs"]);
+    assert_that(&metadata).is_equal_to(vec!["BREAKING-CHANGE"]);
+}
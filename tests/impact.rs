@@ -0,0 +1,53 @@
+use conventional_commit_parser::impact::{Impact, ImpactLevel, ImpactWeights};
+use conventional_commit_parser::parse;
+use speculoos::prelude::*;
+
+#[test]
+fn breaking_change_should_have_high_impact() {
+    // Arrange
+    let weights = ImpactWeights::default();
+
+    // Act
+    let impact = parse("feat!: drop parse_summary").unwrap().impact(&weights);
+
+    // Assert
+    assert_that(&impact).is_equal_to(Impact {
+        score: 70,
+        level: ImpactLevel::High,
+    });
+}
+
+#[test]
+fn chore_should_have_low_impact() {
+    // Arrange
+    let weights = ImpactWeights::default();
+
+    // Act
+    let impact = parse("chore: update gitignore").unwrap().impact(&weights);
+
+    // Assert
+    assert_that(&impact).is_equal_to(Impact {
+        score: 1,
+        level: ImpactLevel::Low,
+    });
+}
+
+#[test]
+fn scope_weight_should_raise_impact() {
+    // Arrange
+    let mut weights = ImpactWeights::default();
+    weights.scopes.insert("security".to_string(), 40);
+
+    // Act
+    let scoped = parse("docs(security): document token rotation")
+        .unwrap()
+        .impact(&weights);
+    let unscoped = parse("docs: document token rotation")
+        .unwrap()
+        .impact(&weights);
+
+    // Assert
+    assert_that(&scoped.level).is_equal_to(ImpactLevel::Medium);
+    assert_that(&scoped.score).is_equal_to(41);
+    assert_that(&unscoped.level).is_equal_to(ImpactLevel::Low);
+}
@@ -0,0 +1,93 @@
+#![cfg(feature = "instrumentation")]
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use conventional_commit_parser::error::ParseErrorKind;
+use conventional_commit_parser::instrumentation::{
+    parse_instrumented, ParseObserver, ParseSection, SectionProfile,
+};
+use indoc::indoc;
+use speculoos::prelude::*;
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+#[derive(Default)]
+struct Recorder {
+    profiles: Vec<SectionProfile>,
+}
+
+impl ParseObserver for Recorder {
+    fn section(&mut self, profile: SectionProfile) {
+        self.profiles.push(profile);
+    }
+
+    fn allocations(&self) -> Option<usize> {
+        Some(ALLOCATIONS.load(Ordering::Relaxed))
+    }
+}
+
+#[test]
+fn should_report_every_parse_section_with_allocations() {
+    // Arrange
+    let message = indoc!(
+        "feat(parser): add instrumentation
+
+        Report the cost of each parse section.
+
+        Refs: #12
+        (cherry picked from commit 1c6c84a8f2ef4e2e0a1e7b2e8f1c9b0a1c2d3e4f)"
+    );
+    let mut recorder = Recorder {
+        profiles: Vec::with_capacity(8),
+    };
+
+    // Act
+    let commit = parse_instrumented(message, &mut recorder).unwrap();
+
+    // Assert
+    let sections: Vec<ParseSection> = recorder.profiles.iter().map(|p| p.section).collect();
+    assert_that(&sections).is_equal_to(vec![
+        ParseSection::Grammar,
+        ParseSection::Header,
+        ParseSection::Body,
+        ParseSection::Footers,
+        ParseSection::PostProcessing,
+    ]);
+    assert_that(&commit).is_equal_to(conventional_commit_parser::parse(message).unwrap());
+    assert_that(&recorder.profiles.iter().all(|p| p.allocations.is_some())).is_true();
+    assert_that(&recorder.profiles[1].allocations.unwrap()).is_greater_than(0);
+}
+
+#[test]
+fn should_stop_reporting_on_parse_error() {
+    // Arrange
+    let mut sections = vec![];
+
+    // Act
+    let error = parse_instrumented(
+        "feat add instrumentation",
+        &mut |profile: SectionProfile| sections.push(profile.section),
+    )
+    .unwrap_err();
+
+    // Assert
+    assert_that(&error.kind).is_equal_to(ParseErrorKind::MissingSeparator);
+    assert_that(&sections).is_empty();
+}
@@ -0,0 +1,60 @@
+use conventional_commit_parser::issue::ClosedIssue;
+use conventional_commit_parser::parse;
+use indoc::indoc;
+use speculoos::prelude::*;
+
+#[test]
+fn should_find_closed_issues_in_summary_body_and_footers() {
+    // Arrange
+    let commit = parse(indoc!(
+        "fix(parser): handle tabs, closes #10
+
+        This also Fixes oknozor/cocogitto#2, #11 and
+        resolves: ABC-3. The parser is mentioned in #12.
+
+        Reviewed-by: Z
+        Fixes: #13, org/repo#14
+        Closes #10"
+    ))
+    .unwrap();
+
+    // Act
+    let issues = commit.closed_issues();
+
+    // Assert
+    assert_that(&issues).is_equal_to(vec![
+        ClosedIssue::Number {
+            repository: None,
+            number: 10,
+        },
+        ClosedIssue::Number {
+            repository: Some("oknozor/cocogitto".to_string()),
+            number: 2,
+        },
+        ClosedIssue::Number {
+            repository: None,
+            number: 11,
+        },
+        ClosedIssue::Key("ABC-3".to_string()),
+        ClosedIssue::Number {
+            repository: None,
+            number: 13,
+        },
+        ClosedIssue::Number {
+            repository: Some("org/repo".to_string()),
+            number: 14,
+        },
+    ]);
+}
+
+#[test]
+fn should_ignore_references_without_closing_keyword() {
+    // Arrange
+    let commit = parse("feat: add closed issues\n\nRefs #10\nSee ABC-3").unwrap();
+
+    // Act
+    let issues = commit.closed_issues();
+
+    // Assert
+    assert_that(&issues).is_empty();
+}
@@ -0,0 +1,550 @@
+use conventional_commit_parser::commit::semantically_equal;
+use conventional_commit_parser::lint::{
+    lint, lint_all, normalize, FooterSchema, FooterValue, LintConfig, LintRule,
+};
+use conventional_commit_parser::options::Preset;
+use conventional_commit_parser::{parse, parse_with_options};
+use indoc::indoc;
+use speculoos::prelude::*;
+
+#[test]
+fn angular_preset_should_report_violations() {
+    // Arrange
+    let preset = Preset::Angular;
+    let message = indoc!(
+        "chore(deps): Bump pest.
+
+        This body line is way too long for the angular convention which limits lines to one hundred characters.
+        "
+    );
+
+    // Act
+    let commit = parse_with_options(message, &preset.parser_options()).unwrap();
+    let violations = lint(&commit, &preset.lint_config());
+
+    // Assert
+    let rules: Vec<LintRule> = violations.into_iter().map(|v| v.rule).collect();
+    assert_that(&rules).has_length(4);
+    assert_that(&rules).contains(LintRule::SummaryLowerCase);
+    assert_that(&rules).contains(LintRule::SummaryNoTrailingPeriod);
+    assert_that(&rules).contains(LintRule::BodyMaxLineLength(100));
+}
+
+#[test]
+fn angular_preset_should_accept_angular_commit() {
+    // Arrange
+    let preset = Preset::Angular;
+
+    // Act
+    let commit =
+        parse_with_options("fix(parser): handle empty scopes", &preset.parser_options()).unwrap();
+
+    // Assert
+    assert_that(&lint(&commit, &preset.lint_config())).is_empty();
+}
+
+#[test]
+fn lenient_preset_should_accept_bracketed_scope() {
+    // Arrange
+    let preset = Preset::Lenient;
+
+    // Act
+    let commit = parse_with_options("feat: [cli] add presets", &preset.parser_options()).unwrap();
+
+    // Assert
+    assert_that(&commit.scope).is_equal_to(Some("cli".to_string()));
+    assert_that(&lint(&commit, &preset.lint_config())).is_empty();
+}
+
+#[test]
+fn header_max_length_should_report_actual_length() {
+    // Arrange
+    let config = LintConfig {
+        rules: vec![LintRule::HeaderMaxLength(10)],
+    };
+
+    // Act
+    let violations = lint(&parse("feat(cli): add presets").unwrap(), &config);
+
+    // Assert
+    assert_that(&violations[0].message)
+        .is_equal_to("Commit header is too long (22 characters, maximum is 10)".to_string());
+}
+
+#[test]
+fn should_aggregate_batch_violations() {
+    // Arrange
+    let config = LintConfig {
+        rules: vec![
+            LintRule::ScopeRequired,
+            LintRule::SummaryLowerCase,
+            LintRule::SummaryNoTrailingPeriod,
+        ],
+    };
+    let commits = vec![
+        parse("feat(lint): add lint_all").unwrap(),
+        parse("fix: Typo.").unwrap(),
+        parse("docs: document lint_all").unwrap(),
+        parse("feat(lint): Add worst offenders").unwrap(),
+    ];
+
+    // Act
+    let report = lint_all(commits, &config);
+
+    // Assert
+    assert_that(&report.is_success()).is_false();
+    assert_that(&report.passed).is_equal_to(1);
+    assert_that(&report.failed).is_equal_to(3);
+    assert_that(
+        &report
+            .commits
+            .iter()
+            .map(|commit| (commit.index, commit.is_success()))
+            .collect::<Vec<_>>(),
+    )
+    .is_equal_to(vec![(0, true), (1, false), (2, false), (3, false)]);
+    assert_that(
+        &report
+            .rules
+            .iter()
+            .map(|stats| stats.violations)
+            .collect::<Vec<_>>(),
+    )
+    .is_equal_to(vec![2, 2, 1]);
+    assert_that(
+        &report
+            .worst_offenders(2)
+            .iter()
+            .map(|commit| commit.index)
+            .collect::<Vec<_>>(),
+    )
+    .is_equal_to(vec![1, 2]);
+}
+
+#[cfg(all(feature = "serde", feature = "json"))]
+#[test]
+fn should_serialize_lint_report() {
+    use conventional_commit_parser::commit::CommitType;
+
+    // Arrange
+    let config = LintConfig {
+        rules: vec![LintRule::TypeEnum(vec![CommitType::Feature])],
+    };
+
+    // Act
+    let report = lint_all(vec![parse("fix: typo").unwrap()], &config);
+    let value = serde_json::to_value(&report).unwrap();
+
+    // Assert
+    assert_that(&value["failed"].as_u64()).is_equal_to(Some(1));
+    assert_that(&value["commits"][0]["header"].as_str()).is_equal_to(Some("fix: typo"));
+    assert_that(&value["rules"][0]["rule"]["TypeEnum"][0].as_str()).is_equal_to(Some("feat"));
+}
+
+#[cfg(feature = "sarif")]
+#[test]
+fn should_render_lint_report_as_sarif() {
+    use conventional_commit_parser::lint::sarif::SarifOptions;
+
+    // Arrange
+    let config = LintConfig {
+        rules: vec![LintRule::ScopeRequired, LintRule::HeaderMaxLength(20)],
+    };
+    let report = lint_all(
+        vec![
+            parse("feat(lint): ok").unwrap(),
+            parse("fix: a summary too long").unwrap(),
+        ],
+        &config,
+    );
+    let options = SarifOptions {
+        artifact_uri: "COMMIT_MSG".to_string(),
+        ..Default::default()
+    };
+
+    // Act
+    let sarif: serde_json::Value = serde_json::from_str(&report.to_sarif_string(&options)).unwrap();
+
+    // Assert
+    let run = &sarif["runs"][0];
+    assert_that(&run["tool"]["driver"]["rules"].as_array().map(Vec::len)).is_equal_to(Some(2));
+    assert_that(&run["results"].as_array().map(Vec::len)).is_equal_to(Some(2));
+    assert_that(&run["results"][1]["ruleId"].as_str()).is_equal_to(Some("header-max-length"));
+    assert_that(&run["results"][1]["ruleIndex"].as_u64()).is_equal_to(Some(1));
+    assert_that(&run["results"][1]["properties"]["commitIndex"].as_u64()).is_equal_to(Some(1));
+    assert_that(
+        &run["results"][0]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"].as_str(),
+    )
+    .is_equal_to(Some("COMMIT_MSG"));
+}
+
+#[test]
+fn should_render_lint_report_as_junit() {
+    use conventional_commit_parser::lint::junit::JunitOptions;
+
+    // Arrange
+    let config = LintConfig {
+        rules: vec![LintRule::ScopeRequired, LintRule::SummaryNoTrailingPeriod],
+    };
+    let report = lint_all(
+        vec![
+            parse("fix: <b> & typo.").unwrap(),
+            parse("feat(lint): add junit").unwrap(),
+        ],
+        &config,
+    );
+    let options = JunitOptions {
+        suite_name: "commits".to_string(),
+    };
+
+    // Act
+    let junit = report.to_junit(&options);
+
+    // Assert
+    assert_that(&junit).is_equal_to(
+        indoc!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+            <testsuites name="commits" tests="2" failures="1">
+              <testsuite name="commits" tests="2" failures="1">
+                <testcase classname="commit-0" name="fix: &lt;b&gt; &amp; typo.">
+                  <failure type="scope-empty" message="Commit scope is required"/>
+                  <failure type="subject-full-stop" message="Commit summary must not end with a period"/>
+                </testcase>
+                <testcase classname="commit-1" name="feat(lint): add junit"/>
+              </testsuite>
+            </testsuites>
+            "#
+        )
+        .to_string(),
+    );
+}
+
+#[test]
+fn should_report_and_wrap_long_footers() {
+    // Arrange
+    let config = LintConfig {
+        rules: vec![
+            LintRule::FooterTokenMaxLength(12),
+            LintRule::FooterMaxLineLength(30),
+        ],
+    };
+    let commit = parse(indoc!(
+        "fix: typo
+
+        Reviewed-by: Z
+        Very-long-trailer-token: a value spanning more than thirty characters
+        Refs: https://example.org/a/very/long/url/that/cannot/be/wrapped"
+    ))
+    .unwrap();
+
+    // Act
+    let violations = lint(&commit, &config);
+    let normalized = normalize(&commit, &config);
+
+    // Assert
+    assert_that(
+        &violations
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+    )
+    .is_equal_to(vec![
+        "Footer token `Very-long-trailer-token` is longer than 12 characters".to_string(),
+        "Footer `Very-long-trailer-token` line 1 is longer than 30 characters".to_string(),
+    ]);
+    assert_that(&normalized.footers[1].content)
+        .is_equal_to("a value spanning more than\n thirty characters".to_string());
+    assert_that(&normalized.footers[2].content)
+        .is_equal_to("https://example.org/a/very/long/url/that/cannot/be/wrapped".to_string());
+    assert_that(&parse(&normalized.to_string()).unwrap()).is_equal_to(&normalized);
+}
+
+#[test]
+fn should_require_full_sha_in_trailers() {
+    // Arrange
+    let config = LintConfig {
+        rules: vec![LintRule::FullShaTrailers(vec!["Fixes".to_string()])],
+    };
+    let abbreviated = parse("fix: typo\n\nfixes: ea3237f (\"add sign off\")").unwrap();
+    let full = parse(
+        "fix: typo\n\nFixes: ea3237fd0e9a1c2b3d4e5f60718293a4b5c6d7e8 (\"add sign off\")\nRefs: 12",
+    )
+    .unwrap();
+
+    // Act
+    let abbreviated_violations = lint(&abbreviated, &config);
+    let full_violations = lint(&full, &config);
+
+    // Assert
+    assert_that(&abbreviated_violations).has_length(1);
+    assert_that(&abbreviated_violations[0].message).is_equal_to(
+        "Footer `fixes` must reference a full commit hash, found `ea3237f (\"add sign off\")`"
+            .to_string(),
+    );
+    assert_that(&full_violations).is_empty();
+}
+
+#[test]
+fn body_line_length_should_exempt_urls_and_indented_code() {
+    // Arrange
+    let config = LintConfig {
+        rules: vec![LintRule::BodyLineLength(40)],
+    };
+    let exempted = parse(indoc!(
+        "chore(deps): bump pest from 2.1.0 to 2.5.0
+
+        - [Release notes](https://github.com/pest-parser/pest/releases)
+
+            let pairs = ConventionalCommitParser::parse(Rule::message, message);
+
+        Bumps pest."
+    ))
+    .unwrap();
+    let too_long = parse(indoc!(
+        "chore(deps): bump pest
+
+        This prose line is longer than forty characters and can be wrapped."
+    ))
+    .unwrap();
+
+    // Act
+    let exempted_violations = lint(&exempted, &config);
+    let too_long_violations = lint(&too_long, &config);
+
+    // Assert
+    assert_that(&exempted_violations).is_empty();
+    assert_that(&too_long_violations).has_length(1);
+    assert_that(&too_long_violations[0].message)
+        .is_equal_to("Commit body line 1 is longer than 40 characters".to_string());
+}
+
+#[test]
+fn normalize_should_leave_dependabot_verbatim_blocks_untouched() {
+    // Arrange
+    let config = LintConfig {
+        rules: vec![
+            LintRule::BodyMaxLineLength(40),
+            LintRule::FooterMaxLineLength(40),
+        ],
+    };
+    let commit = parse(indoc!(
+        "chore(deps): bump spring-boot-starter-parent from 2.5.5 to 2.5.6
+
+        Bumps [spring-boot-starter-parent](https://github.com/spring-projects/spring-boot) from 2.5.5 to 2.5.6.
+        - [Release notes](https://github.com/spring-projects/spring-boot/releases)
+        - [Commits](spring-projects/spring-boot@v2.5.5...v2.5.6)
+
+        ---
+        updated-dependencies:
+        - dependency-name: org.springframework.boot:spring-boot-starter-parent
+          dependency-type: direct:production
+          update-type: version-update:semver-patch
+        ...
+
+        Signed-off-by: dependabot[bot] <support@github.com>"
+    ))
+    .unwrap();
+
+    // Act
+    let normalized = normalize(&commit, &config);
+
+    // Assert
+    assert_that(&normalized.body).is_equal_to(Some(
+        indoc!(
+            "Bumps
+            [spring-boot-starter-parent](https://github.com/spring-projects/spring-boot)
+            from 2.5.5 to 2.5.6.
+            - [Release
+            notes](https://github.com/spring-projects/spring-boot/releases)
+            - [Commits](spring-projects/spring-boot@v2.5.5...v2.5.6)
+
+            ---"
+        )
+        .to_string(),
+    ));
+    assert_that(&normalized.footers).is_equal_to(&commit.footers);
+    assert_that(&semantically_equal(&normalized, &commit)).is_true();
+    assert_that(&normalize(&normalized, &config)).is_equal_to(&normalized);
+    assert_that(&parse(&normalized.to_string()).unwrap()).is_equal_to(&normalized);
+}
+
+#[test]
+fn normalize_should_wrap_body_after_unclosed_fences() {
+    // Arrange
+    let config = LintConfig {
+        rules: vec![LintRule::BodyMaxLineLength(30)],
+    };
+    let commit = parse(indoc!(
+        "fix(parser): handle carriage returns
+
+        ---
+        Windows editors end lines with a carriage return.
+
+        ```
+        Lines are normalized before parsing the message."
+    ))
+    .unwrap();
+
+    // Act
+    let normalized = normalize(&commit, &config);
+
+    // Assert
+    assert_that(&normalized.body).is_equal_to(Some(
+        indoc!(
+            "---
+            Windows editors end lines with
+            a carriage return.
+
+            ```
+            Lines are normalized before
+            parsing the message."
+        )
+        .to_string(),
+    ));
+}
+
+#[test]
+fn normalize_should_wrap_body_around_code_blocks() {
+    // Arrange
+    let config = LintConfig {
+        rules: vec![LintRule::BodyLineLength(30)],
+    };
+    let commit = parse(indoc!(
+        "fix(parser): handle carriage returns
+
+        Windows editors end lines with a carriage return, see Refs #12 upstream.
+
+        ```
+        let message = message.replace(\"\\r\\n\", \"\\n\"); // normalize line endings
+        ```
+
+            assert!(parse(\"fix: typo\\r\\n\").is_ok()); // indented code
+
+        See https://example.org/a/very/long/url/about/line/endings for details."
+    ))
+    .unwrap();
+
+    // Act
+    let normalized = normalize(&commit, &config);
+
+    // Assert
+    assert_that(&normalized.body).is_equal_to(Some(
+        indoc!(
+            "Windows editors end lines with
+            a carriage return, see Refs
+            #12 upstream.
+
+            ```
+            let message = message.replace(\"\\r\\n\", \"\\n\"); // normalize line endings
+            ```
+
+                assert!(parse(\"fix: typo\\r\\n\").is_ok()); // indented code
+
+            See https://example.org/a/very/long/url/about/line/endings for details."
+        )
+        .to_string(),
+    ));
+    assert_that(&lint(&normalized, &config)).is_empty();
+    assert_that(&parse(&normalized.to_string()).unwrap()).is_equal_to(&normalized);
+}
+
+#[test]
+fn should_report_each_footer_violating_its_schema() {
+    // Arrange
+    let config = LintConfig {
+        rules: vec![LintRule::FooterSchema(vec![
+            FooterSchema {
+                token: "Refs".to_string(),
+                required: true,
+                value: FooterValue::IssueReference,
+            },
+            FooterSchema {
+                token: "Reviewed-by".to_string(),
+                required: true,
+                value: FooterValue::Identity,
+            },
+        ])],
+    };
+    let commit = parse(indoc!(
+        "fix: typo
+
+        Refs #12
+        refs: #13, #14
+        Refs: JIRA-1
+        Reviewed-by: Jane Doe <jane@example.org>
+        Reviewed-by: John"
+    ))
+    .unwrap();
+    let unreferenced = parse("fix: typo\n\nReviewed-by: Jane Doe <jane@example.org>").unwrap();
+
+    // Act
+    let violations = lint(&commit, &config);
+    let report = lint_all(vec![commit, unreferenced], &config);
+
+    // Assert
+    let messages: Vec<&str> = violations.iter().map(|v| v.message.as_str()).collect();
+    assert_that(&messages).is_equal_to(vec![
+        "Footer `Refs` value `JIRA-1` is not an issue reference",
+        "Footer `Reviewed-by` value `John` is not a `Name <email>` identity",
+    ]);
+    assert_that(&report.commits[1].violations[0].message)
+        .is_equal_to("Footer `Refs` is required".to_string());
+    assert_that(&report.rules[0].violations).is_equal_to(2);
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn should_check_footer_values_against_regex() {
+    use conventional_commit_parser::lint::FooterPattern;
+
+    // Arrange
+    let config = LintConfig {
+        rules: vec![LintRule::FooterSchema(vec![FooterSchema {
+            token: "Refs".to_string(),
+            required: false,
+            value: FooterValue::Regex(FooterPattern::new(r"#\d+|#\d+-\d+").unwrap()),
+        }])],
+    };
+
+    // Act
+    let valid = lint(&parse("fix: typo\n\nRefs: #12").unwrap(), &config);
+    let range = lint(&parse("fix: typo\n\nRefs: #12-14").unwrap(), &config);
+    let invalid = lint(&parse("fix: typo\n\nRefs: #12 and #13").unwrap(), &config);
+    let invalid_pattern = FooterPattern::new(r"#(\d+");
+
+    // Assert
+    assert_that(&valid).is_empty();
+    assert_that(&range).is_empty();
+    assert_that(&invalid[0].message).is_equal_to(
+        r"Footer `Refs` value `#12 and #13` is not a value matching `#\d+|#\d+-\d+`".to_string(),
+    );
+    assert_that(&invalid_pattern).is_err();
+}
+
+#[test]
+fn should_report_and_drop_forbidden_footers() {
+    // Arrange
+    let config = LintConfig {
+        rules: vec![LintRule::ForbiddenFooters(vec![
+            "Internal-Ticket".to_string()
+        ])],
+    };
+    let commit = parse(indoc!(
+        "fix: typo
+
+        internal-ticket: OPS-12
+        Refs: #12
+        Internal-Ticket: OPS-13"
+    ))
+    .unwrap();
+
+    // Act
+    let violations = lint(&commit, &config);
+    let normalized = normalize(&commit, &config);
+
+    // Assert
+    assert_that(&violations[0].message)
+        .is_equal_to("Forbidden footer(s) `internal-ticket`, `Internal-Ticket`".to_string());
+    assert_that(&normalized.to_string()).is_equal_to("fix: typo\n\nRefs: #12".to_string());
+    assert_that(&lint(&normalized, &config)).is_empty();
+}
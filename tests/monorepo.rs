@@ -0,0 +1,58 @@
+use conventional_commit_parser::monorepo::{affected_packages, BumpLevel, MonorepoMap, Package};
+use conventional_commit_parser::parse;
+use speculoos::prelude::*;
+
+fn map() -> MonorepoMap {
+    let parser = Package::new("conventional_commit_parser", "crates/parser");
+    let mut map = MonorepoMap {
+        unscoped: Some(Package::new("workspace", ".")),
+        ..Default::default()
+    };
+    map.scopes.insert("parser".to_string(), parser.clone());
+    map.scopes.insert("grammar".to_string(), parser);
+    map.scopes
+        .insert("cli".to_string(), Package::new("cog", "crates/cli"));
+    map
+}
+
+#[test]
+fn should_compute_highest_bump_per_package() {
+    // Arrange
+    let commits = vec![
+        parse("fix(parser): handle tabs").unwrap(),
+        parse("feat(grammar)!: drop hash separators").unwrap(),
+        parse("fix(cli): exit code").unwrap(),
+        parse("perf: faster startup").unwrap(),
+        parse("feat(website): add docs").unwrap(),
+        parse("docs(cli): typo").unwrap(),
+    ];
+
+    // Act
+    let bumps = affected_packages(&commits, &map());
+
+    // Assert
+    let bumps: Vec<(&str, BumpLevel, usize)> = bumps
+        .iter()
+        .map(|bump| (bump.package.name.as_str(), bump.level, bump.commits))
+        .collect();
+    assert_that(&bumps).is_equal_to(vec![
+        ("cog", BumpLevel::Patch, 1),
+        ("conventional_commit_parser", BumpLevel::Major, 2),
+        ("workspace", BumpLevel::Patch, 1),
+    ]);
+}
+
+#[test]
+fn should_ignore_unscoped_commits_without_root_package() {
+    // Arrange
+    let map = MonorepoMap {
+        unscoped: None,
+        ..map()
+    };
+
+    // Act
+    let bumps = affected_packages(vec![parse("feat: a feature").unwrap()], &map);
+
+    // Assert
+    assert_that(&bumps).is_empty();
+}
@@ -0,0 +1,587 @@
+use conventional_commit_parser::changelog::Changelog;
+use conventional_commit_parser::commit::{BreakingOrigin, CommitType, FormatOptions};
+use conventional_commit_parser::error::{ParseErrorKind, ParseWarning};
+use conventional_commit_parser::options::{
+    FooterContinuation, HeaderStyle, ParserOptions, Policy, Validator,
+};
+use conventional_commit_parser::scope::ScopeIndex;
+use conventional_commit_parser::{parse, parse_with_diagnostics, parse_with_options};
+use speculoos::prelude::*;
+use std::sync::Arc;
+
+mod assertions;
+use assertions::*;
+
+#[test]
+fn summary_longer_than_max_len_should_fail() {
+    // Arrange
+    let options = ParserOptions {
+        summary_max_len: Some(10),
+        ..Default::default()
+    };
+
+    // Act
+    let result = parse_with_options("feat(parser): toto va à la plage", &options);
+
+    // Assert
+    assert_error(
+        &result,
+        ParseErrorKind::SummaryTooLong {
+            limit: 10,
+            actual: 18,
+        },
+    );
+    assert_that(&result.unwrap_err().to_string())
+        .is_equal_to("Commit summary is too long (18 characters, maximum is 10)".to_string());
+}
+
+#[test]
+fn summary_at_max_len_should_succeed() {
+    // Arrange
+    let options = ParserOptions {
+        summary_max_len: Some(18),
+        ..Default::default()
+    };
+
+    // Act
+    let result = parse_with_options("feat(parser): toto va à la plage", &options);
+
+    // Assert
+    assert_summary(&result, "toto va à la plage");
+}
+
+#[test]
+fn summary_shorter_than_min_len_should_fail() {
+    // Arrange
+    let options = ParserOptions {
+        summary_min_len: Some(5),
+        ..Default::default()
+    };
+
+    // Act
+    let result = parse_with_options("fix: typo", &options);
+
+    // Assert
+    assert_error(
+        &result,
+        ParseErrorKind::SummaryTooShort {
+            limit: 5,
+            actual: 4,
+        },
+    );
+}
+
+#[test]
+fn should_parse_bracketed_scope_header() {
+    // Arrange
+    let options = ParserOptions {
+        header_style: HeaderStyle::BracketedScope,
+        ..Default::default()
+    };
+
+    // Act
+    let parsed = parse_with_options("feat!: [parser] add header styles\n\nA body", &options);
+
+    // Assert
+    assert_commit_type(&parsed, CommitType::Feature);
+    assert_scope(&parsed, "parser");
+    assert_summary(&parsed, "add header styles");
+    assert_body(&parsed, "A body");
+    assert_breaking_change(&parsed);
+}
+
+#[test]
+fn should_parse_slash_scope_header() {
+    // Arrange
+    let options = ParserOptions {
+        header_style: HeaderStyle::SlashScope,
+        ..Default::default()
+    };
+
+    // Act
+    let parsed = parse_with_options("fix/lexer!: handle tabs", &options);
+
+    // Assert
+    assert_commit_type(&parsed, CommitType::BugFix);
+    assert_scope(&parsed, "lexer");
+    assert_summary(&parsed, "handle tabs");
+    assert_breaking_change(&parsed);
+}
+
+#[test]
+fn alternative_header_style_should_accept_conventional_header() {
+    // Arrange
+    let options = ParserOptions {
+        header_style: HeaderStyle::SlashScope,
+        ..Default::default()
+    };
+
+    // Act
+    let parsed = parse_with_options("fix(lexer): handle tabs", &options);
+
+    // Assert
+    assert_scope(&parsed, "lexer");
+    assert_summary(&parsed, "handle tabs");
+}
+
+#[test]
+fn missing_blank_line_before_body_should_be_allowed_by_default() {
+    // Act
+    let parsed = parse_with_options("fix: typo\nin the readme", &ParserOptions::default());
+
+    // Assert
+    assert_body(&parsed, "in the readme");
+}
+
+#[test]
+fn missing_blank_line_before_body_should_warn() {
+    // Arrange
+    let options = ParserOptions {
+        missing_blank_line_before_body: Policy::Warn,
+        ..Default::default()
+    };
+
+    // Act
+    let diagnosed = parse_with_diagnostics("fix: typo\nin the readme", &options).unwrap();
+    let clean = parse_with_diagnostics("fix: typo\n\nin the readme", &options).unwrap();
+
+    // Assert
+    assert_that(&diagnosed.warnings).is_equal_to(vec![ParseWarning {
+        kind: ParseErrorKind::MissingBlankLineBeforeBody,
+        offset: 10,
+    }]);
+    assert_that(&clean.warnings).is_empty();
+}
+
+#[test]
+fn missing_blank_line_before_footers_should_be_denied() {
+    // Arrange
+    let options = ParserOptions {
+        missing_blank_line_before_body: Policy::Deny,
+        ..Default::default()
+    };
+
+    // Act
+    let result = parse_with_options("fix: typo\nRefs #133", &options);
+
+    // Assert
+    assert_error(&result, ParseErrorKind::MissingBlankLineBeforeBody);
+}
+
+#[test]
+fn message_larger_than_max_len_should_fail() {
+    // Arrange
+    let options = ParserOptions {
+        max_message_len: Some(64),
+        ..Default::default()
+    };
+    let message = format!("feat: a large message\n\n{}", "a".repeat(1_000_000));
+
+    // Act
+    let result = parse_with_options(&message, &options);
+    let small = parse_with_options("feat: a small message", &options);
+
+    // Assert
+    assert_error(
+        &result,
+        ParseErrorKind::MessageTooLarge {
+            limit: 64,
+            actual: 1_000_023,
+        },
+    );
+    assert_summary(&small, "a small message");
+}
+
+#[test]
+fn should_reject_scope_refused_by_validator() {
+    // Arrange
+    let options = ParserOptions {
+        scope_validator: Some(Validator::new(|scope| scope.starts_with("pkg-"))),
+        ..Default::default()
+    };
+
+    // Act
+    let accepted = parse_with_options("fix(pkg-core): handle tabs", &options);
+    let unscoped = parse_with_options("fix: handle tabs", &options);
+    let rejected = parse_with_options("fix(core): handle tabs", &options);
+
+    // Assert
+    assert_that(&accepted).is_ok();
+    assert_that(&unscoped).is_ok();
+    let error = rejected.unwrap_err();
+    assert_that(&error.kind).is_equal_to(ParseErrorKind::DisallowedScope {
+        scope: "core".to_string(),
+    });
+    assert_that(&error.to_string()).is_equal_to("Commit scope is not allowed (`core`)".to_string());
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn should_validate_scope_with_regex() {
+    // Arrange
+    let regex = regex::Regex::new("pkg-[a-z]+").unwrap();
+    let options = ParserOptions {
+        scope_validator: Some(Validator::from(regex)),
+        ..Default::default()
+    };
+
+    // Act
+    let accepted = parse_with_options("fix(pkg-core): handle tabs", &options);
+    let partial_match = parse_with_options("fix(pkg-core2): handle tabs", &options);
+
+    // Assert
+    assert_that(&accepted).is_ok();
+    assert_that(&partial_match.map_err(|error| error.kind)).is_err_containing(
+        ParseErrorKind::DisallowedScope {
+            scope: "pkg-core2".to_string(),
+        },
+    );
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn should_validate_with_regex_alternation_sharing_a_prefix() {
+    // Arrange
+    let regex = regex::Regex::new("core|core-ui").unwrap();
+    let options = ParserOptions {
+        scope_validator: Some(Validator::from(regex)),
+        ..Default::default()
+    };
+
+    // Act
+    let first_branch = parse_with_options("fix(core): handle tabs", &options);
+    let second_branch = parse_with_options("fix(core-ui): handle tabs", &options);
+    let rejected = parse_with_options("fix(core-uix): handle tabs", &options);
+
+    // Assert
+    assert_that(&first_branch).is_ok();
+    assert_that(&second_branch).is_ok();
+    assert_that(&rejected).is_err();
+}
+
+#[test]
+fn should_reject_custom_type_refused_by_validator() {
+    // Arrange
+    let options = ParserOptions {
+        custom_type_validator: Some(Validator::new(|commit_type| commit_type.starts_with("exp"))),
+        ..Default::default()
+    };
+
+    // Act
+    let specification_type = parse_with_options("feat: add validators", &options);
+    let accepted = parse_with_options("expui: try a new layout", &options);
+    let rejected = parse_with_options("wip: try a new layout", &options);
+
+    // Assert
+    assert_that(&specification_type).is_ok();
+    assert_commit_type(&accepted, CommitType::Custom("expui".to_string()));
+    assert_that(&rejected.map_err(|error| error.kind)).is_err_containing(
+        ParseErrorKind::DisallowedType {
+            commit_type: "wip".to_string(),
+        },
+    );
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn should_validate_custom_type_with_regex() {
+    // Arrange
+    let regex = regex::Regex::new("exp-[a-z]+").unwrap();
+    let options = ParserOptions {
+        custom_type_validator: Some(Validator::from(regex)),
+        ..Default::default()
+    };
+
+    // Act
+    let accepted = parse_with_options("exp-ui(layout)!: try a new layout", &options);
+    let rejected = parse_with_options("exp-2: try a new layout", &options);
+    let default = parse_with_options("exp-ui: try a new layout", &ParserOptions::default());
+
+    // Assert
+    assert_commit_type(&accepted, CommitType::Custom("exp-ui".to_string()));
+    let accepted = accepted.unwrap();
+    assert_that(&accepted.scope).is_equal_to(Some("layout".to_string()));
+    assert_that(&accepted.is_breaking_change).is_true();
+    assert_that(&accepted.to_string()).is_equal_to("exp-ui(layout)!: try a new layout".to_string());
+    assert_that(&rejected.map_err(|error| error.kind)).is_err_containing(
+        ParseErrorKind::DisallowedType {
+            commit_type: "exp-2".to_string(),
+        },
+    );
+    assert_that(&default).is_err();
+}
+
+#[test]
+fn should_recognize_additional_breaking_change_tokens() {
+    // Arrange
+    let options = ParserOptions {
+        breaking_change_tokens: vec!["CHANGEMENT MAJEUR".to_string(), "BREAKING".to_string()],
+        ..Default::default()
+    };
+    let message = "feat: supprime parse_summary\n\nRefs: #12\nCHANGEMENT MAJEUR: utiliser parse";
+
+    // Act
+    let parsed = parse_with_options(message, &options).unwrap();
+    let english = parse_with_options("feat: x\n\nBREAKING CHANGE: use parse", &options).unwrap();
+    let default = parse_with_options(message, &ParserOptions::default()).unwrap();
+
+    // Assert
+    assert_that(&parsed.is_breaking_change).is_true();
+    assert_that(&parsed.footers).has_length(2);
+    assert_that(&parsed.footers[1].token).is_equal_to("CHANGEMENT MAJEUR".to_string());
+    assert_that(&parsed.footers[1].content).is_equal_to("utiliser parse".to_string());
+    assert_that(&parsed.breaking_change_mark).is_false();
+    assert_that(&parsed.breaking_change_origin()).is_equal_to(Some(BreakingOrigin::Footer));
+    assert_that(&parsed.breaking_change_description()).is_equal_to(Some("utiliser parse"));
+    assert_that(&parsed.trailers().count()).is_equal_to(1);
+    assert_that(&parsed.to_string()).is_equal_to(message.to_string());
+    let unreleased = Changelog::default().unreleased(vec![parsed.clone()]);
+    assert_that(&unreleased.breaking_change_notes()[0].description).is_equal_to("utiliser parse");
+    assert_that(&english.is_breaking_change).is_true();
+    assert_that(&default.is_breaking_change).is_false();
+}
+
+#[test]
+fn should_keep_error_offsets_after_additional_breaking_change_tokens() {
+    // Arrange
+    let options = ParserOptions {
+        breaking_change_tokens: vec!["ÄNDERUNG MIT BRUCH".to_string()],
+        hash_footer_separator: Policy::Deny,
+        ..Default::default()
+    };
+    let message = "feat: x\n\nÄNDERUNG MIT BRUCH: parse entfernt\nRefs #12";
+
+    // Act
+    let error = parse_with_options(message, &options).unwrap_err();
+
+    // Assert
+    let offset = message.find("Refs #12").unwrap();
+    assert_that(&error.kind).is_equal_to(ParseErrorKind::HashFooterSeparator);
+    assert_that(&error.inner.location).is_equal_to(pest::error::InputLocation::Pos(offset));
+}
+
+#[test]
+fn should_capture_pre_header_tag() {
+    // Arrange
+    let options = ParserOptions {
+        accept_pre_header_tag: true,
+        ..Default::default()
+    };
+    let message = "[JIRA-1] feat(parser): accept ticket tags\n\nA body";
+    let tagged = FormatOptions {
+        pre_header_tag: true,
+        ..Default::default()
+    };
+
+    // Act
+    let parsed = parse_with_options(message, &options).unwrap();
+    let untagged = parse_with_options("feat: no tag", &options).unwrap();
+    let rejected = parse_with_options(message, &ParserOptions::default());
+
+    // Assert
+    assert_that(&parsed.pre_header_tag.as_deref()).is_equal_to(Some("JIRA-1"));
+    assert_that(&parsed.commit_type).is_equal_to(CommitType::Feature);
+    assert_that(&parsed.scope.as_deref()).is_equal_to(Some("parser"));
+    assert_that(&parsed.to_string())
+        .is_equal_to("feat(parser): accept ticket tags\n\nA body".to_string());
+    assert_that(&parsed.format(&tagged)).is_equal_to(message.to_string());
+    assert_that(&untagged.format(&tagged)).is_equal_to("feat: no tag".to_string());
+    assert_that(&untagged.pre_header_tag).is_none();
+    assert_that(&rejected).is_err();
+}
+
+#[test]
+fn should_report_body_after_footers() {
+    // Arrange
+    let options = ParserOptions {
+        body_after_footers: Policy::Warn,
+        footer_continuation: FooterContinuation::StopAtBlank,
+        ..Default::default()
+    };
+    let message = "fix: typo\n\nRefs: #12\nReviewed-by: Z\n\nThis paragraph is not a footer";
+
+    // Act
+    let diagnosed = parse_with_diagnostics(message, &options).unwrap();
+    let denied = parse_with_options(
+        message,
+        &ParserOptions {
+            body_after_footers: Policy::Deny,
+            footer_continuation: FooterContinuation::StopAtBlank,
+            ..Default::default()
+        },
+    );
+    let ordered = parse_with_diagnostics(
+        "fix: typo\n\nThe body\n\nRefs: #12\n\nReviewed-by: Z",
+        &options,
+    )
+    .unwrap();
+
+    // Assert
+    assert_that(&diagnosed.warnings).is_equal_to(vec![ParseWarning {
+        kind: ParseErrorKind::BodyAfterFooters,
+        offset: 37,
+    }]);
+    assert_that(&diagnosed.commit.body)
+        .is_equal_to(Some("This paragraph is not a footer".to_string()));
+    assert_error(&denied, ParseErrorKind::BodyAfterFooters);
+    assert_that(&ordered.warnings).is_empty();
+}
+
+#[test]
+fn body_after_footers_should_ignore_cherry_pick_line() {
+    // Arrange
+    let options = ParserOptions {
+        body_after_footers: Policy::Deny,
+        footer_continuation: FooterContinuation::StopAtBlank,
+        ..Default::default()
+    };
+    let message =
+        "fix: x\n\nRefs: #1\n\n(cherry picked from commit 4f2a9c1e8d7b6a5f4e3d2c1b0a9f8e7d6c5b4a39)";
+
+    // Act
+    let parsed = parse_with_options(message, &options);
+
+    // Assert
+    assert_that(&parsed).is_ok();
+}
+
+#[test]
+fn body_after_footers_should_not_be_checked_with_greedy_footer_continuation() {
+    // Arrange
+    let options = ParserOptions {
+        body_after_footers: Policy::Deny,
+        footer_continuation: FooterContinuation::Greedy,
+        ..Default::default()
+    };
+    let message = "fix: typo\n\nRefs: #12\n\nThis paragraph is not a footer";
+
+    // Act
+    let parsed = parse_with_options(message, &options).unwrap();
+
+    // Assert
+    assert_that(&parsed.footers[0].content)
+        .is_equal_to("#12\n\nThis paragraph is not a footer".to_string());
+}
+
+#[test]
+fn greedy_footer_continuation_should_keep_blank_lines_in_footer_values() {
+    // Arrange
+    let message = "feat!: drop parse_summary\n\nBREAKING CHANGE: parse_summary was removed\n\nUse parse instead\nRefs: #12";
+
+    // Act
+    let parsed = parse_with_options(message, &ParserOptions::default()).unwrap();
+
+    // Assert
+    assert_that(&parsed.body).is_none();
+    assert_that(&parsed.footers[0].content)
+        .is_equal_to("parse_summary was removed\n\nUse parse instead".to_string());
+    assert_that(&parsed.footers[1].content).is_equal_to("#12".to_string());
+}
+
+#[test]
+fn stop_at_blank_footer_continuation_should_move_paragraphs_to_the_body() {
+    // Arrange
+    let options = ParserOptions {
+        footer_continuation: FooterContinuation::StopAtBlank,
+        ..Default::default()
+    };
+    let message = "feat!: drop parse_summary\n\nA body\n\nBREAKING CHANGE: parse_summary was removed\n\nUse parse instead\nRefs: #12";
+
+    // Act
+    let parsed = parse_with_options(message, &options).unwrap();
+
+    // Assert
+    assert_that(&parsed.body).is_equal_to(Some("A body\n\nUse parse instead".to_string()));
+    assert_that(&parsed.footers[0].content).is_equal_to("parse_summary was removed".to_string());
+    assert_that(&parsed.footers[1].content).is_equal_to("#12".to_string());
+}
+
+#[test]
+fn should_suggest_nearest_historical_type_and_scope() {
+    // Arrange
+    let history = vec![
+        parse("docs(changelog): document releases").unwrap(),
+        parse("expui(changelog): try a new layout").unwrap(),
+        parse("feat(changelogs): split per package").unwrap(),
+        parse("exp(cli): try a flag").unwrap(),
+    ];
+    let options = ParserOptions {
+        custom_type_validator: Some(Validator::one_of(&["expui", "exp"])),
+        scope_validator: Some(Validator::one_of(&["changelog", "changelogs", "cli"])),
+        suggestion_index: Some(Arc::new(ScopeIndex::build(&history))),
+        ..Default::default()
+    };
+
+    // Act
+    let scope = parse_with_options("docs(chnagelog): typo", &options).unwrap_err();
+    let commit_type = parse_with_options("expiu(cli): try a flag", &options).unwrap_err();
+    let unrelated = parse_with_options("fix(database): typo", &options).unwrap_err();
+
+    // Assert
+    assert_that(&scope.suggestion()).is_equal_to(Some("docs(changelog): typo".to_string()));
+    assert_that(&commit_type.suggestion()).is_equal_to(Some("expui(cli): try a flag".to_string()));
+    assert_that(&unrelated.suggestion()).is_none();
+}
+
+#[test]
+fn should_report_hash_footer_separator() {
+    // Arrange
+    let message = "fix: typo\n\nReviewed-by: Z\nRefs #12";
+    let warn = ParserOptions {
+        hash_footer_separator: Policy::Warn,
+        ..Default::default()
+    };
+    let deny = ParserOptions {
+        hash_footer_separator: Policy::Deny,
+        ..Default::default()
+    };
+
+    // Act
+    let diagnosed = parse_with_diagnostics(message, &warn).unwrap();
+    let denied = parse_with_options(message, &deny);
+    let colon = parse_with_options("fix: typo\n\nRefs: #12", &deny);
+    let allowed = parse_with_options(message, &ParserOptions::default());
+
+    // Assert
+    assert_that(&diagnosed.warnings).is_equal_to(vec![ParseWarning {
+        kind: ParseErrorKind::HashFooterSeparator,
+        offset: 26,
+    }]);
+    assert_error(&denied, ParseErrorKind::HashFooterSeparator);
+    assert_that(&colon).is_ok();
+    assert_that(&allowed).is_ok();
+}
+
+#[test]
+fn should_accept_escaped_parenthesis_in_scope() {
+    // Arrange
+    let options = ParserOptions {
+        escaped_scope_parenthesis: true,
+        ..Default::default()
+    };
+    let message = "fix(handle\\(x\\))!: reject empty input\n\nA body";
+
+    // Act
+    let commit = parse_with_options(message, &options).unwrap();
+
+    // Assert
+    assert_that(&commit.scope).is_equal_to(Some("handle(x)".to_string()));
+    assert_that(&commit.is_breaking_change).is_true();
+    assert_that(&commit.to_string()).is_equal_to(message.to_string());
+    assert_that(&parse_with_options(message, &ParserOptions::default()).is_err()).is_true();
+}
+
+#[test]
+fn should_report_unbalanced_scope_with_escaped_parenthesis() {
+    // Arrange
+    let options = ParserOptions {
+        escaped_scope_parenthesis: true,
+        ..Default::default()
+    };
+
+    // Act
+    let error = parse_with_options("fix(handle\\(x)): typo", &options).unwrap_err();
+
+    // Assert
+    assert_that(&error.kind).is_equal_to(ParseErrorKind::MissingSeparator);
+}
@@ -0,0 +1,116 @@
+use conventional_commit_parser::error::ParseErrorKind;
+use conventional_commit_parser::options::ParserOptions;
+use conventional_commit_parser::{parse, parse_with_options};
+use speculoos::prelude::*;
+use std::time::{Duration, Instant};
+
+// Inputs reduced from fuzzing corpora, each one used to take quadratic time
+// or more in the message size
+const REPEAT: usize = 20_000;
+const TIME_LIMIT: Duration = Duration::from_secs(5);
+
+#[test]
+fn should_parse_long_blank_line_runs_in_linear_time() {
+    // Arrange
+    let message = format!("feat: blank lines\n\nfirst{}last", "\n".repeat(REPEAT));
+
+    // Act
+    let start = Instant::now();
+    let parsed = parse(&message);
+
+    // Assert
+    assert_that(&start.elapsed()).is_less_than(TIME_LIMIT);
+    assert_that(&parsed)
+        .is_ok()
+        .map(|commit| &commit.body)
+        .is_equal_to(Some(format!("first{}last", "\n".repeat(REPEAT))));
+}
+
+#[test]
+fn should_parse_trailing_whitespace_lines_in_linear_time() {
+    // Arrange
+    let message = format!("feat: blank lines\n\nbody{}", "\n ".repeat(REPEAT));
+
+    // Act
+    let start = Instant::now();
+    let parsed = parse(&message);
+
+    // Assert
+    assert_that(&start.elapsed()).is_less_than(TIME_LIMIT);
+    assert_that(&parsed)
+        .is_ok()
+        .map(|commit| &commit.body)
+        .is_equal_to(Some("body".to_string()));
+}
+
+#[test]
+fn should_parse_many_footers_in_linear_time() {
+    // Arrange
+    let message = format!("fix: many footers\n\n{}Refs #1", "Refs: 1\n".repeat(REPEAT));
+
+    // Act
+    let start = Instant::now();
+    let parsed = parse(&message);
+
+    // Assert
+    assert_that(&start.elapsed()).is_less_than(TIME_LIMIT);
+    assert_that(&parsed)
+        .is_ok()
+        .map(|commit| &commit.footers)
+        .has_length(REPEAT + 1);
+}
+
+#[test]
+fn should_parse_many_footer_like_body_lines_in_linear_time() {
+    // Arrange
+    let message = format!(
+        "fix: footer like lines\n\nbody\n{}",
+        "Refs: 1\n\n".repeat(REPEAT)
+    );
+
+    // Act
+    let start = Instant::now();
+    let parsed = parse(&message);
+
+    // Assert
+    assert_that(&start.elapsed()).is_less_than(TIME_LIMIT);
+    assert_that(&parsed)
+        .is_ok()
+        .map(|commit| &commit.footers)
+        .has_length(REPEAT);
+}
+
+#[test]
+fn should_reject_unterminated_scope_in_linear_time() {
+    // Arrange
+    let message = format!("feat({}: unterminated", "(".repeat(REPEAT));
+
+    // Act
+    let start = Instant::now();
+    let parsed = parse(&message);
+
+    // Assert
+    assert_that(&start.elapsed()).is_less_than(TIME_LIMIT);
+    assert_that(&parsed).is_err();
+}
+
+#[test]
+fn should_reject_oversized_pathological_input_before_parsing() {
+    // Arrange
+    let options = ParserOptions {
+        max_message_len: Some(1024),
+        ..Default::default()
+    };
+    let message = format!("feat: blank lines\n\nbody{}", "\n".repeat(REPEAT));
+
+    // Act
+    let parsed = parse_with_options(&message, &options);
+
+    // Assert
+    assert_that(&parsed.map_err(|err| err.kind)).is_err_containing(
+        ParseErrorKind::MessageTooLarge {
+            limit: 1024,
+            actual: message.len(),
+        },
+    );
+}
@@ -0,0 +1,182 @@
+use conventional_commit_parser::commit::ConventionalCommit;
+use conventional_commit_parser::lint::junit::JunitOptions;
+use conventional_commit_parser::lint::plugin::{LintPlugin, PluginRule};
+use conventional_commit_parser::lint::{lint_all, LintConfig, LintRule};
+use conventional_commit_parser::parse;
+use speculoos::prelude::*;
+
+struct MaxFooters(usize);
+
+impl LintPlugin for MaxFooters {
+    fn id(&self) -> &str {
+        "acme/max-footers"
+    }
+
+    fn description(&self) -> String {
+        format!("At most {} footers", self.0)
+    }
+
+    fn check(&self, commit: &ConventionalCommit) -> Vec<String> {
+        commit
+            .footers
+            .iter()
+            .skip(self.0)
+            .map(|footer| format!("Extra footer `{}`", footer.token))
+            .collect()
+    }
+}
+
+#[test]
+fn should_lint_with_plugin_rules() {
+    // Arrange
+    let config = LintConfig {
+        rules: vec![
+            LintRule::ScopeRequired,
+            LintRule::Plugin(PluginRule::new(MaxFooters(1))),
+        ],
+    };
+    let commits = vec![
+        parse("fix(parser): typo\n\nRefs: #1\nRefs: #2\nReviewed-by: Z").unwrap(),
+        parse("fix(parser): typo\n\nRefs: #1").unwrap(),
+    ];
+
+    // Act
+    let report = lint_all(&commits, &config);
+
+    // Assert
+    let messages: Vec<&str> = report.commits[0]
+        .violations
+        .iter()
+        .map(|violation| violation.message.as_str())
+        .collect();
+    assert_that(&messages).is_equal_to(vec!["Extra footer `Refs`", "Extra footer `Reviewed-by`"]);
+    assert_that(&report.rules[1].violations).is_equal_to(1);
+    assert_that(&report.rules[1].rule.description()).is_equal_to("At most 1 footers".to_string());
+    assert_that(&report.passed).is_equal_to(1);
+}
+
+struct Quoted;
+
+impl LintPlugin for Quoted {
+    fn id(&self) -> &str {
+        "acme/\"quoted\"<rule>"
+    }
+
+    fn description(&self) -> String {
+        "Always fails".to_string()
+    }
+
+    fn check(&self, _commit: &ConventionalCommit) -> Vec<String> {
+        vec!["Failed".to_string()]
+    }
+}
+
+#[test]
+fn should_escape_plugin_ids_in_junit_report() {
+    // Arrange
+    let config = LintConfig {
+        rules: vec![LintRule::Plugin(PluginRule::new(Quoted))],
+    };
+    let report = lint_all(vec![parse("fix: typo").unwrap()], &config);
+
+    // Act
+    let junit = report.to_junit(&JunitOptions::default());
+
+    // Assert
+    assert_that(&junit)
+        .contains(r#"<failure type="acme/&quot;quoted&quot;&lt;rule&gt;" message="Failed"/>"#);
+}
+
+#[cfg(feature = "plugins")]
+mod dynamic {
+    use std::ffi::{c_char, c_void};
+
+    use conventional_commit_parser::error::PluginError;
+    use conventional_commit_parser::lint::plugin::{
+        DynamicPlugin, LintPlugin, PluginDescriptor, ReportFn, PLUGIN_ABI_VERSION,
+    };
+    use conventional_commit_parser::parse;
+    use speculoos::prelude::*;
+
+    unsafe extern "C" fn no_todo(
+        message: *const u8,
+        len: usize,
+        report: ReportFn,
+        context: *mut c_void,
+    ) {
+        let message = std::str::from_utf8(std::slice::from_raw_parts(message, len)).unwrap();
+        if message.contains("TODO") {
+            let violation = "Commit message mentions TODO";
+            report(context, violation.as_ptr(), violation.len());
+        }
+    }
+
+    unsafe extern "C" fn empty_report(
+        _: *const u8,
+        _: usize,
+        report: ReportFn,
+        context: *mut c_void,
+    ) {
+        report(context, std::ptr::null(), 0);
+    }
+
+    fn descriptor(abi_version: u32) -> PluginDescriptor {
+        PluginDescriptor {
+            abi_version,
+            id: b"acme/no-todo\0".as_ptr() as *const c_char,
+            description: b"No TODO in commit messages\0".as_ptr() as *const c_char,
+            check: no_todo,
+        }
+    }
+
+    #[test]
+    fn should_check_commits_through_c_abi() {
+        // Arrange
+        let plugin =
+            unsafe { DynamicPlugin::from_descriptor(&descriptor(PLUGIN_ABI_VERSION)) }.unwrap();
+
+        // Act
+        let violations = plugin.check(&parse("feat: plugins\n\nTODO: document them").unwrap());
+        let passed = plugin.check(&parse("feat: plugins").unwrap());
+
+        // Assert
+        assert_that(&plugin.id()).is_equal_to("acme/no-todo");
+        assert_that(&plugin.description()).is_equal_to("No TODO in commit messages".to_string());
+        assert_that(&violations).is_equal_to(vec!["Commit message mentions TODO".to_string()]);
+        assert_that(&passed).is_empty();
+    }
+
+    #[test]
+    fn should_report_null_messages_as_empty() {
+        // Arrange
+        let descriptor = PluginDescriptor {
+            check: empty_report,
+            ..descriptor(PLUGIN_ABI_VERSION)
+        };
+        let plugin = unsafe { DynamicPlugin::from_descriptor(&descriptor) }.unwrap();
+
+        // Act
+        let violations = plugin.check(&parse("feat: plugins").unwrap());
+
+        // Assert
+        assert_that(&violations).is_equal_to(vec![String::new()]);
+    }
+
+    #[test]
+    fn should_reject_incompatible_plugins() {
+        // Act
+        let incompatible =
+            unsafe { DynamicPlugin::from_descriptor(&descriptor(PLUGIN_ABI_VERSION + 1)) };
+        let missing = unsafe { DynamicPlugin::load("/nonexistent/libacme_lint.so") };
+
+        // Assert
+        assert!(matches!(
+            incompatible,
+            Err(PluginError::IncompatibleAbi {
+                expected: PLUGIN_ABI_VERSION,
+                ..
+            })
+        ));
+        assert!(matches!(missing, Err(PluginError::Load(_))));
+    }
+}
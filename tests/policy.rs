@@ -0,0 +1,240 @@
+use conventional_commit_parser::commit::{Annotation, CommitType, ConventionalCommit};
+use conventional_commit_parser::options::ParserOptions;
+use conventional_commit_parser::policy::{
+    evaluate, evaluate_with_context, PolicyConfig, PolicyContext, PolicyRule, PolicyViolation,
+};
+use conventional_commit_parser::{parse, parse_with_options};
+use speculoos::prelude::*;
+
+#[test]
+fn should_pass_release_gate() {
+    // Arrange
+    let config = PolicyConfig {
+        rules: vec![
+            PolicyRule::RequireAnyType(vec![CommitType::Feature, CommitType::BugFix]),
+            PolicyRule::NoBreakingChanges,
+            PolicyRule::MaxCustomTypes(1),
+        ],
+    };
+    let commits = vec![
+        parse("fix: typo").unwrap(),
+        parse("wip: parser").unwrap(),
+        parse("wip: lint").unwrap(),
+    ];
+
+    // Act
+    let violations = evaluate(commits, &config);
+
+    // Assert
+    assert_that(&violations).is_empty();
+}
+
+#[test]
+fn should_report_custom_types_over_limit() {
+    // Arrange
+    let config = PolicyConfig {
+        rules: vec![PolicyRule::MaxCustomTypes(1)],
+    };
+    let commits = vec![
+        parse("wip: parser").unwrap(),
+        parse("feat: policy").unwrap(),
+        parse("deploy: staging").unwrap(),
+        parse("wip: lint").unwrap(),
+    ];
+
+    // Act
+    let violations = evaluate(&commits, &config);
+
+    // Assert
+    assert_that(&violations).is_equal_to(vec![PolicyViolation {
+        rule: PolicyRule::MaxCustomTypes(1),
+        message: "2 custom commit types used (`deploy`, `wip`), maximum is 1".to_string(),
+        commits: vec![0, 2, 3],
+    }]);
+}
+
+#[test]
+fn should_require_releasable_commit() {
+    // Arrange
+    let config = PolicyConfig {
+        rules: vec![PolicyRule::RequireAnyType(vec![
+            CommitType::Feature,
+            CommitType::BugFix,
+        ])],
+    };
+
+    // Act
+    let violations = evaluate(vec![parse("docs: readme").unwrap()], &config);
+    let empty = evaluate(Vec::<ConventionalCommit>::new(), &config);
+
+    // Assert
+    assert_that(&violations[0].to_string())
+        .is_equal_to("No commit of type `feat`, `fix`".to_string());
+    assert_that(&violations[0].commits).is_empty();
+    assert_that(&empty).has_length(1);
+}
+
+#[test]
+fn should_forbid_types_on_matching_branches_only() {
+    // Arrange
+    let config = PolicyConfig {
+        rules: vec![PolicyRule::ForbidTypesOnBranch {
+            branch: "release/*.x".to_string(),
+            types: vec![CommitType::Feature],
+        }],
+    };
+    let commits = vec![
+        parse("fix: typo").unwrap(),
+        parse("feat: add policy").unwrap(),
+    ];
+    let context = |branch| PolicyContext {
+        branch: Some(branch),
+    };
+
+    // Act
+    let release = evaluate_with_context(&commits, &config, &context("release/1.x"));
+    let main = evaluate_with_context(&commits, &config, &context("main"));
+    let prefix_only = evaluate_with_context(&commits, &config, &context("release/1.0"));
+    let no_branch = evaluate(&commits, &config);
+
+    // Assert
+    assert_that(&release).has_length(1);
+    assert_that(&release[0].commits).is_equal_to(vec![1]);
+    assert_that(&release[0].message)
+        .is_equal_to("1 commit(s) of type `feat` on branch `release/1.x`".to_string());
+    assert_that(&main).is_empty();
+    assert_that(&prefix_only).is_empty();
+    assert_that(&no_branch).is_empty();
+}
+
+#[test]
+fn should_require_branch_ticket_in_every_commit() {
+    // Arrange
+    let config = PolicyConfig {
+        rules: vec![PolicyRule::RequireBranchTicket],
+    };
+    let commits = vec![
+        parse("fix(ABC-12): typo").unwrap(),
+        parse("fix: typo\n\nRefs: ABC-123").unwrap(),
+        parse("fix: typo\n\nRefs: ABC-12").unwrap(),
+    ];
+    let ticket_branch = PolicyContext {
+        branch: Some("feature/ABC-12_typo"),
+    };
+    let plain_branch = PolicyContext {
+        branch: Some("feature/fix-typo"),
+    };
+
+    // Act
+    let violations = evaluate_with_context(&commits, &config, &ticket_branch);
+    let without_ticket = evaluate_with_context(&commits, &config, &plain_branch);
+
+    // Assert
+    assert_that(&violations).has_length(1);
+    assert_that(&violations[0].commits).is_equal_to(vec![1]);
+    assert_that(&without_ticket).is_empty();
+}
+
+#[test]
+fn should_accept_branch_ticket_in_pre_header_tag() {
+    // Arrange
+    let config = PolicyConfig {
+        rules: vec![PolicyRule::RequireBranchTicket],
+    };
+    let options = ParserOptions {
+        accept_pre_header_tag: true,
+        ..ParserOptions::default()
+    };
+    let commits = vec![parse_with_options("[ABC-42] fix: login redirect", &options).unwrap()];
+    let context = PolicyContext {
+        branch: Some("hotfix/ABC-42-login"),
+    };
+
+    // Act
+    let violations = evaluate_with_context(&commits, &config, &context);
+
+    // Assert
+    assert_that(&violations).is_empty();
+}
+
+#[test]
+fn should_report_duplicate_headers() {
+    // Arrange
+    let config = PolicyConfig {
+        rules: vec![PolicyRule::NoDuplicateHeaders],
+    };
+    let commits = vec![
+        parse("fix: typo").unwrap(),
+        parse("feat(api): add endpoint").unwrap(),
+        parse("fix: Typo.").unwrap(),
+        parse("feat(api)!: add endpoint").unwrap(),
+        parse("fix:  typo").unwrap(),
+    ];
+
+    // Act
+    let violations = evaluate(&commits, &config);
+
+    // Assert
+    assert_that(&violations).is_equal_to(vec![PolicyViolation {
+        rule: PolicyRule::NoDuplicateHeaders,
+        message: "1 header(s) used by more than one commit: `fix: typo`".to_string(),
+        commits: vec![0, 2, 4],
+    }]);
+}
+
+#[test]
+fn should_report_commits_over_annotation_limit() {
+    // Arrange
+    let config = PolicyConfig {
+        rules: vec![PolicyRule::MaxAnnotation {
+            annotation: "files_changed".to_string(),
+            max: 20,
+            types: vec![CommitType::BugFix],
+        }],
+    };
+    let annotated = |message: &str, files_changed: i64| {
+        let mut commit = parse(message).unwrap();
+        commit.annotations.insert(
+            "files_changed".to_string(),
+            Annotation::Integer(files_changed),
+        );
+        commit
+    };
+    let commits = vec![
+        annotated("fix: typo", 2),
+        annotated("fix: rewrite the parser", 42),
+        annotated("feat: add annotations", 42),
+        parse("fix: missing diff stat").unwrap(),
+    ];
+
+    // Act
+    let violations = evaluate(&commits, &config);
+
+    // Assert
+    assert_that(&violations).is_equal_to(vec![PolicyViolation {
+        rule: PolicyRule::MaxAnnotation {
+            annotation: "files_changed".to_string(),
+            max: 20,
+            types: vec![CommitType::BugFix],
+        },
+        message: "1 commit(s) with `files_changed` above 20".to_string(),
+        commits: vec![1],
+    }]);
+}
+
+#[cfg(all(feature = "serde", feature = "json"))]
+#[test]
+fn should_serialize_annotations_untagged() {
+    // Arrange
+    let json = r#"{"files_changed":3,"generated":false,"language":"rust"}"#;
+
+    // Act
+    let annotations: std::collections::BTreeMap<String, Annotation> =
+        serde_json::from_str(json).unwrap();
+
+    // Assert
+    assert_that(&annotations["files_changed"]).is_equal_to(Annotation::Integer(3));
+    assert_that(&annotations["generated"]).is_equal_to(Annotation::Bool(false));
+    assert_that(&annotations["language"]).is_equal_to(Annotation::Text("rust".to_string()));
+    assert_that(&serde_json::to_string(&annotations).unwrap()).is_equal_to(json.to_string());
+}
@@ -0,0 +1,69 @@
+use conventional_commit_parser::commit::{CommitType, ConventionalCommit};
+use conventional_commit_parser::parse;
+use conventional_commit_parser::query::{CommitQuery, QueryExt};
+use speculoos::prelude::*;
+
+fn commits() -> Vec<ConventionalCommit> {
+    vec![
+        parse("feat(api)!: remove v1 endpoints").unwrap(),
+        parse("fix(api): handle empty body\n\nRefs #12").unwrap(),
+        parse("feat: add config file").unwrap(),
+        parse("docs(cli): document flags").unwrap(),
+    ]
+}
+
+#[test]
+fn empty_query_should_match_every_commit() {
+    // Act
+    let matching = commits().into_iter().matching(&CommitQuery::new()).count();
+
+    // Assert
+    assert_that(&matching).is_equal_to(4);
+}
+
+#[test]
+fn should_filter_by_type_and_scope() {
+    // Arrange
+    let commits = commits();
+    let query = CommitQuery::new()
+        .types([CommitType::Feature, CommitType::BugFix])
+        .scope("api");
+
+    // Act
+    let matching: Vec<&str> = commits
+        .iter()
+        .matching(&query)
+        .map(|commit| commit.summary.as_str())
+        .collect();
+
+    // Assert
+    assert_that(&matching).is_equal_to(vec!["remove v1 endpoints", "handle empty body"]);
+}
+
+#[test]
+fn should_filter_unscoped_non_breaking_commits() {
+    // Arrange
+    let query = CommitQuery::new().unscoped().breaking(false);
+
+    // Act
+    let matching: Vec<ConventionalCommit> = commits().into_iter().matching(&query).collect();
+
+    // Assert
+    assert_that(&matching).has_length(1);
+    assert_that(&matching[0].summary).is_equal_to("add config file".to_string());
+}
+
+#[test]
+fn should_filter_by_footer_token() {
+    // Arrange
+    let query = CommitQuery::new().footer("refs");
+
+    // Act
+    let matching = commits()
+        .iter()
+        .filter(|commit| query.matches(commit))
+        .count();
+
+    // Assert
+    assert_that(&matching).is_equal_to(1);
+}
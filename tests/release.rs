@@ -0,0 +1,98 @@
+use conventional_commit_parser::commit::CommitType;
+use conventional_commit_parser::monorepo::BumpLevel;
+use conventional_commit_parser::parse;
+use conventional_commit_parser::release::{
+    release_decision, Channel, Condition, ReleaseDecision, ReleaseRule, ReleaseRules,
+};
+use speculoos::prelude::*;
+
+fn rules(branch: &str) -> ReleaseRules {
+    ReleaseRules {
+        branch: Some(branch.to_string()),
+        rules: vec![
+            ReleaseRule {
+                branch: None,
+                condition: Condition::OnlyTypes(vec![CommitType::Performances]),
+                channel: Channel::Skip,
+            },
+            ReleaseRule {
+                branch: Some("next".to_string()),
+                condition: Condition::AnyType(vec![CommitType::Feature]),
+                channel: Channel::Prerelease("beta".to_string()),
+            },
+            ReleaseRule {
+                branch: None,
+                condition: Condition::AnyBreakingChange,
+                channel: Channel::Prerelease("rc".to_string()),
+            },
+        ],
+        default: Channel::Stable,
+    }
+}
+
+#[test]
+fn should_skip_release_without_bump() {
+    // Arrange
+    let commits = vec![
+        parse("docs: typo").unwrap(),
+        parse("chore: bump dependencies").unwrap(),
+    ];
+
+    // Act
+    let decision = release_decision(&commits, &rules("main"));
+
+    // Assert
+    assert_that(&decision).is_equal_to(ReleaseDecision::Skip);
+}
+
+#[test]
+fn should_skip_release_on_matching_rule() {
+    // Arrange
+    let commits = vec![parse("perf: skip allocations").unwrap()];
+
+    // Act
+    let decision = release_decision(commits, &rules("main"));
+
+    // Assert
+    assert_that(&decision).is_equal_to(ReleaseDecision::Skip);
+}
+
+#[test]
+fn should_apply_branch_rules_only_on_their_branch() {
+    // Arrange
+    let commits = vec![
+        parse("feat: add release hints").unwrap(),
+        parse("fix: typo").unwrap(),
+    ];
+
+    // Act
+    let on_next = release_decision(&commits, &rules("next"));
+    let on_main = release_decision(&commits, &rules("main"));
+
+    // Assert
+    assert_that(&on_next).is_equal_to(ReleaseDecision::Prerelease {
+        channel: "beta".to_string(),
+        level: BumpLevel::Minor,
+    });
+    assert_that(&on_main).is_equal_to(ReleaseDecision::Release(BumpLevel::Minor));
+}
+
+#[test]
+fn should_use_first_matching_rule() {
+    // Arrange
+    let commits = vec![parse("feat!: drop summaries").unwrap()];
+
+    // Act
+    let on_next = release_decision(&commits, &rules("next"));
+    let on_main = release_decision(&commits, &rules("main"));
+
+    // Assert
+    assert_that(&on_next).is_equal_to(ReleaseDecision::Prerelease {
+        channel: "beta".to_string(),
+        level: BumpLevel::Major,
+    });
+    assert_that(&on_main).is_equal_to(ReleaseDecision::Prerelease {
+        channel: "rc".to_string(),
+        level: BumpLevel::Major,
+    });
+}
@@ -0,0 +1,39 @@
+use conventional_commit_parser::parse;
+use conventional_commit_parser::scope::{ScopeIndex, ScopeUsage};
+use speculoos::prelude::*;
+
+#[test]
+fn should_rank_scopes_by_frequency() {
+    // Arrange
+    let history = vec![
+        parse("feat(cli): add verbose flag").unwrap(),
+        parse("fix(parser): handle empty scopes").unwrap(),
+        parse("docs: update readme").unwrap(),
+        parse("fix(Parser): typo").unwrap(),
+        parse("feat(parser): add scope index").unwrap(),
+        parse("fix(changelog): escape html").unwrap(),
+    ];
+
+    // Act
+    let index = ScopeIndex::build(history);
+
+    // Assert
+    let ranked: Vec<(&str, usize)> = index
+        .ranked()
+        .iter()
+        .map(|usage| (usage.scope.as_str(), usage.count))
+        .collect();
+    assert_that(&ranked).is_equal_to(vec![
+        ("parser", 2),
+        ("cli", 1),
+        ("Parser", 1),
+        ("changelog", 1),
+    ]);
+    assert_that(&index.get("parser")).is_equal_to(Some(&ScopeUsage {
+        scope: "parser".to_string(),
+        count: 2,
+        first_seen: 1,
+    }));
+    assert_that(&index.complete("PA")).is_equal_to(vec!["parser", "Parser"]);
+    assert_that(&index.whitelist(2)).is_equal_to(vec!["parser"]);
+}
@@ -0,0 +1,94 @@
+use conventional_commit_parser::parse;
+use conventional_commit_parser::sections::{find_footer_block, split_message};
+use indoc::indoc;
+use speculoos::prelude::*;
+
+const MESSAGES: [&str; 5] = [
+    "feat(parser): add split_message",
+    "fix: typo\nin the readme",
+    "fix: correct typos\n\nsome body\nRefs: 12",
+    "feat!: drop parse_summary\n\nBREAKING CHANGE: use parse instead\n\nwith more prose",
+    indoc!(
+        "chore(deps): bump archunit-junit5-engine from 0.21.0 to 0.22.0 (#11)
+
+        Bumps [archunit-junit5-engine](https://github.com/TNG/ArchUnit) from 0.21.0 to 0.22.0.
+
+        ---
+        updated-dependencies:
+        - dependency-name: com.tngtech.archunit:archunit-junit5-engine
+        ...
+
+        Signed-off-by: dependabot[bot] <support@github.com>"
+    ),
+];
+
+#[test]
+fn split_sections_should_parse_like_full_message() {
+    for message in MESSAGES {
+        // Act
+        let sections = split_message(message);
+
+        // Assert
+        assert_that(&sections.parse())
+            .is_ok()
+            .is_equal_to(parse(message).unwrap());
+    }
+}
+
+#[test]
+fn should_split_footers_directly_after_header() {
+    // Act
+    let sections = split_message("fix: typo\nRefs #133\n");
+
+    // Assert
+    assert_that(&sections.header).is_equal_to("fix: typo");
+    assert_that(&sections.body).is_none();
+    assert_that(&sections.footer_block).is_equal_to(Some("Refs #133"));
+}
+
+#[test]
+fn header_only_split_should_not_parse_body() {
+    // Arrange
+    let message = "feat(api): add endpoint\n\nthis body is not: a valid footer (\n";
+
+    // Act
+    let sections = split_message(message);
+
+    // Assert
+    assert_that(&sections.body).is_equal_to(Some("this body is not: a valid footer ("));
+    assert_that(&sections.footer_block).is_none();
+    assert_that(&sections.parse_header().unwrap().summary).is_equal_to("add endpoint".to_string());
+}
+
+#[test]
+fn should_find_footer_block_range() {
+    // Arrange
+    let message = indoc!(
+        "feat!: drop parse_summary
+
+        A body
+
+        BREAKING CHANGE: use parse instead
+        Refs #133
+
+        "
+    );
+
+    // Act
+    let range = find_footer_block(message);
+
+    // Assert
+    assert_that(&range).is_equal_to(Some(35..79));
+    assert_that(&&message[35..79]).is_equal_to("BREAKING CHANGE: use parse instead\nRefs #133");
+}
+
+#[test]
+fn message_without_footers_should_have_no_footer_block() {
+    // Act
+    let header_only = find_footer_block("feat: Refs: 133");
+    let with_body = find_footer_block("feat: a feature\n\nwith a body");
+
+    // Assert
+    assert_that(&header_only).is_none();
+    assert_that(&with_body).is_none();
+}
@@ -51,6 +51,47 @@ fn parsing_a_commit_type_without_colon_separator_should_fail() {
     assert_error(&result, ParseErrorKind::MissingSeparator);
 }
 
+#[test]
+fn parsing_a_commit_without_description_should_fail() {
+    // Arrange
+    let commit_message = "feat: ";
+
+    // Act
+    let result = parse(commit_message);
+
+    // Assert
+    assert_error(&result, ParseErrorKind::EmptyDescription);
+}
+
+#[test]
+fn parsing_an_empty_or_whitespace_only_commit_should_fail() {
+    // Arrange
+    let commit_messages = vec!["", "   ", "\n\n", " \t\r\n"];
+
+    for commit_message in commit_messages {
+        // Act
+        let result = parse(commit_message);
+
+        // Assert
+        assert_error(&result, ParseErrorKind::EmptyCommitMessage);
+        assert!(conventional_commit_parser::is_effectively_empty(
+            commit_message
+        ));
+    }
+}
+
+#[test]
+fn parsing_a_commit_with_blank_description_and_body_should_fail() {
+    // Arrange
+    let commit_message = "feat(parser):   \n\nA body";
+
+    // Act
+    let result = parse(commit_message);
+
+    // Assert
+    assert_error(&result, ParseErrorKind::EmptyDescription);
+}
+
 #[test]
 fn parsing_a_commit_type_with_whitespace_should_fail() {
     // Arrange
@@ -233,7 +274,7 @@ fn commits_with_footer() {
     let parsed = &parse(commit_message);
 
     // Assert
-    assert_body(&parsed, "This is a body");
+    assert_body(parsed, "This is a body");
 
     assert_contains_footer(
         parsed,
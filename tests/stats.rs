@@ -0,0 +1,62 @@
+use conventional_commit_parser::parse;
+use conventional_commit_parser::stats::StatsCollector;
+use indoc::indoc;
+use speculoos::prelude::*;
+
+#[test]
+fn should_count_authors_from_trailers() {
+    // Arrange
+    let commits = vec![
+        parse(indoc!(
+            "feat(parser): add stats
+
+            Signed-off-by: Jane Doe <Jane@Example.org>
+            Co-authored-by: Jane Doe <jane@example.org>
+            Co-authored-by: John Doe <john@example.org>"
+        ))
+        .unwrap(),
+        parse("fix: typo\n\nsigned-off-by: Jane Doe <jane@example.org>").unwrap(),
+        parse("chore: release\n\nSigned-off-by: release bot").unwrap(),
+    ];
+
+    // Act
+    let stats: StatsCollector = commits.into_iter().collect();
+
+    // Assert
+    let authors: Vec<(&str, usize)> = stats
+        .authors
+        .iter()
+        .map(|(author, count)| (author.as_str(), *count))
+        .collect();
+    assert_that(&authors).is_equal_to(vec![
+        ("Jane Doe <jane@example.org>", 2),
+        ("John Doe <john@example.org>", 1),
+        ("release bot", 1),
+    ]);
+    assert_that(&stats.unscoped).is_equal_to(2);
+}
+
+#[test]
+fn merged_collectors_should_equal_a_single_pass() {
+    // Arrange
+    let commits = vec![
+        parse("feat(parser): add stats").unwrap(),
+        parse("fix(parser)!: reject tabs").unwrap(),
+        parse("feat(changelog): add releases").unwrap(),
+        parse("docs: readme").unwrap(),
+    ];
+    let mut single = StatsCollector::default();
+    single.extend(&commits);
+
+    // Act
+    let mut first = StatsCollector::default();
+    first.extend(&commits[..2]);
+    let mut second = StatsCollector::default();
+    second.extend(&commits[2..]);
+    first.merge(&second);
+
+    // Assert
+    assert_that(&first).is_equal_to(&single);
+    assert_that(&single.breaking_changes).is_equal_to(1);
+    assert_that(&single.commit_types["feat"]).is_equal_to(2);
+}
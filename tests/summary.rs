@@ -1,8 +1,9 @@
-use conventional_commit_parser::parse_summary;
+use conventional_commit_parser::{parse_revert, parse_summary};
 
 mod assertions;
 use assertions::*;
 use conventional_commit_parser::commit::CommitType;
+use conventional_commit_parser::error::{GrammarRule, ParseErrorKind};
 
 #[test]
 fn parse_summary_only() {
@@ -19,3 +20,183 @@ fn parse_summary_only() {
     assert_no_footers(&result);
     assert_no_body(&result);
 }
+
+#[test]
+fn should_reject_repeated_breaking_change_mark_with_suggestion() {
+    // Act
+    let result = parse_summary("feat(parser)!!: drop legacy separators");
+
+    // Assert
+    assert_error(&result, ParseErrorKind::InvalidBreakingChangeMark);
+    assert_eq!(
+        result.unwrap_err().suggestion(),
+        Some("feat(parser)!: drop legacy separators".to_string())
+    );
+}
+
+#[test]
+fn should_reject_breaking_change_mark_before_scope_with_suggestion() {
+    // Act
+    let result = parse_summary("feat!(parser): drop legacy separators");
+
+    // Assert
+    assert_error(&result, ParseErrorKind::BreakingMarkBeforeScope);
+    assert_eq!(
+        result.unwrap_err().suggestion(),
+        Some("feat(parser)!: drop legacy separators".to_string())
+    );
+}
+
+#[test]
+fn should_parse_git_log_oneline() {
+    // Act
+    let (sha, commit) =
+        conventional_commit_parser::parse_oneline("0a1b2c3 fix(parser)!: reject nested scopes")
+            .unwrap();
+    let (decorated_sha, decorated) = conventional_commit_parser::parse_oneline(
+        "4d5e6f7 (HEAD -> main, tag: v1.0.0) docs: update readme\n",
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(sha, "0a1b2c3");
+    assert_eq!(commit.scope, Some("parser".to_string()));
+    assert_eq!(commit.summary, "reject nested scopes");
+    assert!(commit.is_breaking_change);
+    assert_eq!(decorated_sha, "4d5e6f7");
+    assert_eq!(decorated.commit_type, CommitType::Documentation);
+    assert_eq!(decorated.summary, "update readme");
+}
+
+#[test]
+fn oneline_should_require_commit_sha() {
+    // Act
+    let missing = conventional_commit_parser::parse_oneline("feat: add oneline").unwrap_err();
+    let invalid = conventional_commit_parser::parse_oneline("abc1234 add oneline").unwrap_err();
+
+    // Assert
+    assert_eq!(missing.kind, ParseErrorKind::MissingCommitSha);
+    assert_eq!(invalid.kind, ParseErrorKind::MissingSeparator);
+}
+
+#[test]
+fn should_parse_revert_of_several_commits() {
+    // Arrange
+    let message = "Revert \"Merge branch 'feature'\"\n\n\
+        This reverts commit 4457a0a4457a0a, reversing\n\
+        changes made to 47be50947be509.\n\
+        This reverts commit ea3237fea3237f.";
+
+    // Act
+    let revert = parse_revert(message).unwrap();
+
+    // Assert
+    assert_eq!(revert.reverted_header, "Merge branch 'feature'");
+    assert_eq!(
+        revert.reverted_shas,
+        vec!["4457a0a4457a0a", "ea3237fea3237f"]
+    );
+    assert!(revert.reverted_commit().is_err());
+}
+
+#[test]
+fn should_reject_invalid_revert_messages() {
+    // Act
+    let not_a_revert = parse_revert("revert: feat(parser): add reverts").unwrap_err();
+    let missing = parse_revert("Revert \"feat: x\"\n\nReverting it").unwrap_err();
+    let invalid = parse_revert("Revert \"feat: x\"\n\nThis reverts commit HEAD~1.").unwrap_err();
+
+    // Assert
+    assert_eq!(not_a_revert.kind, ParseErrorKind::MalformedRevertHeader);
+    assert_eq!(missing.kind, ParseErrorKind::MissingRevertedSha);
+    assert_eq!(
+        invalid.kind,
+        ParseErrorKind::InvalidRevertedSha {
+            sha: "HEAD~1".to_string()
+        }
+    );
+}
+
+#[test]
+fn should_expose_grammar_rule_context() {
+    // Act
+    let misplaced_mark = parse_summary("feat!(parser): x").unwrap_err();
+    let empty = parse_summary("feat: ").unwrap_err();
+    let custom = conventional_commit_parser::parse("").unwrap_err();
+
+    // Assert
+    let misplaced_mark = misplaced_mark.rule_context().unwrap();
+    assert!(misplaced_mark
+        .unexpected
+        .contains(&GrammarRule::BreakingMarkBeforeScope));
+    let empty = empty.rule_context().unwrap();
+    assert!(empty.expected.contains(&GrammarRule::Description));
+    assert_eq!(
+        empty
+            .expected
+            .iter()
+            .filter(|rule| **rule == GrammarRule::Description)
+            .count(),
+        1
+    );
+    assert!(custom.rule_context().is_none());
+}
+
+#[test]
+fn should_reject_new_line_in_summary() {
+    // Act
+    let result = parse_summary("feat: a\nb");
+    let trailing_new_line = parse_summary("feat: a\r\n");
+
+    // Assert
+    assert_error(&result, ParseErrorKind::UnexpectedNewLineInSummary);
+    assert_eq!(
+        result.unwrap_err().inner.line_col,
+        pest::error::LineColLocation::Pos((1, 8))
+    );
+    assert_eq!(trailing_new_line.unwrap().summary, "a");
+}
+
+#[test]
+fn should_reject_unclosed_scope_with_suggestion() {
+    // Act
+    let result = parse_summary("feat(parser: close scopes");
+
+    // Assert
+    assert_error(&result, ParseErrorKind::UnclosedScope);
+    assert_eq!(
+        result.unwrap_err().suggestion(),
+        Some("feat(parser): close scopes".to_string())
+    );
+}
+
+#[test]
+fn should_reject_unclosed_scope_before_breaking_change_mark() {
+    // Act
+    let result = parse_summary("feat(parser!: close scopes");
+
+    // Assert
+    assert_error(&result, ParseErrorKind::UnclosedScope);
+    assert_eq!(
+        result.unwrap_err().suggestion(),
+        Some("feat(parser)!: close scopes".to_string())
+    );
+}
+
+#[test]
+fn should_reject_unclosed_scope_at_end_of_input() {
+    // Act
+    let result = parse_summary("feat(parser");
+
+    // Assert
+    assert_error(&result, ParseErrorKind::UnclosedScope);
+}
+
+#[test]
+fn should_keep_whitespace_error_for_closed_scope() {
+    // Act
+    let result = parse_summary("feat(the parser): close scopes");
+
+    // Assert
+    assert_error(&result, ParseErrorKind::UnexpectedWhitespaceOrNewLine);
+}
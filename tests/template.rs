@@ -0,0 +1,77 @@
+use conventional_commit_parser::commit::CommitType;
+use conventional_commit_parser::template::{check_template, TemplateOptions, TemplatePart};
+use indoc::indoc;
+use speculoos::prelude::*;
+
+#[test]
+fn should_report_every_part_of_an_empty_template() {
+    // Arrange
+    let template = indoc!(
+        "
+        # <type>(<scope>): <summary>
+        #
+        # <body>"
+    );
+    let options = TemplateOptions {
+        require_body: true,
+        ..Default::default()
+    };
+
+    // Act
+    let status = check_template(template, &options);
+
+    // Assert
+    assert_that(&status.missing).is_equal_to(vec![
+        TemplatePart::Type,
+        TemplatePart::Summary,
+        TemplatePart::Body,
+    ]);
+    assert_that(&status.commit).is_none();
+}
+
+#[test]
+fn should_ignore_everything_below_scissors_line() {
+    // Arrange
+    let template = indoc!(
+        "fix(parser): handle templates
+
+        Ticket: PROJ-12
+        # ------------------------ >8 ------------------------
+        diff --git a/src/lib.rs b/src/lib.rs"
+    );
+    let options = TemplateOptions {
+        require_scope: true,
+        required_footers: vec!["ticket".to_string()],
+        ..Default::default()
+    };
+
+    // Act
+    let status = check_template(template, &options);
+
+    // Assert
+    assert_that(&status.missing).is_empty();
+    assert_that(&status.is_complete()).is_true();
+    assert_that(&status.message)
+        .is_equal_to("fix(parser): handle templates\n\nTicket: PROJ-12\n".to_string());
+    assert_that(&status.commit.unwrap().commit_type).is_equal_to(CommitType::BugFix);
+}
+
+#[test]
+fn should_report_missing_type_and_empty_footer_value() {
+    // Arrange
+    let template = "add templates\n\nRefs: \n; Refs: <issue>";
+    let options = TemplateOptions {
+        comment_char: ';',
+        required_footers: vec!["Refs".to_string()],
+        ..Default::default()
+    };
+
+    // Act
+    let status = check_template(template, &options);
+
+    // Assert
+    assert_that(&status.missing).is_equal_to(vec![
+        TemplatePart::Type,
+        TemplatePart::Footer("Refs".to_string()),
+    ]);
+}
@@ -0,0 +1,57 @@
+use conventional_commit_parser::commit::Separator;
+use conventional_commit_parser::token::{tokenize, Token};
+use indoc::indoc;
+use speculoos::prelude::*;
+
+#[test]
+fn should_tokenize_full_message() {
+    // Arrange
+    let message = indoc!(
+        "feat: add a lexer
+        A body line
+        
+        BREAKING CHANGE:
+        tokens are public
+        Reviewed-by: Z
+
+        Trailing prose"
+    );
+
+    // Act
+    let tokens: Vec<Token> = tokenize(message).collect();
+
+    // Assert
+    assert_that(&tokens).is_equal_to(vec![
+        Token::Type("feat"),
+        Token::Summary("add a lexer"),
+        Token::BodyLine("A body line"),
+        Token::BlankLine,
+        Token::FooterLine {
+            token: "BREAKING CHANGE",
+            separator: Separator::ColonWithNewLine,
+            value: "",
+        },
+        Token::ContinuationLine("tokens are public"),
+        Token::FooterLine {
+            token: "Reviewed-by",
+            separator: Separator::Colon,
+            value: "Z",
+        },
+        Token::BlankLine,
+        Token::BodyLine("Trailing prose"),
+    ]);
+}
+
+#[test]
+fn should_tokenize_malformed_header_without_failing() {
+    // Act
+    let tokens: Vec<Token> = tokenize("feat(a scope): summary\r\nbody").collect();
+    let empty: Vec<Token> = tokenize("").collect();
+
+    // Assert
+    assert_that(&tokens).is_equal_to(vec![
+        Token::Header("feat(a scope): summary"),
+        Token::BodyLine("body"),
+    ]);
+    assert_that(&empty).is_empty();
+}
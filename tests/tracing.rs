@@ -0,0 +1,94 @@
+#![cfg(feature = "tracing")]
+
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+
+use conventional_commit_parser::options::{ParserOptions, Policy};
+use conventional_commit_parser::{parse, parse_with_diagnostics};
+use speculoos::prelude::*;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+/// Records span names and event messages
+#[derive(Clone, Default)]
+struct Recorder {
+    records: Arc<Mutex<Vec<String>>>,
+}
+
+struct MessageVisitor<'a>(&'a mut Vec<String>);
+
+impl Visit for MessageVisitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        if field.name() == "message" {
+            self.0.push(format!("{:?}", value));
+        }
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let mut records = self.records.lock().unwrap();
+        records.push(format!("span {}", span.metadata().name()));
+        Id::from_u64(records.len() as u64)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        event.record(&mut MessageVisitor(&mut self.records.lock().unwrap()));
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+fn record<T>(f: impl FnOnce() -> T) -> Vec<String> {
+    let recorder = Recorder::default();
+    tracing::subscriber::with_default(recorder.clone(), f);
+    let records = recorder.records.lock().unwrap().clone();
+    records
+}
+
+#[test]
+fn should_trace_parsed_commit() {
+    // Act
+    let records = record(|| parse("feat: add tracing").is_ok());
+
+    // Assert
+    assert_that(&records).is_equal_to(vec![
+        "span parse".to_string(),
+        "commit message parsed".to_string(),
+    ]);
+}
+
+#[test]
+fn should_trace_rule_failure() {
+    // Act
+    let records = record(|| parse("feat add tracing").is_err());
+
+    // Assert
+    assert_that(&records).contains("commit message rule failure".to_string());
+}
+
+#[test]
+fn should_trace_recovery_decisions() {
+    // Arrange
+    let options = ParserOptions {
+        missing_blank_line_before_body: Policy::Warn,
+        ..Default::default()
+    };
+
+    // Act
+    let records = record(|| parse_with_diagnostics("fix: typo\nin the readme", &options).is_ok());
+
+    // Assert
+    assert_that(&records).contains("recoverable formatting issue".to_string());
+}